@@ -0,0 +1,117 @@
+//! Memory of the green trees of a batch of similar queries, with and without a shared
+//! [`NodeCache`]. Run with `cargo bench --bench memory`.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use ll_sparql_parser::{parse_with_cache, NodeCache, ParseOptions};
+
+/// Counts the bytes that are currently allocated.
+struct Counting;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+const PROLOGUE: &str = "PREFIX wd: <http://www.wikidata.org/entity/>
+PREFIX wdt: <http://www.wikidata.org/prop/direct/>
+PREFIX wikibase: <http://wikiba.se/ontology#>
+PREFIX bd: <http://www.bigdata.com/rdf#>
+PREFIX rdfs: <http://www.w3.org/2000/01/rdf-schema#>
+";
+
+const LABEL_SERVICE: &str =
+    "  SERVICE wikibase:label { bd:serviceParam wikibase:language \"[AUTO_LANGUAGE],en\". }\n";
+
+/// Queries in the shape of a query log of a Wikidata endpoint: the same prologue and
+/// label service everywhere, with different entities, properties and modifiers.
+fn corpus(size: usize) -> Vec<String> {
+    let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+    let mut next = move |bound: u64| {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state % bound
+    };
+    (0..size)
+        .map(|_| {
+            let mut query = PROLOGUE.to_string();
+            let (property, entity) = (next(3000), next(100_000_000));
+            match next(3) {
+                0 => {
+                    query += "SELECT ?item ?itemLabel WHERE {\n";
+                    query += &format!("  ?item wdt:P{property} wd:Q{entity} .\n");
+                }
+                1 => {
+                    query += "SELECT ?item ?itemLabel ?date WHERE {\n";
+                    query += &format!("  ?item wdt:P31 wd:Q{entity} ; wdt:P{property} ?date .\n");
+                    query += &format!("  FILTER(YEAR(?date) > {})\n", 1800 + next(200));
+                }
+                _ => {
+                    query += "SELECT ?item ?itemLabel (COUNT(?x) AS ?count) WHERE {\n";
+                    query += &format!("  ?item wdt:P{property} ?x .\n");
+                    query +=
+                        "  OPTIONAL { ?item rdfs:label ?label FILTER(LANG(?label) = \"en\") }\n";
+                }
+            }
+            query += LABEL_SERVICE;
+            query += "}\n";
+            if next(2) == 0 {
+                query += &format!("LIMIT {}\n", 10 * (1 + next(100)));
+            }
+            query
+        })
+        .collect()
+}
+
+/// Bytes that stay allocated for the trees of `corpus`, including `cache`.
+fn retained(corpus: &[String], mut cache: Option<NodeCache>) -> usize {
+    let before = ALLOCATED.load(Ordering::Relaxed);
+    let trees: Vec<_> = corpus
+        .iter()
+        .map(|query| {
+            let mut fresh = NodeCache::default();
+            let cache = cache.as_mut().unwrap_or(&mut fresh);
+            parse_with_cache(query, None, ParseOptions::default(), cache)
+                .green_node()
+                .clone()
+        })
+        .collect();
+    let retained = ALLOCATED.load(Ordering::Relaxed) - before;
+    drop((trees, cache));
+    retained
+}
+
+fn main() {
+    let corpus = corpus(10_000);
+    let text: usize = corpus.iter().map(String::len).sum();
+    let fresh = retained(&corpus, None);
+    let shared = retained(&corpus, Some(NodeCache::default()));
+    println!("{} queries, {} KiB of text", corpus.len(), text / 1024);
+    println!("trees, a cache per query:  {:>8} KiB", fresh / 1024);
+    println!(
+        "trees, one shared cache:   {:>8} KiB (including the cache)",
+        shared / 1024
+    );
+    let saved = fresh.saturating_sub(shared);
+    println!(
+        "saved:                     {:>8} KiB ({:.0}%)",
+        saved / 1024,
+        100.0 * saved as f64 / fresh as f64
+    );
+}
@@ -0,0 +1,86 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use ll_sparql_parser::{parse, parse_with_options, ParseOptions};
+
+/// Queries and updates in the shape they show up in query logs.
+const DOCUMENTS: &[&str] = &[
+    r#"PREFIX wd: <http://www.wikidata.org/entity/>
+PREFIX wdt: <http://www.wikidata.org/prop/direct/>
+PREFIX rdfs: <http://www.w3.org/2000/01/rdf-schema#>
+SELECT ?item ?label (COUNT(?sitelink) AS ?count) WHERE {
+  ?item wdt:P31 wd:Q5 ;
+        wdt:P27 wd:Q183 ;
+        rdfs:label ?label .
+  OPTIONAL { ?sitelink <http://schema.org/about> ?item }
+  FILTER(LANG(?label) = "de" && !CONTAINS(?label, "von"))
+}
+GROUP BY ?item ?label
+HAVING (COUNT(?sitelink) > 10)
+ORDER BY DESC(?count) ?label
+LIMIT 100"#,
+    r#"PREFIX foaf: <http://xmlns.com/foaf/0.1/>
+CONSTRUCT { ?person foaf:name ?name ; foaf:knows [ foaf:name ?friend ] }
+WHERE {
+  { ?person foaf:name ?name } UNION { ?person foaf:nick ?name }
+  ?person foaf:knows/foaf:name ?friend .
+  MINUS { ?person foaf:age ?age FILTER(?age < 18) }
+  BIND(CONCAT(STR(?name), " ", UCASE(?friend)) AS ?display)
+}"#,
+    r#"SELECT DISTINCT ?s WHERE {
+  VALUES (?type ?min) { (<http://ex.org/A> 1) (<http://ex.org/B> 2.5) (UNDEF 3e2) }
+  ?s a ?type ; <http://ex.org/value> ?v ; (<http://ex.org/p>|^<http://ex.org/q>)* ?o .
+  FILTER EXISTS { ?o <http://ex.org/flag> true }
+  FILTER(?v >= ?min && ?v IN (1, 2, 3) && REGEX(STR(?o), "^http", "i"))
+  SERVICE SILENT <http://ex.org/sparql> { ?o <http://ex.org/label> ?label }
+} OFFSET 20 LIMIT 10"#,
+    r#"ASK { ?s ?p ?o . FILTER(isIRI(?s) || (isLITERAL(?o) && STRLEN(?o) > 3 * (2 + 1))) }"#,
+    r#"PREFIX ex: <http://ex.org/>
+WITH ex:graph
+DELETE { ?s ex:price ?old }
+INSERT { ?s ex:price ?new ; ex:updated NOW() }
+WHERE { ?s ex:price ?old . BIND(?old * 1.1 AS ?new) } ;
+INSERT DATA { GRAPH ex:log { ex:run ex:at "2024-01-01"^^<http://www.w3.org/2001/XMLSchema#date> } } ;
+CLEAR SILENT GRAPH ex:tmp"#,
+];
+
+/// Documents with errors, which exercise error recovery.
+const BROKEN: &[&str] = &[
+    "SELECT ?s WHERE { ?s ?p . FILTER(?s = ) OPTIONAL { ?s } } LIMIT",
+    "SELECT * WHERE { ?s ?p ?o ) ?a ?b . { ?c ?d } ORDER BY",
+    "PREFIX ex: SELECT (COUNT(*) ?n) WHERE { ?s ex: ?o ; ; } GROUP ?s",
+    "INSERT { ?s ?p } WHERE { BIND(1 +) VALUES ?x { 1 2 ",
+];
+
+fn parse_all(documents: &[&str]) {
+    for document in documents {
+        black_box(parse(black_box(document)));
+    }
+}
+
+fn parse_all_compact(documents: &[&str]) {
+    let options = ParseOptions {
+        compact_expressions: true,
+        ..Default::default()
+    };
+    for document in documents {
+        black_box(parse_with_options(black_box(document), None, options));
+    }
+}
+
+fn bench_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    for (name, documents) in [("valid", DOCUMENTS), ("broken", BROKEN)] {
+        let bytes = documents
+            .iter()
+            .map(|document| document.len())
+            .sum::<usize>();
+        group.throughput(Throughput::Bytes(bytes as u64));
+        group.bench_function(name, |b| b.iter(|| parse_all(documents)));
+        group.bench_function(format!("{name}/compact"), |b| {
+            b.iter(|| parse_all_compact(documents))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse);
+criterion_main!(benches);
@@ -1,6 +1,6 @@
 mod generator;
 fn main() {
-    if std::env::var("GENERATE_PARSER").map_or(false, |env| env == "1") {
+    if std::env::var("GENERATE_PARSER").is_ok_and(|env| env == "1") {
         generator::generate();
     }
 }
@@ -0,0 +1,288 @@
+use std::{collections::HashSet, fs::File, io::Write};
+
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use ungrammar::{Grammar, Node, Rule};
+
+use super::{format_rule, generate_token_kind};
+
+/// An accessor of a struct in the typed syntax tree.
+struct Field {
+    name: String,
+    ty: FieldType,
+    labeled: bool,
+    many: bool,
+}
+
+#[derive(PartialEq, Eq)]
+enum FieldType {
+    /// A child node of the rule, a struct or an enum.
+    Node(String),
+    /// A child token of any of these kinds.
+    Tokens(Vec<String>),
+}
+
+/// Generates `src/ast/generated.rs` from `ast.ungram`.
+///
+/// `kinds` are the node kinds of the parser, every one of them needs a rule.
+pub(super) fn generate_ast(grammar: &Grammar, kinds: &[String], tokens: &HashSet<String>) {
+    let is_kind = |node: Node| kinds.contains(&grammar[node].name);
+    for kind in kinds {
+        assert!(
+            grammar.iter().any(|node| grammar[node].name == *kind),
+            "ast.ungram has no rule for the node kind `{kind}`"
+        );
+    }
+    for token in grammar.tokens() {
+        let name = &grammar[token].name;
+        assert!(
+            tokens.contains(name),
+            "ast.ungram uses the unknown token '{name}'"
+        );
+    }
+    let items = grammar.iter().map(|node| {
+        let name = &grammar[node].name;
+        let rule = &grammar[node].rule;
+        let comment = format!(" {} = {}", name, format_rule(grammar, rule));
+        match is_kind(node) {
+            true => generate_struct(grammar, node, &comment),
+            false => generate_enum(grammar, node, &comment, is_kind),
+        }
+    });
+    let ast = quote! {
+        use crate::{SyntaxKind, SyntaxNode, SyntaxToken};
+        use super::token;
+        #(#items)*
+    };
+
+    let syntax_tree = syn::parse2(ast).unwrap();
+    let formatted_code = prettyplease::unparse(&syntax_tree);
+
+    let mut file = File::create("src/ast/generated.rs").unwrap();
+    file.write_all(formatted_code.as_bytes()).unwrap();
+}
+
+fn generate_struct(grammar: &Grammar, node: Node, comment: &str) -> TokenStream {
+    let kind = format_ident!("{}", grammar[node].name);
+    let ident = type_ident(&grammar[node].name);
+    let mut fields = Vec::new();
+    collect_fields(grammar, &grammar[node].rule, None, false, &mut fields);
+    let fields = merge_fields(&grammar[node].name, fields);
+    let accessors = fields.iter().map(|field| {
+        let method = format_ident!("{}", field.name);
+        match &field.ty {
+            FieldType::Node(ty) => {
+                let ty = type_ident(ty);
+                let same_type = fields.iter().filter(|other| other.ty == field.ty);
+                let index = same_type
+                    .clone()
+                    .take_while(|other| other.name != field.name)
+                    .count();
+                match (field.many, same_type.count()) {
+                    (true, _) => quote! {
+                        pub fn #method(&self) -> impl Iterator<Item = #ty> {
+                            self.syntax.children().filter_map(#ty::cast)
+                        }
+                    },
+                    (false, 1) => quote! {
+                        pub fn #method(&self) -> Option<#ty> {
+                            self.syntax.children().find_map(#ty::cast)
+                        }
+                    },
+                    (false, _) => quote! {
+                        pub fn #method(&self) -> Option<#ty> {
+                            self.syntax.children().filter_map(#ty::cast).nth(#index)
+                        }
+                    },
+                }
+            }
+            FieldType::Tokens(tokens) => {
+                let kinds = tokens.iter().map(|token| generate_token_kind(token));
+                quote! {
+                    pub fn #method(&self) -> Option<SyntaxToken> {
+                        token(&self.syntax, &[#(SyntaxKind::#kinds),*])
+                    }
+                }
+            }
+        }
+    });
+    quote! {
+        #[doc = #comment]
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub struct #ident {
+            syntax: SyntaxNode,
+        }
+
+        impl #ident {
+            #(#accessors)*
+
+            pub fn cast(node: SyntaxNode) -> Option<Self> {
+                match node.kind() {
+                    SyntaxKind::#kind => Some(Self { syntax: node }),
+                    _ => None,
+                }
+            }
+
+            pub fn syntax(&self) -> &SyntaxNode {
+                &self.syntax
+            }
+        }
+    }
+}
+
+fn generate_enum(
+    grammar: &Grammar,
+    node: Node,
+    comment: &str,
+    is_kind: impl Fn(Node) -> bool,
+) -> TokenStream {
+    let name = &grammar[node].name;
+    let ident = type_ident(name);
+    let variants: Vec<Node> = match &grammar[node].rule {
+        Rule::Alt(rules) => rules
+            .iter()
+            .map(|rule| match rule {
+                Rule::Node(variant) if is_kind(*variant) => *variant,
+                _ => panic!("`{name}` is no node kind, it must be an alternative of node kinds"),
+            })
+            .collect(),
+        _ => panic!("`{name}` is no node kind, it must be an alternative of node kinds"),
+    };
+    let kinds: Vec<Ident> = variants
+        .iter()
+        .map(|variant| format_ident!("{}", grammar[*variant].name))
+        .collect();
+    let types: Vec<Ident> = variants
+        .iter()
+        .map(|variant| type_ident(&grammar[*variant].name))
+        .collect();
+    quote! {
+        #[doc = #comment]
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum #ident {
+            #(#types(#types)),*
+        }
+
+        impl #ident {
+            pub fn cast(node: SyntaxNode) -> Option<Self> {
+                match node.kind() {
+                    #(SyntaxKind::#kinds => Some(Self::#types(#types { syntax: node })),)*
+                    _ => None,
+                }
+            }
+
+            pub fn syntax(&self) -> &SyntaxNode {
+                match self {
+                    #(Self::#types(node) => node.syntax(),)*
+                }
+            }
+        }
+    }
+}
+
+/// Collects an accessor for every node and every labeled token in `rule`.
+fn collect_fields(
+    grammar: &Grammar,
+    rule: &Rule,
+    label: Option<&str>,
+    many: bool,
+    fields: &mut Vec<Field>,
+) {
+    let field = |name: String, ty: FieldType| Field {
+        name,
+        ty,
+        labeled: label.is_some(),
+        many,
+    };
+    match rule {
+        Rule::Labeled { label, rule } => collect_fields(grammar, rule, Some(label), many, fields),
+        Rule::Node(node) => {
+            let name = label.map_or_else(|| snake_case(&grammar[*node].name), str::to_string);
+            fields.push(field(name, FieldType::Node(grammar[*node].name.clone())));
+        }
+        Rule::Token(token) => {
+            if let Some(label) = label {
+                let ty = FieldType::Tokens(vec![grammar[*token].name.clone()]);
+                fields.push(field(label.to_string(), ty));
+            }
+        }
+        Rule::Alt(rules) if label.is_some() => {
+            let tokens = rules
+                .iter()
+                .map(|rule| match rule {
+                    Rule::Token(token) => grammar[*token].name.clone(),
+                    _ => panic!("labels of alternatives apply to tokens only"),
+                })
+                .collect();
+            fields.push(field(label.unwrap().to_string(), FieldType::Tokens(tokens)));
+        }
+        Rule::Seq(rules) | Rule::Alt(rules) => {
+            assert!(label.is_none(), "labels of sequences are not supported");
+            for rule in rules {
+                collect_fields(grammar, rule, None, many, fields);
+            }
+        }
+        Rule::Opt(rule) => collect_fields(grammar, rule, label, many, fields),
+        Rule::Rep(rule) => collect_fields(grammar, rule, label, true, fields),
+    }
+}
+
+/// Merges the occurrences of a child into one accessor.
+///
+/// An unlabeled node that occurs more than once is a list, named in plural
+/// unless the name already ends in `s`, like `graph_pattern_not_triples`.
+/// Labeled occurrences of the same node are told apart by their position.
+fn merge_fields(rule: &str, fields: Vec<Field>) -> Vec<Field> {
+    let mut merged: Vec<Field> = Vec::new();
+    for field in fields {
+        match merged.iter_mut().find(|other| other.name == field.name) {
+            Some(other) => {
+                assert!(
+                    other.ty == field.ty,
+                    "`{rule}` has two kinds of `{}`",
+                    field.name
+                );
+                other.many |= field.many || !field.labeled;
+            }
+            None => merged.push(field),
+        }
+    }
+    for field in &mut merged {
+        if field.many && !field.labeled && !field.name.ends_with('s') {
+            field.name.push('s');
+        }
+    }
+    for field in &merged {
+        let same_type = merged.iter().filter(|other| other.ty == field.ty).count();
+        assert!(
+            same_type == 1 || matches!(field.ty, FieldType::Tokens(_)) || !field.many,
+            "`{rule}` has a list of `{}` next to single ones",
+            field.name
+        );
+    }
+    merged
+}
+
+/// The struct or enum of a rule, `iri` becomes `Iri`.
+fn type_ident(name: &str) -> Ident {
+    let mut chars = name.chars();
+    let first = chars.next().unwrap().to_ascii_uppercase();
+    format_ident!("{}{}", first, chars.as_str())
+}
+
+/// The accessor of a rule, `RDFLiteral` becomes `rdf_literal`.
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::new();
+    for (index, char) in chars.iter().enumerate() {
+        if char.is_uppercase() && index > 0 {
+            let after_lower = !chars[index - 1].is_uppercase();
+            let before_lower = chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+            if after_lower || before_lower {
+                snake.push('_');
+            }
+        }
+        snake.push(char.to_ascii_lowercase());
+    }
+    snake
+}
@@ -1,14 +1,77 @@
+mod ast;
+mod terminals;
 mod utils;
 use std::{
+    collections::{HashMap, HashSet},
     fs::File,
     io::{Read, Write},
+    slice,
     str::FromStr,
 };
 
-use proc_macro2::TokenStream;
+use ast::generate_ast;
+use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
-use ungrammar::{Grammar, Rule, Token};
-use utils::{compute_first, is_nullable, FirstSet};
+use terminals::terminal_attributes;
+use ungrammar::{Grammar, Node, Rule, Token};
+use utils::{compute_first, compute_follow, is_nullable, FirstSet, FollowSet};
+
+/// Tokens that end or start a larger construct, error recovery stops at them.
+/// The recovery set of a rule are the synchronizing tokens of its FOLLOW set.
+/// Closing brackets are not part of it, the parser stops at them by bracket matching.
+const SYNC_TOKENS: &[&str] = &[
+    ".",
+    ";",
+    ",",
+    "SELECT",
+    "CONSTRUCT",
+    "DESCRIBE",
+    "ASK",
+    "WHERE",
+    "FROM",
+    "GROUP",
+    "HAVING",
+    "ORDER",
+    "LIMIT",
+    "OFFSET",
+    "VALUES",
+    "PREFIX",
+    "BASE",
+    "OPTIONAL",
+    "MINUS",
+    "FILTER",
+    "BIND",
+    "SERVICE",
+    "UNION",
+    "WITH",
+    "USING",
+    "INSERT",
+    "DELETE",
+    "INSERT_DATA",
+    "DELETE_DATA",
+    "DELETE_WHERE",
+    "LOAD",
+    "CLEAR",
+    "DROP",
+    "CREATE",
+    "ADD",
+    "MOVE",
+    "COPY",
+];
+
+/// The rule at the top of the precedence cascade of expressions.
+/// With `ParseOptions::compact_expressions` its contents are parsed by the
+/// hand-written expression parser instead of the cascade.
+const COMPACT_EXPRESSION: &str = "Expression";
+
+/// Rules whose FIRST set the hand-written expression parser checks.
+const COMPACT_EXPRESSION_FIRST: &[&str] = &["UnaryExpression"];
+
+/// Node kinds that the hand-written expression parser produces, they are no rules.
+const COMPACT_EXPRESSION_KINDS: &[&str] = &["BinaryExpr", "UnaryExpr", "InExpr"];
+
+/// Rules that the parser is started with.
+const ENTRY_POINTS: [&str; 2] = ["QueryUnit", "UpdateUnit"];
 
 pub fn generate() {
     let mut file = File::open("sparql.ungram").expect("File should exist");
@@ -17,17 +80,114 @@ pub fn generate() {
         .expect("could not read file");
     let grammar = ungrammar::Grammar::from_str(&contents).unwrap();
     let first = compute_first(&grammar);
+    let follow = compute_follow(&grammar, &first);
 
     generate_types(&grammar);
-    generate_parser(&grammar, &first);
+    generate_parser(&grammar, &first, &follow);
+
+    let mut file = File::open("ast.ungram").expect("File should exist");
+    let mut contents = String::new();
+    file.read_to_string(&mut contents)
+        .expect("could not read file");
+    let ast = ungrammar::Grammar::from_str(&contents).unwrap();
+    let kinds: Vec<String> = grammar
+        .iter()
+        .map(|node| grammar[node].name.clone())
+        .chain(COMPACT_EXPRESSION_KINDS.iter().map(|kind| kind.to_string()))
+        .collect();
+    let tokens = grammar
+        .tokens()
+        .map(|token| grammar[token].name.clone())
+        .collect();
+    generate_ast(&ast, &kinds, &tokens);
+}
+
+/// The token sets that the generated parser checks, emitted as constants.
+#[derive(Default)]
+struct TokenSets {
+    /// The rule whose parser is being generated.
+    rule: Option<Node>,
+    /// Nodes whose FIRST set is checked by some rule, as `FIRST_<node>`.
+    nodes: HashSet<Node>,
+    /// Other checked sets of every rule, as `FIRST_<rule>_<index>`.
+    local: HashMap<Node, Vec<Vec<Token>>>,
+}
+
+impl TokenSets {
+    /// The constant that holds the FIRST set of `rules`.
+    fn first_of_seq(&mut self, grammar: &Grammar, first: &FirstSet, rules: &[Rule]) -> Ident {
+        if let [Rule::Node(node)] = rules {
+            return self.first_of_node(grammar, *node);
+        }
+        let mut tokens: Vec<Token> = first.get_first_of_seq(rules, grammar).into_iter().collect();
+        tokens.sort();
+        let rule = self.rule.expect("sets are checked inside of a rule");
+        if tokens == first.get_first_of_sorted(&Rule::Node(rule), grammar) {
+            return self.first_of_node(grammar, rule);
+        }
+        let local = self.local.entry(rule).or_default();
+        let index = match local.iter().position(|set| *set == tokens) {
+            Some(index) => index,
+            None => {
+                local.push(tokens);
+                local.len() - 1
+            }
+        };
+        format_ident!("FIRST_{}_{}", grammar[rule].name, index)
+    }
+
+    fn first_of_node(&mut self, grammar: &Grammar, node: Node) -> Ident {
+        self.nodes.insert(node);
+        format_ident!("FIRST_{}", grammar[node].name)
+    }
+
+    /// The constants of the sets that are checked by the parser of `node`
+    /// and the FIRST set of `node` if another rule checks it.
+    fn generate_constants(&self, grammar: &Grammar, first: &FirstSet, node: Node) -> TokenStream {
+        let name = &grammar[node].name;
+        let node_first = self.nodes.contains(&node).then(|| {
+            let ident = format_ident!("FIRST_{}", name);
+            let kinds = generate_first_set(first, &Rule::Node(node), grammar);
+            let visibility = match COMPACT_EXPRESSION_FIRST.contains(&name.as_str()) {
+                true => quote! { pub(super) },
+                false => quote! {},
+            };
+            quote! { #visibility const #ident: TokenSet = TokenSet::new(&[#(#kinds),*]); }
+        });
+        let local =
+            self.local
+                .get(&node)
+                .into_iter()
+                .flatten()
+                .enumerate()
+                .map(|(index, tokens)| {
+                    let ident = format_ident!("FIRST_{}_{}", name, index);
+                    let kinds = tokens.iter().map(|token| {
+                        let kind = generate_token_kind(&grammar[*token].name);
+                        quote! { SyntaxKind::#kind }
+                    });
+                    quote! { const #ident: TokenSet = TokenSet::new(&[#(#kinds),*]); }
+                });
+        quote! {
+            #node_first
+            #(#local)*
+        }
+    }
 }
 
-fn generate_rule(grammar: &Grammar, rule: &Rule, first: &FirstSet) -> TokenStream {
+fn generate_rule(
+    grammar: &Grammar,
+    rule: &Rule,
+    first: &FirstSet,
+    sets: &mut TokenSets,
+) -> TokenStream {
     match rule {
         Rule::Labeled {
             label: _,
             rule: other,
-        } => generate_rule(grammar, other, first),
+        } => generate_rule(grammar, other, first, sets),
+        // A rule that refers to itself continues the loop of its own node, see `generate_parser`.
+        Rule::Node(node) if sets.rule == Some(*node) => quote! { continue; },
         Rule::Node(node) => {
             let ident = format_ident!("parse_{}", grammar[*node].name);
             quote! {#ident (p);}
@@ -36,10 +196,34 @@ fn generate_rule(grammar: &Grammar, rule: &Rule, first: &FirstSet) -> TokenStrea
             let ident = generate_token_kind(&grammar[*token].name);
             quote! {p.expect(SyntaxKind::#ident);}
         }
-        Rule::Seq(rules) => rules
-            .iter()
-            .map(|other| generate_rule(grammar, other, first))
-            .collect(),
+        Rule::Seq(rules) => match braced_group(grammar, rules) {
+            // Parsing resumes inside the braces after junk, instead of
+            // closing the group at the first unexpected token.
+            Some((open, close)) => {
+                let mut generate_seq = |rules: &[Rule]| -> TokenStream {
+                    rules
+                        .iter()
+                        .map(|other| generate_rule(grammar, other, first, sets))
+                        .collect()
+                };
+                let before = generate_seq(&rules[..=open]);
+                let inner = generate_seq(&rules[open + 1..close]);
+                let after = generate_seq(&rules[close..]);
+                let inner_first = sets.first_of_seq(grammar, first, &rules[open + 1..close]);
+                quote! {
+                    #before
+                    #inner
+                    while p.recover_in_braces(#inner_first) {
+                        #inner
+                    }
+                    #after
+                }
+            }
+            None => rules
+                .iter()
+                .map(|other| generate_rule(grammar, other, first, sets))
+                .collect(),
+        },
         Rule::Alt(rules) => {
             let match_arms: Vec<TokenStream> = rules
                 .iter()
@@ -52,7 +236,7 @@ fn generate_rule(grammar: &Grammar, rule: &Rule, first: &FirstSet) -> TokenStrea
                             quote! { SyntaxKind::#kind }
                         })
                         .collect();
-                    let parse_rule = generate_rule(grammar, other_rule, first);
+                    let parse_rule = generate_rule(grammar, other_rule, first, sets);
                     quote! {
                         #(#tokens )|* => {
                             #parse_rule
@@ -60,48 +244,38 @@ fn generate_rule(grammar: &Grammar, rule: &Rule, first: &FirstSet) -> TokenStrea
                     }
                 })
                 .collect();
+            let expected = generate_first_set(first, rule, grammar);
             let catch_arm = match is_nullable(rule, grammar) {
                 true => quote! {
                     _ => {}
                 },
                 false => quote! {
                     _ =>{
-                        p.advance_with_error("Expected ....");
+                        p.err_recover(&[#(#expected),*]);
                     }
                 },
             };
             quote! {
                 match p.nth(0){
                   #(#match_arms)*,
-                  SyntaxKind::Eof => {
-                        eprintln!("Unexpected Eof");
-                        p.close(marker, SyntaxKind::Error);
-                        return
-                  },
                   #catch_arm
                 };
             }
         }
         Rule::Opt(other_rule) => {
-            let first_set: Vec<TokenStream> = first
-                .get_first_of_sorted(other_rule, grammar)
-                .iter()
-                .map(|token| generate_token_kind(&grammar[*token].name))
-                .map(|ident| quote! {SyntaxKind::#ident})
-                .collect();
-
-            let parse_rule = generate_rule(grammar, other_rule, first);
+            let parse_rule = generate_rule(grammar, other_rule, first, sets);
+            let first_set = sets.first_of_seq(grammar, first, slice::from_ref(other_rule));
             quote! {
-                if p.at_any(&[#(#first_set),*]){
+                if p.at_any(#first_set){
                 #parse_rule
                 }
             }
         }
         Rule::Rep(other_rule) => {
-            let first_set: Vec<TokenStream> = generate_first_set(first, rule, grammar);
-            let parse_rule = generate_rule(grammar, other_rule, first);
+            let parse_rule = generate_rule(grammar, other_rule, first, sets);
+            let first_set = sets.first_of_seq(grammar, first, slice::from_ref(other_rule));
             quote! {
-                while [#(#first_set),*].contains(&p.nth(0)) {
+                while p.at_any(#first_set) {
                     #parse_rule
                 }
             }
@@ -109,6 +283,14 @@ fn generate_rule(grammar: &Grammar, rule: &Rule, first: &FirstSet) -> TokenStrea
     }
 }
 
+/// Finds the positions of a `'{'` and the matching `'}'` in a sequence.
+fn braced_group(grammar: &Grammar, rules: &[Rule]) -> Option<(usize, usize)> {
+    let is_token = |rule: &Rule, name: &str| matches!(rule, Rule::Token(token) if grammar[*token].name == name);
+    let open = rules.iter().position(|rule| is_token(rule, "{"))?;
+    let close = open + rules[open..].iter().position(|rule| is_token(rule, "}"))?;
+    Some((open, close))
+}
+
 fn generate_first_set(first: &FirstSet, rule: &Rule, grammar: &Grammar) -> Vec<TokenStream> {
     let mut first_set = first
         .get_first_of(rule, grammar)
@@ -136,7 +318,7 @@ fn generate_token_kind(token: &str) -> proc_macro2::Ident {
             "[" => "LBrack",
             "]" => "RBrack",
             "." => "Dot",
-            "," => "Colon",
+            "," => "Comma",
             ";" => "Semicolon",
             "|" => "Pipe",
             "||" => "DoublePipe",
@@ -163,53 +345,84 @@ fn generate_token_kind(token: &str) -> proc_macro2::Ident {
 fn generate_types(grammar: &Grammar) {
     let token_kinds = grammar
         .tokens()
-        .map(|token| generate_token_kind(grammar[token].name.as_str()))
-        .map(|token| {
-            let x = format!("{}", token);
+        .map(|token| grammar[token].name.as_str())
+        .map(|name| {
+            let kind = generate_token_kind(name);
+            let attributes = match terminal_attributes(name) {
+                Some(attributes) => TokenStream::from_str(attributes).unwrap(),
+                // Keywords are matched case-insensitively, with the exception of `a`.
+                None if name
+                    .chars()
+                    .all(|char| char.is_alphanumeric() || char == '_')
+                    && name != "a" =>
+                {
+                    quote! { #[token(#name, ignore(case))] }
+                }
+                None => quote! { #[token(#name)] },
+            };
             quote! {
-
-                    #[token(#x, ignore(case))]
-                    #token
+                #attributes
+                #kind
             }
         });
-    let parser_trees = grammar
+    let parser_trees: Vec<_> = grammar
         .iter()
-        .map(|node| format_ident!("{}", grammar[node].name));
+        .map(|node| format_ident!("{}", grammar[node].name))
+        .collect();
+    let last = parser_trees.last().unwrap();
 
     let tokens = quote! {
-        use logos::{Logos, Span};
+        use super::callbacks;
+        use logos::Logos;
 
         #[allow(non_camel_case_types)]
-        #[derive(Logos, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Serialize)]
+        #[derive(Logos, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
         #[repr(u16)]
         pub enum SyntaxKind  {
             Eof = 0,
             Error,
-            #[regex(r#"[ \t\n\f]+"#)]
+            /// Zero-width node without children at the position of an absent token.
+            /// The kinds that were expected are in the diagnostic reported at its position.
+            Missing,
+            /// `lhs op rhs` in a compact expression tree, see `ParseOptions::compact_expressions`.
+            /// After an operand, the sign of a numeric literal is split off as the operator,
+            /// like in `?a -1`.
+            BinaryExpr,
+            /// `op operand` in a compact expression tree.
+            UnaryExpr,
+            /// `lhs IN (...)` or `lhs NOT IN (...)` in a compact expression tree.
+            InExpr,
+            #[regex(r#"[ \t\r\n\f]+"#)]
             WHITESPACE,
-            #(#token_kinds),*
+            #[regex(r#"#[^\r\n]*"#)]
+            COMMENT,
+            /// The second word of `INSERT DATA` and `DELETE DATA`, which the parser
+            /// reads together with the first as `INSERT_DATA` or `DELETE_DATA`.
+            #[token("DATA", ignore(case))]
+            DATA,
+            #(#token_kinds,)*
+            #(#parser_trees),*
         }
 
-        #[allow(non_camel_case_types)]
-        pub enum SyntaxKind {
-            Error,
-            #(#parser_trees),*
+        impl SyntaxKind {
+            /// The kind with the highest discriminant.
+            pub(crate) const LAST: SyntaxKind = SyntaxKind::#last;
         }
     };
 
     let syntax_tree = syn::parse2(tokens).unwrap();
     let formatted_code = prettyplease::unparse(&syntax_tree);
 
-    let mut file = File::create("gen_kinds.rs").unwrap();
+    let mut file = File::create("src/syntax_kind/generated.rs").unwrap();
     file.write_all(formatted_code.as_bytes()).unwrap();
 }
 
 fn format_rule(grammar: &Grammar, rule: &Rule) -> String {
     match rule {
-        ungrammar::Rule::Labeled {
-            label: _,
-            rule: other,
-        } => format_rule(grammar, other),
+        ungrammar::Rule::Labeled { label, rule: other } => match **other {
+            Rule::Seq(_) | Rule::Alt(_) => format!("{}:({})", label, format_rule(grammar, other)),
+            _ => format!("{}:{}", label, format_rule(grammar, other)),
+        },
         ungrammar::Rule::Node(node) => grammar[*node].name.clone(),
         ungrammar::Rule::Token(token) => format!("'{}'", grammar[*token].name.clone()),
         ungrammar::Rule::Seq(rules) => rules
@@ -240,38 +453,149 @@ fn format_rule(grammar: &Grammar, rule: &Rule) -> String {
     }
 }
 
-fn generate_parser(grammar: &Grammar, first: &FirstSet) {
-    let functions = grammar.iter().enumerate().map(|(idx, node)| {
+/// Whether every reference to `node` in `rule` is the last thing `rule` parses.
+fn refers_in_tail_only(rule: &Rule, node: Node, tail: bool) -> bool {
+    match rule {
+        Rule::Labeled { rule, .. } | Rule::Opt(rule) => refers_in_tail_only(rule, node, tail),
+        Rule::Rep(rule) => refers_in_tail_only(rule, node, false),
+        Rule::Node(other) => *other != node || tail,
+        Rule::Token(_) => true,
+        Rule::Alt(rules) => rules
+            .iter()
+            .all(|rule| refers_in_tail_only(rule, node, tail)),
+        Rule::Seq(rules) => rules
+            .iter()
+            .enumerate()
+            .all(|(index, rule)| refers_in_tail_only(rule, node, tail && index + 1 == rules.len())),
+    }
+}
+
+/// Collects the nodes that are referenced by some rule.
+fn referenced_nodes(rule: &Rule, nodes: &mut HashSet<Node>) {
+    match rule {
+        Rule::Labeled { rule, .. } | Rule::Opt(rule) | Rule::Rep(rule) => {
+            referenced_nodes(rule, nodes)
+        }
+        Rule::Node(node) => {
+            nodes.insert(*node);
+        }
+        Rule::Token(_) => {}
+        Rule::Seq(rules) | Rule::Alt(rules) => {
+            rules.iter().for_each(|rule| referenced_nodes(rule, nodes))
+        }
+    }
+}
+
+fn generate_parser(grammar: &Grammar, first: &FirstSet, follow: &FollowSet) {
+    let mut referenced = HashSet::new();
+    grammar
+        .iter()
+        .for_each(|node| referenced_nodes(&grammar[node].rule, &mut referenced));
+    let mut sets = TokenSets::default();
+    for node in grammar.iter() {
+        if COMPACT_EXPRESSION_FIRST.contains(&grammar[node].name.as_str()) {
+            sets.first_of_node(grammar, node);
+        }
+    }
+    // The bodies are generated first, they decide which sets become constants.
+    let bodies: Vec<(Node, TokenStream)> = grammar
+        .iter()
+        .map(|node| {
+            sets.rule = Some(node);
+            let name = &grammar[node].name;
+            let rule = &grammar[node].rule;
+            let tree_kind = format_ident!("{}", name);
+            let rules = generate_rule(grammar, rule, first, &mut sets);
+            // Tail recursion like in `TriplesBlock = TriplesSameSubjectPath ( '.' TriplesBlock? )?`
+            // becomes a loop that adds to a single node, long chains would overflow the stack
+            // of the parser and of the tree otherwise.
+            let mut nodes = HashSet::new();
+            referenced_nodes(rule, &mut nodes);
+            let rules = match nodes.contains(&node) {
+                false => rules,
+                true => {
+                    assert!(
+                        refers_in_tail_only(rule, node, true),
+                        "`{name}` refers to itself before its end"
+                    );
+                    quote! {
+                        loop {
+                            #rules
+                            break;
+                        }
+                    }
+                }
+            };
+            let rules = match name == COMPACT_EXPRESSION {
+                false => rules,
+                true => quote! {
+                    if p.options.compact_expressions {
+                        parse_compact_expression(p);
+                    } else {
+                        #rules
+                    }
+                },
+            };
+            let open = match follow_recovery(grammar, follow, node).is_empty() {
+                true => quote! { p.open() },
+                false => {
+                    let recovery = format_ident!("RECOVERY_{}", name);
+                    quote! { p.open_rule(#recovery) }
+                }
+            };
+            // Entry points always produce the root node, even for empty input.
+            let escape = match is_nullable(rule, grammar) && !ENTRY_POINTS.contains(&name.as_str())
+            {
+                false => quote! {},
+                true => {
+                    let first_set = sets.first_of_node(grammar, node);
+                    quote! {
+                        if !p.at_any(#first_set){
+                            return;
+                        }
+                    }
+                }
+            };
+            let body = quote! {
+                #escape
+                let marker = #open;
+                #rules
+                p.close(marker, SyntaxKind::#tree_kind);
+            };
+            (node, body)
+        })
+        .collect();
+    let functions = bodies.into_iter().enumerate().map(|(idx, (node, body))| {
         let name = &grammar[node].name;
         let rule = &grammar[node].rule;
         let comment = format!(" [{}] {} -> {}", idx, name, format_rule(grammar, rule));
-        let tree_kind = format_ident!("{}", name);
         let function_name = format_ident!("parse_{}", name);
-        let rules = generate_rule(grammar, rule, first);
-        let nullable = is_nullable(rule, grammar);
-        let first_set = generate_first_set(first, rule, grammar);
-        let escape = match nullable {
-            false => quote! {},
-            true => quote! {
-                if !p.at_any(&[#(#first_set),*]){
-                    return;
-                }
-            },
-        };
+        let constants = sets.generate_constants(grammar, first, node);
+        let recovery = follow_recovery(grammar, follow, node);
+        let recovery = (!recovery.is_empty()).then(|| {
+            let ident = format_ident!("RECOVERY_{}", name);
+            quote! { const #ident: TokenSet = TokenSet::new(&[#(#recovery),*]); }
+        });
+        // Entry points are called from outside of the generated code.
+        let allow_dead_code =
+            match referenced.contains(&node) || ENTRY_POINTS.contains(&name.as_str()) {
+                true => quote! {},
+                false => quote! { #[allow(dead_code)] },
+            };
         quote! {
+            #constants
+            #recovery
+            #allow_dead_code
             #[doc = #comment]
             pub (super) fn #function_name (p: &mut Parser){
-                #escape
-                let marker = p.open();
-                #rules
-                p.close(marker, SyntaxKind::#tree_kind);
+                #body
             }
         }
     });
     let parser = quote! {
-        #![allow(non_snake_case)]
+        #![allow(non_snake_case, non_upper_case_globals)]
         use crate::SyntaxKind;
-        use super::Parser;
+        use super::{expressions::parse_compact_expression, Parser, TokenSet};
          #(#functions)*
     };
 
@@ -281,3 +605,14 @@ fn generate_parser(grammar: &Grammar, first: &FirstSet) {
     let mut file = File::create("src/parser/grammar.rs").unwrap();
     file.write_all(formatted_code.as_bytes()).unwrap();
 }
+
+/// The synchronizing tokens in the FOLLOW set of `node`, error recovery inside of it stops at them.
+fn follow_recovery(grammar: &Grammar, follow: &FollowSet, node: Node) -> Vec<TokenStream> {
+    follow
+        .get_sorted(&node)
+        .into_iter()
+        .filter(|token| SYNC_TOKENS.contains(&grammar[*token].name.as_str()))
+        .map(|token| generate_token_kind(&grammar[token].name))
+        .map(|ident| quote! {SyntaxKind::#ident})
+        .collect()
+}
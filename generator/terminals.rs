@@ -0,0 +1,68 @@
+/// [164] PN_CHARS_BASE as the contents of a regex character class.
+macro_rules! pn_chars_base {
+    () => {
+        r"A-Za-z\u{00C0}-\u{00D6}\u{00D8}-\u{00F6}\u{00F8}-\u{02FF}\u{0370}-\u{037D}\u{037F}-\u{1FFF}\u{200C}-\u{200D}\u{2070}-\u{218F}\u{2C00}-\u{2FEF}\u{3001}-\u{D7FF}\u{F900}-\u{FDCF}\u{FDF0}-\u{FFFD}\u{10000}-\u{EFFFF}"
+    };
+}
+
+/// [167] PN_CHARS without PN_CHARS_U and `-`.
+macro_rules! pn_chars_extra {
+    () => {
+        r"0-9\u{00B7}\u{0300}-\u{036F}\u{203F}-\u{2040}"
+    };
+}
+
+/// [166] VARNAME ::= ( PN_CHARS_U | [0-9] ) ( PN_CHARS_U | [0-9] | #x00B7 | [#x0300-#x036F] | [#x203F-#x2040] )*
+macro_rules! varname {
+    () => {
+        concat!(
+            "[",
+            pn_chars_base!(),
+            "_0-9][",
+            pn_chars_base!(),
+            "_",
+            pn_chars_extra!(),
+            "]*"
+        )
+    };
+}
+
+/// Lexer attributes of the terminals that are neither keywords nor punctuation.
+///
+/// An empty string marks terminals without a pattern of their own,
+/// they are produced by the callbacks in `src/syntax_kind/callbacks.rs`, or, for the
+/// compound update keywords, by the parser input in `src/parser/lexer.rs`.
+pub(super) fn terminal_attributes(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "IRIREF" => r####"#[regex(r#"<[^<>\"{}|^`\\\u{00}-\u{20}]*>"#)]"####,
+        "PNAME_NS" => concat!(
+            r#"#[regex(""#,
+            "[",
+            pn_chars_base!(),
+            "][",
+            pn_chars_base!(),
+            pn_chars_extra!(),
+            r#"_.-]*:", callbacks::prefixed_name)]"#,
+            r#"#[token(":", callbacks::prefixed_name)]"#
+        ),
+        "VAR1" => concat!(r#"#[regex("\\?"#, varname!(), r#"")]"#),
+        "VAR2" => concat!(r#"#[regex("\\$"#, varname!(), r#"")]"#),
+        "(" => r#"#[token("(", callbacks::nil)]"#,
+        "[" => r#"#[token("[", callbacks::anon)]"#,
+        "INTEGER" => r#"#[regex(r"[0-9]+", callbacks::numeric)]"#,
+        "DECIMAL" => r#"#[regex(r"\.[0-9]+", callbacks::numeric)]"#,
+        "INTEGER_POSITIVE" => r#"#[regex(r"\+[0-9]+", callbacks::numeric)]"#,
+        "DECIMAL_POSITIVE" => r#"#[regex(r"\+\.[0-9]+", callbacks::numeric)]"#,
+        "INTEGER_NEGATIVE" => r#"#[regex(r"-[0-9]+", callbacks::numeric)]"#,
+        "DECIMAL_NEGATIVE" => r#"#[regex(r"-\.[0-9]+", callbacks::numeric)]"#,
+        "LANGTAG" => r#"#[regex("@[a-zA-Z]+(-[a-zA-Z0-9]+)*")]"#,
+        "STRING_LITERAL1" => r####"#[regex(r#"'([^'\\\n\r]|\\[tbnrf\\"'])*'"#)]"####,
+        "STRING_LITERAL2" => r####"#[regex(r#""([^"\\\n\r]|\\[tbnrf\\"'])*""#)]"####,
+        "STRING_LITERAL_LONG1" => r####"#[regex(r#"'''(('|'')?([^'\\]|\\[tbnrf\\"']))*'''"#)]"####,
+        "STRING_LITERAL_LONG2" => r####"#[regex(r#""""(("|"")?([^"\\]|\\[tbnrf\\"']))*""""#)]"####,
+        "BLANK_NODE_LABEL" => r#"#[token("_:", callbacks::blank_node_label)]"#,
+        "INSERT_DATA" | "DELETE_DATA" | "DELETE_WHERE" | "NIL" | "ANON" | "DOUBLE"
+        | "DOUBLE_POSITIVE" | "DOUBLE_NEGATIVE" | "PNAME_LN" => "",
+        _ => return None,
+    })
+}
@@ -27,7 +27,7 @@ impl FirstSet {
             .into_iter()
             .collect::<Vec<Token>>();
         first_set.sort();
-        return first_set;
+        first_set
     }
     pub(super) fn get_first_of(&self, rule: &Rule, grammar: &Grammar) -> HashSet<Token> {
         match rule {
@@ -41,16 +41,7 @@ impl FirstSet {
                 .get(node)
                 .expect("Every node should have a first-set")
                 .clone(),
-            Rule::Seq(rules) => {
-                let mut set: HashSet<Token> = HashSet::new();
-                for rule in rules.iter() {
-                    set.extend(&self.get_first_of(rule, grammar).clone());
-                    if !is_nullable(rule, grammar) {
-                        break;
-                    }
-                }
-                set
-            }
+            Rule::Seq(rules) => self.get_first_of_seq(rules, grammar),
             Rule::Alt(rules) => {
                 let set = rules.iter().fold(HashSet::new(), |mut accu, rule| {
                     accu.extend(self.get_first_of(rule, grammar));
@@ -61,14 +52,25 @@ impl FirstSet {
             Rule::Token(token) => HashSet::from([*token]),
         }
     }
+
+    pub(super) fn get_first_of_seq(&self, rules: &[Rule], grammar: &Grammar) -> HashSet<Token> {
+        let mut set: HashSet<Token> = HashSet::new();
+        for rule in rules.iter() {
+            set.extend(&self.get_first_of(rule, grammar).clone());
+            if !is_nullable(rule, grammar) {
+                break;
+            }
+        }
+        set
+    }
 }
 
 pub(super) fn compute_first(grammar: &Grammar) -> FirstSet {
     let mut first = FirstSet::new();
     for node in grammar.iter() {
-        compute_first_helper(node, &grammar, &mut first);
+        compute_first_helper(node, grammar, &mut first);
     }
-    return first;
+    first
 }
 
 fn compute_first_helper(node: Node, grammar: &Grammar, first_set: &mut FirstSet) {
@@ -130,102 +132,101 @@ pub(super) fn get_first(
     }
 }
 
-// fn compute_follow_set(
-//     non_term: &String,
-//     grammar: &Grammar,
-//     first_set: &FirstSet,
-//     follow_set: &mut FollowSet,
-// ) {
-// if follow_set.contains_key(non_term) {
-//     return;
-// }
-// follow_set.insert(non_term.clone(), HashSet::new());
-// if Term::NonTerminal(non_term.clone()) == grammar.start {
-//     follow_set.entry(non_term.to_string()).and_modify(|e| {
-//         e.insert("$".to_string());
-//     });
-// }
-// grammar
-//     .rules
-//     .iter()
-//     .filter_map(|rule| match rule {
-//         Rule(_lhs, Term::Concatination(terms)) => {
-//             if terms.iter().any(|term| match term {
-//                 Term::NonTerminal(nt) if nt == non_term => true,
-//                 _ => false,
-//             }) {
-//                 return Some(rule);
-//             }
-//             return None;
-//         }
-//         _ => None,
-//     })
-//     .for_each(|rule| {
-//         match &rule.1 {
-//             Term::Concatination(terms) => {
-//                 terms
-//                     .iter()
-//                     .zip(terms.iter().skip(1))
-//                     .filter_map(|(elem, next)| match elem {
-//                         Term::NonTerminal(nt) if nt == non_term => Some(next),
-//                         _ => None,
-//                     })
-//                     .for_each(|elem| match elem {
-//                         Term::Terminal(t) => {
-//                             follow_set
-//                                 .entry(non_term.clone())
-//                                 .and_modify(|e| {
-//                                     e.insert(t.clone());
-//                                 })
-//                                 .or_insert(HashSet::from([t.clone()]));
-//                         }
-//                         Term::NonTerminal(nt) => {
-//                             let mut first = first_set
-//                                 .get(nt)
-//                                 .expect(&format!(
-//                                     "FIRST of {} should have beed calculated already",
-//                                     nt
-//                                 ))
-//                                 .clone();
-//                             if first.contains("''") {
-//                                 first.remove("''");
-//                                 compute_follow_set(nt, grammar, first_set, follow_set);
-//                                 let follow = follow_set.get(nt).expect(&format!(
-//                                     "FIRST of {} should have beed calculated already",
-//                                     nt
-//                                 ));
-//                                 first.extend(follow.clone());
-//                             }
-//                             follow_set
-//                                 .entry(non_term.clone())
-//                                 .and_modify(|e| {
-//                                     e.extend(first.clone());
-//                                 })
-//                                 .or_insert(first);
-//                         }
-//                         _ => {}
-//                     });
-//                 match terms.last() {
-//                     Some(Term::NonTerminal(nt)) if nt == non_term => {
-//                         compute_follow_set(&rule.0, grammar, first_set, follow_set);
-//                         let set = follow_set
-//                             .get(&rule.0)
-//                             .expect(&format!(
-//                                 "FOLLOW of {} should have beed calculated already",
-//                                 nt
-//                             ))
-//                             .clone();
-//                         follow_set
-//                             .entry(non_term.clone())
-//                             .and_modify(|e| {
-//                                 e.extend(set.clone());
-//                             })
-//                             .or_insert(set);
-//                     }
-//                     _ => {}
-//                 }
-//             }
-//             _ => panic!("All rules in the computation of FOLLOW should be concatinations"),
-//         };
-//     });
-// }
+pub(super) struct FollowSet(HashMap<Node, HashSet<Token>>);
+
+impl FollowSet {
+    pub(super) fn get_sorted(&self, node: &Node) -> Vec<Token> {
+        let mut follow_set: Vec<Token> = self
+            .0
+            .get(node)
+            .map(|set| set.iter().copied().collect())
+            .unwrap_or_default();
+        follow_set.sort();
+        follow_set
+    }
+}
+
+/// Computes the FOLLOW set of every node as a fixed point.
+/// The end of input is not a token of the grammar and therefore never part of a FOLLOW set.
+pub(super) fn compute_follow(grammar: &Grammar, first: &FirstSet) -> FollowSet {
+    let mut follow = FollowSet(grammar.iter().map(|node| (node, HashSet::new())).collect());
+    loop {
+        let mut changed = false;
+        for node in grammar.iter() {
+            let trailer = follow.0[&node].clone();
+            follow_helper(
+                &grammar[node].rule,
+                trailer,
+                grammar,
+                first,
+                &mut follow,
+                &mut changed,
+            );
+        }
+        if !changed {
+            return follow;
+        }
+    }
+}
+
+/// Adds `trailer` to the FOLLOW set of every node that can end `rule`
+/// and returns the tokens that can start `rule` followed by `trailer`.
+fn follow_helper(
+    rule: &Rule,
+    trailer: HashSet<Token>,
+    grammar: &Grammar,
+    first: &FirstSet,
+    follow: &mut FollowSet,
+    changed: &mut bool,
+) -> HashSet<Token> {
+    match rule {
+        Rule::Labeled {
+            label: _,
+            rule: other,
+        } => follow_helper(other, trailer, grammar, first, follow, changed),
+        Rule::Token(token) => HashSet::from([*token]),
+        Rule::Node(node) => {
+            let set = follow
+                .0
+                .get_mut(node)
+                .expect("Every node should have a follow-set");
+            let len = set.len();
+            set.extend(&trailer);
+            *changed |= set.len() != len;
+            let mut starts = first
+                .get(node)
+                .expect("Every node should have a first-set")
+                .clone();
+            if is_nullable(rule, grammar) {
+                starts.extend(trailer);
+            }
+            starts
+        }
+        Rule::Seq(rules) => rules.iter().rev().fold(trailer, |trailer, rule| {
+            follow_helper(rule, trailer, grammar, first, follow, changed)
+        }),
+        Rule::Alt(rules) => rules.iter().fold(HashSet::new(), |mut accu, rule| {
+            accu.extend(follow_helper(
+                rule,
+                trailer.clone(),
+                grammar,
+                first,
+                follow,
+                changed,
+            ));
+            accu
+        }),
+        Rule::Opt(other) => {
+            let mut starts = follow_helper(other, trailer.clone(), grammar, first, follow, changed);
+            starts.extend(trailer);
+            starts
+        }
+        Rule::Rep(other) => {
+            let mut repeated = trailer.clone();
+            repeated.extend(first.get_first_of(other, grammar));
+            let mut starts = follow_helper(other, repeated, grammar, first, follow, changed);
+            starts.extend(trailer);
+            starts
+        }
+    }
+}
@@ -0,0 +1,3521 @@
+use super::token;
+use crate::{SyntaxKind, SyntaxNode, SyntaxToken};
+/// QueryUnit = Query
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QueryUnit {
+    syntax: SyntaxNode,
+}
+impl QueryUnit {
+    pub fn query(&self) -> Option<Query> {
+        self.syntax.children().find_map(Query::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::QueryUnit => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// Query = Prologue QueryForm ValuesClause
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Query {
+    syntax: SyntaxNode,
+}
+impl Query {
+    pub fn prologue(&self) -> Option<Prologue> {
+        self.syntax.children().find_map(Prologue::cast)
+    }
+    pub fn query_form(&self) -> Option<QueryForm> {
+        self.syntax.children().find_map(QueryForm::cast)
+    }
+    pub fn values_clause(&self) -> Option<ValuesClause> {
+        self.syntax.children().find_map(ValuesClause::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::Query => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// Prologue = (BaseDecl | PrefixDecl)*
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Prologue {
+    syntax: SyntaxNode,
+}
+impl Prologue {
+    pub fn base_decls(&self) -> impl Iterator<Item = BaseDecl> {
+        self.syntax.children().filter_map(BaseDecl::cast)
+    }
+    pub fn prefix_decls(&self) -> impl Iterator<Item = PrefixDecl> {
+        self.syntax.children().filter_map(PrefixDecl::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::Prologue => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// QueryForm = SelectQuery | ConstructQuery | DescribeQuery | AskQuery
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum QueryForm {
+    SelectQuery(SelectQuery),
+    ConstructQuery(ConstructQuery),
+    DescribeQuery(DescribeQuery),
+    AskQuery(AskQuery),
+}
+impl QueryForm {
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::SelectQuery => Some(Self::SelectQuery(SelectQuery { syntax: node })),
+            SyntaxKind::ConstructQuery => {
+                Some(Self::ConstructQuery(ConstructQuery { syntax: node }))
+            }
+            SyntaxKind::DescribeQuery => Some(Self::DescribeQuery(DescribeQuery { syntax: node })),
+            SyntaxKind::AskQuery => Some(Self::AskQuery(AskQuery { syntax: node })),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        match self {
+            Self::SelectQuery(node) => node.syntax(),
+            Self::ConstructQuery(node) => node.syntax(),
+            Self::DescribeQuery(node) => node.syntax(),
+            Self::AskQuery(node) => node.syntax(),
+        }
+    }
+}
+/// ValuesClause = 'VALUES' DataBlock
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ValuesClause {
+    syntax: SyntaxNode,
+}
+impl ValuesClause {
+    pub fn data_block(&self) -> Option<DataBlock> {
+        self.syntax.children().find_map(DataBlock::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::ValuesClause => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// SelectQuery = SelectClause DatasetClause* WhereClause SolutionModifier
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SelectQuery {
+    syntax: SyntaxNode,
+}
+impl SelectQuery {
+    pub fn select_clause(&self) -> Option<SelectClause> {
+        self.syntax.children().find_map(SelectClause::cast)
+    }
+    pub fn dataset_clauses(&self) -> impl Iterator<Item = DatasetClause> {
+        self.syntax.children().filter_map(DatasetClause::cast)
+    }
+    pub fn where_clause(&self) -> Option<WhereClause> {
+        self.syntax.children().find_map(WhereClause::cast)
+    }
+    pub fn solution_modifier(&self) -> Option<SolutionModifier> {
+        self.syntax.children().find_map(SolutionModifier::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::SelectQuery => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// ConstructQuery = 'CONSTRUCT' (ConstructTemplate DatasetClause* WhereClause | DatasetClause* 'WHERE' '{' TriplesTemplate? '}') SolutionModifier
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ConstructQuery {
+    syntax: SyntaxNode,
+}
+impl ConstructQuery {
+    pub fn construct_template(&self) -> Option<ConstructTemplate> {
+        self.syntax.children().find_map(ConstructTemplate::cast)
+    }
+    pub fn dataset_clauses(&self) -> impl Iterator<Item = DatasetClause> {
+        self.syntax.children().filter_map(DatasetClause::cast)
+    }
+    pub fn where_clause(&self) -> Option<WhereClause> {
+        self.syntax.children().find_map(WhereClause::cast)
+    }
+    pub fn triples_template(&self) -> Option<TriplesTemplate> {
+        self.syntax.children().find_map(TriplesTemplate::cast)
+    }
+    pub fn solution_modifier(&self) -> Option<SolutionModifier> {
+        self.syntax.children().find_map(SolutionModifier::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::ConstructQuery => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// DescribeQuery = 'DESCRIBE' (VarOrIri* | star:'*') DatasetClause* WhereClause? SolutionModifier
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DescribeQuery {
+    syntax: SyntaxNode,
+}
+impl DescribeQuery {
+    pub fn var_or_iris(&self) -> impl Iterator<Item = VarOrIri> {
+        self.syntax.children().filter_map(VarOrIri::cast)
+    }
+    pub fn star(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::Star])
+    }
+    pub fn dataset_clauses(&self) -> impl Iterator<Item = DatasetClause> {
+        self.syntax.children().filter_map(DatasetClause::cast)
+    }
+    pub fn where_clause(&self) -> Option<WhereClause> {
+        self.syntax.children().find_map(WhereClause::cast)
+    }
+    pub fn solution_modifier(&self) -> Option<SolutionModifier> {
+        self.syntax.children().find_map(SolutionModifier::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::DescribeQuery => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// AskQuery = 'ASK' DatasetClause* WhereClause SolutionModifier
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AskQuery {
+    syntax: SyntaxNode,
+}
+impl AskQuery {
+    pub fn dataset_clauses(&self) -> impl Iterator<Item = DatasetClause> {
+        self.syntax.children().filter_map(DatasetClause::cast)
+    }
+    pub fn where_clause(&self) -> Option<WhereClause> {
+        self.syntax.children().find_map(WhereClause::cast)
+    }
+    pub fn solution_modifier(&self) -> Option<SolutionModifier> {
+        self.syntax.children().find_map(SolutionModifier::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::AskQuery => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// UpdateUnit = Update
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UpdateUnit {
+    syntax: SyntaxNode,
+}
+impl UpdateUnit {
+    pub fn update(&self) -> Option<Update> {
+        self.syntax.children().find_map(Update::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::UpdateUnit => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// Update = Prologue (UpdateOne ';' Prologue)* UpdateOne?
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Update {
+    syntax: SyntaxNode,
+}
+impl Update {
+    pub fn prologues(&self) -> impl Iterator<Item = Prologue> {
+        self.syntax.children().filter_map(Prologue::cast)
+    }
+    pub fn update_ones(&self) -> impl Iterator<Item = UpdateOne> {
+        self.syntax.children().filter_map(UpdateOne::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::Update => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// BaseDecl = 'BASE' iri:'IRIREF'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BaseDecl {
+    syntax: SyntaxNode,
+}
+impl BaseDecl {
+    pub fn iri(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::IRIREF])
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::BaseDecl => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// PrefixDecl = 'PREFIX' prefix:'PNAME_NS' iri:'IRIREF'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PrefixDecl {
+    syntax: SyntaxNode,
+}
+impl PrefixDecl {
+    pub fn prefix(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::PNAME_NS])
+    }
+    pub fn iri(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::IRIREF])
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::PrefixDecl => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// SelectClause = 'SELECT' modifier:('DISTINCT' | 'REDUCED')? (Var | '(' Expression 'AS' Var ')')* star:'*'?
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SelectClause {
+    syntax: SyntaxNode,
+}
+impl SelectClause {
+    pub fn modifier(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::DISTINCT, SyntaxKind::REDUCED])
+    }
+    pub fn vars(&self) -> impl Iterator<Item = Var> {
+        self.syntax.children().filter_map(Var::cast)
+    }
+    pub fn expressions(&self) -> impl Iterator<Item = Expression> {
+        self.syntax.children().filter_map(Expression::cast)
+    }
+    pub fn star(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::Star])
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::SelectClause => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// DatasetClause = 'FROM' (DefaultGraphClause | NamedGraphClause)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DatasetClause {
+    syntax: SyntaxNode,
+}
+impl DatasetClause {
+    pub fn default_graph_clause(&self) -> Option<DefaultGraphClause> {
+        self.syntax.children().find_map(DefaultGraphClause::cast)
+    }
+    pub fn named_graph_clause(&self) -> Option<NamedGraphClause> {
+        self.syntax.children().find_map(NamedGraphClause::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::DatasetClause => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// WhereClause = 'WHERE'? GroupGraphPattern
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WhereClause {
+    syntax: SyntaxNode,
+}
+impl WhereClause {
+    pub fn group_graph_pattern(&self) -> Option<GroupGraphPattern> {
+        self.syntax.children().find_map(GroupGraphPattern::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::WhereClause => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// SolutionModifier = GroupClause? HavingClause? OrderClause? LimitOffsetClauses?
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SolutionModifier {
+    syntax: SyntaxNode,
+}
+impl SolutionModifier {
+    pub fn group_clause(&self) -> Option<GroupClause> {
+        self.syntax.children().find_map(GroupClause::cast)
+    }
+    pub fn having_clause(&self) -> Option<HavingClause> {
+        self.syntax.children().find_map(HavingClause::cast)
+    }
+    pub fn order_clause(&self) -> Option<OrderClause> {
+        self.syntax.children().find_map(OrderClause::cast)
+    }
+    pub fn limit_offset_clauses(&self) -> Option<LimitOffsetClauses> {
+        self.syntax.children().find_map(LimitOffsetClauses::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::SolutionModifier => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// SubSelect = SelectClause WhereClause SolutionModifier ValuesClause
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SubSelect {
+    syntax: SyntaxNode,
+}
+impl SubSelect {
+    pub fn select_clause(&self) -> Option<SelectClause> {
+        self.syntax.children().find_map(SelectClause::cast)
+    }
+    pub fn where_clause(&self) -> Option<WhereClause> {
+        self.syntax.children().find_map(WhereClause::cast)
+    }
+    pub fn solution_modifier(&self) -> Option<SolutionModifier> {
+        self.syntax.children().find_map(SolutionModifier::cast)
+    }
+    pub fn values_clause(&self) -> Option<ValuesClause> {
+        self.syntax.children().find_map(ValuesClause::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::SubSelect => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// Var = name:('VAR1' | 'VAR2')
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Var {
+    syntax: SyntaxNode,
+}
+impl Var {
+    pub fn name(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::VAR1, SyntaxKind::VAR2])
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::Var => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// Expression = ConditionalOrExpression | Expr
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Expression {
+    syntax: SyntaxNode,
+}
+impl Expression {
+    pub fn conditional_or_expression(&self) -> Option<ConditionalOrExpression> {
+        self.syntax
+            .children()
+            .find_map(ConditionalOrExpression::cast)
+    }
+    pub fn expr(&self) -> Option<Expr> {
+        self.syntax.children().find_map(Expr::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::Expression => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// ConstructTemplate = '{' ConstructTriples? '}'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ConstructTemplate {
+    syntax: SyntaxNode,
+}
+impl ConstructTemplate {
+    pub fn construct_triples(&self) -> Option<ConstructTriples> {
+        self.syntax.children().find_map(ConstructTriples::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::ConstructTemplate => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// TriplesTemplate = TriplesSameSubject ('.' TriplesSameSubject)* '.'?
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TriplesTemplate {
+    syntax: SyntaxNode,
+}
+impl TriplesTemplate {
+    pub fn triples_same_subjects(&self) -> impl Iterator<Item = TriplesSameSubject> {
+        self.syntax.children().filter_map(TriplesSameSubject::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::TriplesTemplate => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// VarOrIri = Var | iri
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VarOrIri {
+    syntax: SyntaxNode,
+}
+impl VarOrIri {
+    pub fn var(&self) -> Option<Var> {
+        self.syntax.children().find_map(Var::cast)
+    }
+    pub fn iri(&self) -> Option<Iri> {
+        self.syntax.children().find_map(Iri::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::VarOrIri => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// DefaultGraphClause = SourceSelector
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DefaultGraphClause {
+    syntax: SyntaxNode,
+}
+impl DefaultGraphClause {
+    pub fn source_selector(&self) -> Option<SourceSelector> {
+        self.syntax.children().find_map(SourceSelector::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::DefaultGraphClause => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// NamedGraphClause = 'NAMED' SourceSelector
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NamedGraphClause {
+    syntax: SyntaxNode,
+}
+impl NamedGraphClause {
+    pub fn source_selector(&self) -> Option<SourceSelector> {
+        self.syntax.children().find_map(SourceSelector::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::NamedGraphClause => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// SourceSelector = iri
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SourceSelector {
+    syntax: SyntaxNode,
+}
+impl SourceSelector {
+    pub fn iri(&self) -> Option<Iri> {
+        self.syntax.children().find_map(Iri::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::SourceSelector => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// iri = iriref:'IRIREF' | PrefixedName
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Iri {
+    syntax: SyntaxNode,
+}
+impl Iri {
+    pub fn iriref(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::IRIREF])
+    }
+    pub fn prefixed_name(&self) -> Option<PrefixedName> {
+        self.syntax.children().find_map(PrefixedName::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::iri => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// GroupGraphPattern = '{' (SubSelect | GroupGraphPatternSub) '}'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GroupGraphPattern {
+    syntax: SyntaxNode,
+}
+impl GroupGraphPattern {
+    pub fn sub_select(&self) -> Option<SubSelect> {
+        self.syntax.children().find_map(SubSelect::cast)
+    }
+    pub fn group_graph_pattern_sub(&self) -> Option<GroupGraphPatternSub> {
+        self.syntax.children().find_map(GroupGraphPatternSub::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::GroupGraphPattern => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// GroupClause = 'GROUP' 'BY' GroupCondition*
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GroupClause {
+    syntax: SyntaxNode,
+}
+impl GroupClause {
+    pub fn group_conditions(&self) -> impl Iterator<Item = GroupCondition> {
+        self.syntax.children().filter_map(GroupCondition::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::GroupClause => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// HavingClause = 'HAVING' HavingCondition*
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HavingClause {
+    syntax: SyntaxNode,
+}
+impl HavingClause {
+    pub fn having_conditions(&self) -> impl Iterator<Item = HavingCondition> {
+        self.syntax.children().filter_map(HavingCondition::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::HavingClause => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// OrderClause = 'ORDER' 'BY' OrderCondition*
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OrderClause {
+    syntax: SyntaxNode,
+}
+impl OrderClause {
+    pub fn order_conditions(&self) -> impl Iterator<Item = OrderCondition> {
+        self.syntax.children().filter_map(OrderCondition::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::OrderClause => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// LimitOffsetClauses = LimitClause? OffsetClause?
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LimitOffsetClauses {
+    syntax: SyntaxNode,
+}
+impl LimitOffsetClauses {
+    pub fn limit_clause(&self) -> Option<LimitClause> {
+        self.syntax.children().find_map(LimitClause::cast)
+    }
+    pub fn offset_clause(&self) -> Option<OffsetClause> {
+        self.syntax.children().find_map(OffsetClause::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::LimitOffsetClauses => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// GroupCondition = BuiltInCall | FunctionCall | '(' Expression ('AS' Var)? ')' | Var
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GroupCondition {
+    syntax: SyntaxNode,
+}
+impl GroupCondition {
+    pub fn built_in_call(&self) -> Option<BuiltInCall> {
+        self.syntax.children().find_map(BuiltInCall::cast)
+    }
+    pub fn function_call(&self) -> Option<FunctionCall> {
+        self.syntax.children().find_map(FunctionCall::cast)
+    }
+    pub fn expression(&self) -> Option<Expression> {
+        self.syntax.children().find_map(Expression::cast)
+    }
+    pub fn vars(&self) -> impl Iterator<Item = Var> {
+        self.syntax.children().filter_map(Var::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::GroupCondition => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// BuiltInCall = Aggregate | SubstringExpression | StrReplaceExpression | RegexExpression | ExistsFunc | NotExistsFunc | function:('STR' | 'LANG' | 'LANGMATCHES' | 'DATATYPE' | 'BOUND' | 'IRI' | 'URI' | 'BNODE' | 'RAND' | 'ABS' | 'CEIL' | 'FLOOR' | 'ROUND' | 'CONCAT' | 'STRLEN' | 'UCASE' | 'LCASE' | 'ENCODE_FOR_URI' | 'CONTAINS' | 'STRSTARTS' | 'STRENDS' | 'STRBEFORE' | 'STRAFTER' | 'YEAR' | 'MONTH' | 'DAY' | 'HOURS' | 'MINUTES' | 'SECONDS' | 'TIMEZONE' | 'TZ' | 'NOW' | 'UUID' | 'STRUUID' | 'MD5' | 'SHA1' | 'SHA256' | 'SHA384' | 'SHA512' | 'COALESCE' | 'IF' | 'STRLANG' | 'STRDT' | 'sameTerm' | 'isIRI' | 'isURI' | 'isBLANK' | 'isLITERAL' | 'isNUMERIC') ('(' (Expression (',' Expression)* | Var) ')' | ExpressionList | 'NIL')
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BuiltInCall {
+    syntax: SyntaxNode,
+}
+impl BuiltInCall {
+    pub fn aggregate(&self) -> Option<Aggregate> {
+        self.syntax.children().find_map(Aggregate::cast)
+    }
+    pub fn substring_expression(&self) -> Option<SubstringExpression> {
+        self.syntax.children().find_map(SubstringExpression::cast)
+    }
+    pub fn str_replace_expression(&self) -> Option<StrReplaceExpression> {
+        self.syntax.children().find_map(StrReplaceExpression::cast)
+    }
+    pub fn regex_expression(&self) -> Option<RegexExpression> {
+        self.syntax.children().find_map(RegexExpression::cast)
+    }
+    pub fn exists_func(&self) -> Option<ExistsFunc> {
+        self.syntax.children().find_map(ExistsFunc::cast)
+    }
+    pub fn not_exists_func(&self) -> Option<NotExistsFunc> {
+        self.syntax.children().find_map(NotExistsFunc::cast)
+    }
+    pub fn function(&self) -> Option<SyntaxToken> {
+        token(
+            &self.syntax,
+            &[
+                SyntaxKind::STR,
+                SyntaxKind::LANG,
+                SyntaxKind::LANGMATCHES,
+                SyntaxKind::DATATYPE,
+                SyntaxKind::BOUND,
+                SyntaxKind::IRI,
+                SyntaxKind::URI,
+                SyntaxKind::BNODE,
+                SyntaxKind::RAND,
+                SyntaxKind::ABS,
+                SyntaxKind::CEIL,
+                SyntaxKind::FLOOR,
+                SyntaxKind::ROUND,
+                SyntaxKind::CONCAT,
+                SyntaxKind::STRLEN,
+                SyntaxKind::UCASE,
+                SyntaxKind::LCASE,
+                SyntaxKind::ENCODE_FOR_URI,
+                SyntaxKind::CONTAINS,
+                SyntaxKind::STRSTARTS,
+                SyntaxKind::STRENDS,
+                SyntaxKind::STRBEFORE,
+                SyntaxKind::STRAFTER,
+                SyntaxKind::YEAR,
+                SyntaxKind::MONTH,
+                SyntaxKind::DAY,
+                SyntaxKind::HOURS,
+                SyntaxKind::MINUTES,
+                SyntaxKind::SECONDS,
+                SyntaxKind::TIMEZONE,
+                SyntaxKind::TZ,
+                SyntaxKind::NOW,
+                SyntaxKind::UUID,
+                SyntaxKind::STRUUID,
+                SyntaxKind::MD5,
+                SyntaxKind::SHA1,
+                SyntaxKind::SHA256,
+                SyntaxKind::SHA384,
+                SyntaxKind::SHA512,
+                SyntaxKind::COALESCE,
+                SyntaxKind::IF,
+                SyntaxKind::STRLANG,
+                SyntaxKind::STRDT,
+                SyntaxKind::sameTerm,
+                SyntaxKind::isIRI,
+                SyntaxKind::isURI,
+                SyntaxKind::isBLANK,
+                SyntaxKind::isLITERAL,
+                SyntaxKind::isNUMERIC,
+            ],
+        )
+    }
+    pub fn expressions(&self) -> impl Iterator<Item = Expression> {
+        self.syntax.children().filter_map(Expression::cast)
+    }
+    pub fn var(&self) -> Option<Var> {
+        self.syntax.children().find_map(Var::cast)
+    }
+    pub fn expression_list(&self) -> Option<ExpressionList> {
+        self.syntax.children().find_map(ExpressionList::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::BuiltInCall => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// FunctionCall = iri ArgList
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FunctionCall {
+    syntax: SyntaxNode,
+}
+impl FunctionCall {
+    pub fn iri(&self) -> Option<Iri> {
+        self.syntax.children().find_map(Iri::cast)
+    }
+    pub fn arg_list(&self) -> Option<ArgList> {
+        self.syntax.children().find_map(ArgList::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::FunctionCall => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// HavingCondition = Constraint
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HavingCondition {
+    syntax: SyntaxNode,
+}
+impl HavingCondition {
+    pub fn constraint(&self) -> Option<Constraint> {
+        self.syntax.children().find_map(Constraint::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::HavingCondition => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// Constraint = BrackettedExpression | BuiltInCall | FunctionCall
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Constraint {
+    syntax: SyntaxNode,
+}
+impl Constraint {
+    pub fn bracketted_expression(&self) -> Option<BrackettedExpression> {
+        self.syntax.children().find_map(BrackettedExpression::cast)
+    }
+    pub fn built_in_call(&self) -> Option<BuiltInCall> {
+        self.syntax.children().find_map(BuiltInCall::cast)
+    }
+    pub fn function_call(&self) -> Option<FunctionCall> {
+        self.syntax.children().find_map(FunctionCall::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::Constraint => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// OrderCondition = direction:('ASC' | 'DESC')? BrackettedExpression | Constraint | Var
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OrderCondition {
+    syntax: SyntaxNode,
+}
+impl OrderCondition {
+    pub fn direction(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::ASC, SyntaxKind::DESC])
+    }
+    pub fn bracketted_expression(&self) -> Option<BrackettedExpression> {
+        self.syntax.children().find_map(BrackettedExpression::cast)
+    }
+    pub fn constraint(&self) -> Option<Constraint> {
+        self.syntax.children().find_map(Constraint::cast)
+    }
+    pub fn var(&self) -> Option<Var> {
+        self.syntax.children().find_map(Var::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::OrderCondition => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// BrackettedExpression = '(' Expression ')'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BrackettedExpression {
+    syntax: SyntaxNode,
+}
+impl BrackettedExpression {
+    pub fn expression(&self) -> Option<Expression> {
+        self.syntax.children().find_map(Expression::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::BrackettedExpression => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// LimitClause = 'LIMIT' value:'INTEGER'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LimitClause {
+    syntax: SyntaxNode,
+}
+impl LimitClause {
+    pub fn value(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::INTEGER])
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::LimitClause => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// OffsetClause = 'OFFSET' value:'INTEGER'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OffsetClause {
+    syntax: SyntaxNode,
+}
+impl OffsetClause {
+    pub fn value(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::INTEGER])
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::OffsetClause => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// DataBlock = InlineDataOneVar | InlineDataFull
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DataBlock {
+    syntax: SyntaxNode,
+}
+impl DataBlock {
+    pub fn inline_data_one_var(&self) -> Option<InlineDataOneVar> {
+        self.syntax.children().find_map(InlineDataOneVar::cast)
+    }
+    pub fn inline_data_full(&self) -> Option<InlineDataFull> {
+        self.syntax.children().find_map(InlineDataFull::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::DataBlock => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// UpdateOne = Operation
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UpdateOne {
+    syntax: SyntaxNode,
+}
+impl UpdateOne {
+    pub fn operation(&self) -> Option<Operation> {
+        self.syntax.children().find_map(Operation::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::UpdateOne => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// Operation = Load | Clear | Drop | Add | Move | Copy | Create | InsertData | DeleteData | DeleteWhere | Modify
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Operation {
+    Load(Load),
+    Clear(Clear),
+    Drop(Drop),
+    Add(Add),
+    Move(Move),
+    Copy(Copy),
+    Create(Create),
+    InsertData(InsertData),
+    DeleteData(DeleteData),
+    DeleteWhere(DeleteWhere),
+    Modify(Modify),
+}
+impl Operation {
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::Load => Some(Self::Load(Load { syntax: node })),
+            SyntaxKind::Clear => Some(Self::Clear(Clear { syntax: node })),
+            SyntaxKind::Drop => Some(Self::Drop(Drop { syntax: node })),
+            SyntaxKind::Add => Some(Self::Add(Add { syntax: node })),
+            SyntaxKind::Move => Some(Self::Move(Move { syntax: node })),
+            SyntaxKind::Copy => Some(Self::Copy(Copy { syntax: node })),
+            SyntaxKind::Create => Some(Self::Create(Create { syntax: node })),
+            SyntaxKind::InsertData => Some(Self::InsertData(InsertData { syntax: node })),
+            SyntaxKind::DeleteData => Some(Self::DeleteData(DeleteData { syntax: node })),
+            SyntaxKind::DeleteWhere => Some(Self::DeleteWhere(DeleteWhere { syntax: node })),
+            SyntaxKind::Modify => Some(Self::Modify(Modify { syntax: node })),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        match self {
+            Self::Load(node) => node.syntax(),
+            Self::Clear(node) => node.syntax(),
+            Self::Drop(node) => node.syntax(),
+            Self::Add(node) => node.syntax(),
+            Self::Move(node) => node.syntax(),
+            Self::Copy(node) => node.syntax(),
+            Self::Create(node) => node.syntax(),
+            Self::InsertData(node) => node.syntax(),
+            Self::DeleteData(node) => node.syntax(),
+            Self::DeleteWhere(node) => node.syntax(),
+            Self::Modify(node) => node.syntax(),
+        }
+    }
+}
+/// Load = 'LOAD' silent:'SILENT'? iri ('INTO' GraphRef)?
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Load {
+    syntax: SyntaxNode,
+}
+impl Load {
+    pub fn silent(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::SILENT])
+    }
+    pub fn iri(&self) -> Option<Iri> {
+        self.syntax.children().find_map(Iri::cast)
+    }
+    pub fn graph_ref(&self) -> Option<GraphRef> {
+        self.syntax.children().find_map(GraphRef::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::Load => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// Clear = 'CLEAR' silent:'SILENT'? GraphRefAll
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Clear {
+    syntax: SyntaxNode,
+}
+impl Clear {
+    pub fn silent(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::SILENT])
+    }
+    pub fn graph_ref_all(&self) -> Option<GraphRefAll> {
+        self.syntax.children().find_map(GraphRefAll::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::Clear => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// Drop = 'DROP' silent:'SILENT'? GraphRefAll
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Drop {
+    syntax: SyntaxNode,
+}
+impl Drop {
+    pub fn silent(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::SILENT])
+    }
+    pub fn graph_ref_all(&self) -> Option<GraphRefAll> {
+        self.syntax.children().find_map(GraphRefAll::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::Drop => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// Add = 'ADD' silent:'SILENT'? source:GraphOrDefault 'TO' target:GraphOrDefault
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Add {
+    syntax: SyntaxNode,
+}
+impl Add {
+    pub fn silent(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::SILENT])
+    }
+    pub fn source(&self) -> Option<GraphOrDefault> {
+        self.syntax
+            .children()
+            .filter_map(GraphOrDefault::cast)
+            .nth(0usize)
+    }
+    pub fn target(&self) -> Option<GraphOrDefault> {
+        self.syntax
+            .children()
+            .filter_map(GraphOrDefault::cast)
+            .nth(1usize)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::Add => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// Move = 'MOVE' silent:'SILENT'? source:GraphOrDefault 'TO' target:GraphOrDefault
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Move {
+    syntax: SyntaxNode,
+}
+impl Move {
+    pub fn silent(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::SILENT])
+    }
+    pub fn source(&self) -> Option<GraphOrDefault> {
+        self.syntax
+            .children()
+            .filter_map(GraphOrDefault::cast)
+            .nth(0usize)
+    }
+    pub fn target(&self) -> Option<GraphOrDefault> {
+        self.syntax
+            .children()
+            .filter_map(GraphOrDefault::cast)
+            .nth(1usize)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::Move => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// Copy = 'COPY' silent:'SILENT'? source:GraphOrDefault 'TO' target:GraphOrDefault
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Copy {
+    syntax: SyntaxNode,
+}
+impl Copy {
+    pub fn silent(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::SILENT])
+    }
+    pub fn source(&self) -> Option<GraphOrDefault> {
+        self.syntax
+            .children()
+            .filter_map(GraphOrDefault::cast)
+            .nth(0usize)
+    }
+    pub fn target(&self) -> Option<GraphOrDefault> {
+        self.syntax
+            .children()
+            .filter_map(GraphOrDefault::cast)
+            .nth(1usize)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::Copy => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// Create = 'CREATE' silent:'SILENT'? GraphRef
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Create {
+    syntax: SyntaxNode,
+}
+impl Create {
+    pub fn silent(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::SILENT])
+    }
+    pub fn graph_ref(&self) -> Option<GraphRef> {
+        self.syntax.children().find_map(GraphRef::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::Create => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// InsertData = 'INSERT_DATA' QuadData
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InsertData {
+    syntax: SyntaxNode,
+}
+impl InsertData {
+    pub fn quad_data(&self) -> Option<QuadData> {
+        self.syntax.children().find_map(QuadData::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::InsertData => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// DeleteData = 'DELETE_DATA' QuadData
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DeleteData {
+    syntax: SyntaxNode,
+}
+impl DeleteData {
+    pub fn quad_data(&self) -> Option<QuadData> {
+        self.syntax.children().find_map(QuadData::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::DeleteData => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// DeleteWhere = 'DELETE_WHERE' QuadPattern
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DeleteWhere {
+    syntax: SyntaxNode,
+}
+impl DeleteWhere {
+    pub fn quad_pattern(&self) -> Option<QuadPattern> {
+        self.syntax.children().find_map(QuadPattern::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::DeleteWhere => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// Modify = ('WITH' iri)? DeleteClause? InsertClause? UsingClause* 'WHERE' GroupGraphPattern
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Modify {
+    syntax: SyntaxNode,
+}
+impl Modify {
+    pub fn iri(&self) -> Option<Iri> {
+        self.syntax.children().find_map(Iri::cast)
+    }
+    pub fn delete_clause(&self) -> Option<DeleteClause> {
+        self.syntax.children().find_map(DeleteClause::cast)
+    }
+    pub fn insert_clause(&self) -> Option<InsertClause> {
+        self.syntax.children().find_map(InsertClause::cast)
+    }
+    pub fn using_clauses(&self) -> impl Iterator<Item = UsingClause> {
+        self.syntax.children().filter_map(UsingClause::cast)
+    }
+    pub fn group_graph_pattern(&self) -> Option<GroupGraphPattern> {
+        self.syntax.children().find_map(GroupGraphPattern::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::Modify => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// GraphRef = 'GRAPH' iri
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GraphRef {
+    syntax: SyntaxNode,
+}
+impl GraphRef {
+    pub fn iri(&self) -> Option<Iri> {
+        self.syntax.children().find_map(Iri::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::GraphRef => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// GraphRefAll = GraphRef | target:('DEFAULT' | 'NAMED' | 'ALL')
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GraphRefAll {
+    syntax: SyntaxNode,
+}
+impl GraphRefAll {
+    pub fn graph_ref(&self) -> Option<GraphRef> {
+        self.syntax.children().find_map(GraphRef::cast)
+    }
+    pub fn target(&self) -> Option<SyntaxToken> {
+        token(
+            &self.syntax,
+            &[SyntaxKind::DEFAULT, SyntaxKind::NAMED, SyntaxKind::ALL],
+        )
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::GraphRefAll => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// GraphOrDefault = default:'DEFAULT' | 'GRAPH'? iri
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GraphOrDefault {
+    syntax: SyntaxNode,
+}
+impl GraphOrDefault {
+    pub fn default(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::DEFAULT])
+    }
+    pub fn iri(&self) -> Option<Iri> {
+        self.syntax.children().find_map(Iri::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::GraphOrDefault => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// QuadData = '{' Quads '}'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QuadData {
+    syntax: SyntaxNode,
+}
+impl QuadData {
+    pub fn quads(&self) -> Option<Quads> {
+        self.syntax.children().find_map(Quads::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::QuadData => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// QuadPattern = '{' Quads '}'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QuadPattern {
+    syntax: SyntaxNode,
+}
+impl QuadPattern {
+    pub fn quads(&self) -> Option<Quads> {
+        self.syntax.children().find_map(Quads::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::QuadPattern => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// DeleteClause = 'DELETE' QuadPattern
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DeleteClause {
+    syntax: SyntaxNode,
+}
+impl DeleteClause {
+    pub fn quad_pattern(&self) -> Option<QuadPattern> {
+        self.syntax.children().find_map(QuadPattern::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::DeleteClause => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// InsertClause = 'INSERT' QuadPattern
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InsertClause {
+    syntax: SyntaxNode,
+}
+impl InsertClause {
+    pub fn quad_pattern(&self) -> Option<QuadPattern> {
+        self.syntax.children().find_map(QuadPattern::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::InsertClause => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// UsingClause = 'USING' named:'NAMED'? iri
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UsingClause {
+    syntax: SyntaxNode,
+}
+impl UsingClause {
+    pub fn named(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::NAMED])
+    }
+    pub fn iri(&self) -> Option<Iri> {
+        self.syntax.children().find_map(Iri::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::UsingClause => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// Quads = (TriplesTemplate | QuadsNotTriples '.'?)*
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Quads {
+    syntax: SyntaxNode,
+}
+impl Quads {
+    pub fn triples_templates(&self) -> impl Iterator<Item = TriplesTemplate> {
+        self.syntax.children().filter_map(TriplesTemplate::cast)
+    }
+    pub fn quads_not_triples(&self) -> impl Iterator<Item = QuadsNotTriples> {
+        self.syntax.children().filter_map(QuadsNotTriples::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::Quads => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// QuadsNotTriples = 'GRAPH' VarOrIri '{' TriplesTemplate? '}'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QuadsNotTriples {
+    syntax: SyntaxNode,
+}
+impl QuadsNotTriples {
+    pub fn var_or_iri(&self) -> Option<VarOrIri> {
+        self.syntax.children().find_map(VarOrIri::cast)
+    }
+    pub fn triples_template(&self) -> Option<TriplesTemplate> {
+        self.syntax.children().find_map(TriplesTemplate::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::QuadsNotTriples => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// TriplesSameSubject = VarOrTerm PropertyListNotEmpty | TriplesNode PropertyList
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TriplesSameSubject {
+    syntax: SyntaxNode,
+}
+impl TriplesSameSubject {
+    pub fn var_or_term(&self) -> Option<VarOrTerm> {
+        self.syntax.children().find_map(VarOrTerm::cast)
+    }
+    pub fn property_list_not_empty(&self) -> Option<PropertyListNotEmpty> {
+        self.syntax.children().find_map(PropertyListNotEmpty::cast)
+    }
+    pub fn triples_node(&self) -> Option<TriplesNode> {
+        self.syntax.children().find_map(TriplesNode::cast)
+    }
+    pub fn property_list(&self) -> Option<PropertyList> {
+        self.syntax.children().find_map(PropertyList::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::TriplesSameSubject => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// GroupGraphPatternSub = (TriplesBlock | GraphPatternNotTriples '.'?)*
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GroupGraphPatternSub {
+    syntax: SyntaxNode,
+}
+impl GroupGraphPatternSub {
+    pub fn triples_blocks(&self) -> impl Iterator<Item = TriplesBlock> {
+        self.syntax.children().filter_map(TriplesBlock::cast)
+    }
+    pub fn graph_pattern_not_triples(&self) -> impl Iterator<Item = GraphPatternNotTriples> {
+        self.syntax
+            .children()
+            .filter_map(GraphPatternNotTriples::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::GroupGraphPatternSub => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// TriplesBlock = TriplesSameSubjectPath ('.' TriplesSameSubjectPath)* '.'?
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TriplesBlock {
+    syntax: SyntaxNode,
+}
+impl TriplesBlock {
+    pub fn triples_same_subject_paths(&self) -> impl Iterator<Item = TriplesSameSubjectPath> {
+        self.syntax
+            .children()
+            .filter_map(TriplesSameSubjectPath::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::TriplesBlock => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// GraphPatternNotTriples = GraphPattern
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GraphPatternNotTriples {
+    syntax: SyntaxNode,
+}
+impl GraphPatternNotTriples {
+    pub fn graph_pattern(&self) -> Option<GraphPattern> {
+        self.syntax.children().find_map(GraphPattern::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::GraphPatternNotTriples => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// TriplesSameSubjectPath = VarOrTerm PropertyListPathNotEmpty | TriplesNodePath PropertyListPath
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TriplesSameSubjectPath {
+    syntax: SyntaxNode,
+}
+impl TriplesSameSubjectPath {
+    pub fn var_or_term(&self) -> Option<VarOrTerm> {
+        self.syntax.children().find_map(VarOrTerm::cast)
+    }
+    pub fn property_list_path_not_empty(&self) -> Option<PropertyListPathNotEmpty> {
+        self.syntax
+            .children()
+            .find_map(PropertyListPathNotEmpty::cast)
+    }
+    pub fn triples_node_path(&self) -> Option<TriplesNodePath> {
+        self.syntax.children().find_map(TriplesNodePath::cast)
+    }
+    pub fn property_list_path(&self) -> Option<PropertyListPath> {
+        self.syntax.children().find_map(PropertyListPath::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::TriplesSameSubjectPath => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// GraphPattern = GroupOrUnionGraphPattern | OptionalGraphPattern | MinusGraphPattern | GraphGraphPattern | ServiceGraphPattern | Filter | Bind | InlineData
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GraphPattern {
+    GroupOrUnionGraphPattern(GroupOrUnionGraphPattern),
+    OptionalGraphPattern(OptionalGraphPattern),
+    MinusGraphPattern(MinusGraphPattern),
+    GraphGraphPattern(GraphGraphPattern),
+    ServiceGraphPattern(ServiceGraphPattern),
+    Filter(Filter),
+    Bind(Bind),
+    InlineData(InlineData),
+}
+impl GraphPattern {
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::GroupOrUnionGraphPattern => {
+                Some(Self::GroupOrUnionGraphPattern(GroupOrUnionGraphPattern {
+                    syntax: node,
+                }))
+            }
+            SyntaxKind::OptionalGraphPattern => {
+                Some(Self::OptionalGraphPattern(OptionalGraphPattern {
+                    syntax: node,
+                }))
+            }
+            SyntaxKind::MinusGraphPattern => {
+                Some(Self::MinusGraphPattern(MinusGraphPattern { syntax: node }))
+            }
+            SyntaxKind::GraphGraphPattern => {
+                Some(Self::GraphGraphPattern(GraphGraphPattern { syntax: node }))
+            }
+            SyntaxKind::ServiceGraphPattern => {
+                Some(Self::ServiceGraphPattern(ServiceGraphPattern {
+                    syntax: node,
+                }))
+            }
+            SyntaxKind::Filter => Some(Self::Filter(Filter { syntax: node })),
+            SyntaxKind::Bind => Some(Self::Bind(Bind { syntax: node })),
+            SyntaxKind::InlineData => Some(Self::InlineData(InlineData { syntax: node })),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        match self {
+            Self::GroupOrUnionGraphPattern(node) => node.syntax(),
+            Self::OptionalGraphPattern(node) => node.syntax(),
+            Self::MinusGraphPattern(node) => node.syntax(),
+            Self::GraphGraphPattern(node) => node.syntax(),
+            Self::ServiceGraphPattern(node) => node.syntax(),
+            Self::Filter(node) => node.syntax(),
+            Self::Bind(node) => node.syntax(),
+            Self::InlineData(node) => node.syntax(),
+        }
+    }
+}
+/// GroupOrUnionGraphPattern = GroupGraphPattern ('UNION' GroupGraphPattern)*
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GroupOrUnionGraphPattern {
+    syntax: SyntaxNode,
+}
+impl GroupOrUnionGraphPattern {
+    pub fn group_graph_patterns(&self) -> impl Iterator<Item = GroupGraphPattern> {
+        self.syntax.children().filter_map(GroupGraphPattern::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::GroupOrUnionGraphPattern => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// OptionalGraphPattern = 'OPTIONAL' GroupGraphPattern
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OptionalGraphPattern {
+    syntax: SyntaxNode,
+}
+impl OptionalGraphPattern {
+    pub fn group_graph_pattern(&self) -> Option<GroupGraphPattern> {
+        self.syntax.children().find_map(GroupGraphPattern::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::OptionalGraphPattern => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// MinusGraphPattern = 'MINUS' GroupGraphPattern
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MinusGraphPattern {
+    syntax: SyntaxNode,
+}
+impl MinusGraphPattern {
+    pub fn group_graph_pattern(&self) -> Option<GroupGraphPattern> {
+        self.syntax.children().find_map(GroupGraphPattern::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::MinusGraphPattern => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// GraphGraphPattern = 'GRAPH' VarOrIri GroupGraphPattern
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GraphGraphPattern {
+    syntax: SyntaxNode,
+}
+impl GraphGraphPattern {
+    pub fn var_or_iri(&self) -> Option<VarOrIri> {
+        self.syntax.children().find_map(VarOrIri::cast)
+    }
+    pub fn group_graph_pattern(&self) -> Option<GroupGraphPattern> {
+        self.syntax.children().find_map(GroupGraphPattern::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::GraphGraphPattern => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// ServiceGraphPattern = 'SERVICE' silent:'SILENT'? VarOrIri GroupGraphPattern
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ServiceGraphPattern {
+    syntax: SyntaxNode,
+}
+impl ServiceGraphPattern {
+    pub fn silent(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::SILENT])
+    }
+    pub fn var_or_iri(&self) -> Option<VarOrIri> {
+        self.syntax.children().find_map(VarOrIri::cast)
+    }
+    pub fn group_graph_pattern(&self) -> Option<GroupGraphPattern> {
+        self.syntax.children().find_map(GroupGraphPattern::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::ServiceGraphPattern => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// Filter = 'FILTER' Constraint
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Filter {
+    syntax: SyntaxNode,
+}
+impl Filter {
+    pub fn constraint(&self) -> Option<Constraint> {
+        self.syntax.children().find_map(Constraint::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::Filter => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// Bind = 'BIND' '(' Expression 'AS' Var ')'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Bind {
+    syntax: SyntaxNode,
+}
+impl Bind {
+    pub fn expression(&self) -> Option<Expression> {
+        self.syntax.children().find_map(Expression::cast)
+    }
+    pub fn var(&self) -> Option<Var> {
+        self.syntax.children().find_map(Var::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::Bind => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// InlineData = 'VALUES' DataBlock
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InlineData {
+    syntax: SyntaxNode,
+}
+impl InlineData {
+    pub fn data_block(&self) -> Option<DataBlock> {
+        self.syntax.children().find_map(DataBlock::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::InlineData => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// InlineDataOneVar = Var '{' DataBlockValue* '}'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InlineDataOneVar {
+    syntax: SyntaxNode,
+}
+impl InlineDataOneVar {
+    pub fn var(&self) -> Option<Var> {
+        self.syntax.children().find_map(Var::cast)
+    }
+    pub fn data_block_values(&self) -> impl Iterator<Item = DataBlockValue> {
+        self.syntax.children().filter_map(DataBlockValue::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::InlineDataOneVar => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// InlineDataFull = ('NIL' | '(' Var* ')') '{' ('(' DataBlockValue* ')' | 'NIL')* '}'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InlineDataFull {
+    syntax: SyntaxNode,
+}
+impl InlineDataFull {
+    pub fn vars(&self) -> impl Iterator<Item = Var> {
+        self.syntax.children().filter_map(Var::cast)
+    }
+    pub fn data_block_values(&self) -> impl Iterator<Item = DataBlockValue> {
+        self.syntax.children().filter_map(DataBlockValue::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::InlineDataFull => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// DataBlockValue = iri | RDFLiteral | NumericLiteral | BooleanLiteral | undef:'UNDEF'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DataBlockValue {
+    syntax: SyntaxNode,
+}
+impl DataBlockValue {
+    pub fn iri(&self) -> Option<Iri> {
+        self.syntax.children().find_map(Iri::cast)
+    }
+    pub fn rdf_literal(&self) -> Option<RDFLiteral> {
+        self.syntax.children().find_map(RDFLiteral::cast)
+    }
+    pub fn numeric_literal(&self) -> Option<NumericLiteral> {
+        self.syntax.children().find_map(NumericLiteral::cast)
+    }
+    pub fn boolean_literal(&self) -> Option<BooleanLiteral> {
+        self.syntax.children().find_map(BooleanLiteral::cast)
+    }
+    pub fn undef(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::UNDEF])
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::DataBlockValue => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// RDFLiteral = String (lang:'LANGTAG' | '^^' datatype:iri)?
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RDFLiteral {
+    syntax: SyntaxNode,
+}
+impl RDFLiteral {
+    pub fn string(&self) -> Option<String> {
+        self.syntax.children().find_map(String::cast)
+    }
+    pub fn lang(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::LANGTAG])
+    }
+    pub fn datatype(&self) -> Option<Iri> {
+        self.syntax.children().find_map(Iri::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::RDFLiteral => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// NumericLiteral = NumericLiteralUnsigned | NumericLiteralPositive | NumericLiteralNegative
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NumericLiteral {
+    syntax: SyntaxNode,
+}
+impl NumericLiteral {
+    pub fn numeric_literal_unsigned(&self) -> Option<NumericLiteralUnsigned> {
+        self.syntax
+            .children()
+            .find_map(NumericLiteralUnsigned::cast)
+    }
+    pub fn numeric_literal_positive(&self) -> Option<NumericLiteralPositive> {
+        self.syntax
+            .children()
+            .find_map(NumericLiteralPositive::cast)
+    }
+    pub fn numeric_literal_negative(&self) -> Option<NumericLiteralNegative> {
+        self.syntax
+            .children()
+            .find_map(NumericLiteralNegative::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::NumericLiteral => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// BooleanLiteral = value:('true' | 'false')
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BooleanLiteral {
+    syntax: SyntaxNode,
+}
+impl BooleanLiteral {
+    pub fn value(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::True, SyntaxKind::False])
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::BooleanLiteral => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// ArgList = 'NIL' | '(' distinct:'DISTINCT'? Expression (',' Expression)* ')'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ArgList {
+    syntax: SyntaxNode,
+}
+impl ArgList {
+    pub fn distinct(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::DISTINCT])
+    }
+    pub fn expressions(&self) -> impl Iterator<Item = Expression> {
+        self.syntax.children().filter_map(Expression::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::ArgList => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// ExpressionList = 'NIL' | '(' Expression (',' Expression)* ')'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExpressionList {
+    syntax: SyntaxNode,
+}
+impl ExpressionList {
+    pub fn expressions(&self) -> impl Iterator<Item = Expression> {
+        self.syntax.children().filter_map(Expression::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::ExpressionList => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// ConstructTriples = TriplesSameSubject ('.' TriplesSameSubject)* '.'?
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ConstructTriples {
+    syntax: SyntaxNode,
+}
+impl ConstructTriples {
+    pub fn triples_same_subjects(&self) -> impl Iterator<Item = TriplesSameSubject> {
+        self.syntax.children().filter_map(TriplesSameSubject::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::ConstructTriples => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// VarOrTerm = Var | GraphTerm
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VarOrTerm {
+    syntax: SyntaxNode,
+}
+impl VarOrTerm {
+    pub fn var(&self) -> Option<Var> {
+        self.syntax.children().find_map(Var::cast)
+    }
+    pub fn graph_term(&self) -> Option<GraphTerm> {
+        self.syntax.children().find_map(GraphTerm::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::VarOrTerm => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// PropertyListNotEmpty = Verb ObjectList (';' (Verb ObjectList)?)*
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PropertyListNotEmpty {
+    syntax: SyntaxNode,
+}
+impl PropertyListNotEmpty {
+    pub fn verbs(&self) -> impl Iterator<Item = Verb> {
+        self.syntax.children().filter_map(Verb::cast)
+    }
+    pub fn object_lists(&self) -> impl Iterator<Item = ObjectList> {
+        self.syntax.children().filter_map(ObjectList::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::PropertyListNotEmpty => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// TriplesNode = Collection | BlankNodePropertyList
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TriplesNode {
+    syntax: SyntaxNode,
+}
+impl TriplesNode {
+    pub fn collection(&self) -> Option<Collection> {
+        self.syntax.children().find_map(Collection::cast)
+    }
+    pub fn blank_node_property_list(&self) -> Option<BlankNodePropertyList> {
+        self.syntax.children().find_map(BlankNodePropertyList::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::TriplesNode => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// PropertyList = PropertyListNotEmpty?
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PropertyList {
+    syntax: SyntaxNode,
+}
+impl PropertyList {
+    pub fn property_list_not_empty(&self) -> Option<PropertyListNotEmpty> {
+        self.syntax.children().find_map(PropertyListNotEmpty::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::PropertyList => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// Verb = VarOrIri | a:'a'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Verb {
+    syntax: SyntaxNode,
+}
+impl Verb {
+    pub fn var_or_iri(&self) -> Option<VarOrIri> {
+        self.syntax.children().find_map(VarOrIri::cast)
+    }
+    pub fn a(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::a])
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::Verb => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// ObjectList = Object (',' Object)*
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ObjectList {
+    syntax: SyntaxNode,
+}
+impl ObjectList {
+    pub fn objects(&self) -> impl Iterator<Item = Object> {
+        self.syntax.children().filter_map(Object::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::ObjectList => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// Object = GraphNode
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Object {
+    syntax: SyntaxNode,
+}
+impl Object {
+    pub fn graph_node(&self) -> Option<GraphNode> {
+        self.syntax.children().find_map(GraphNode::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::Object => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// GraphNode = VarOrTerm | TriplesNode
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GraphNode {
+    syntax: SyntaxNode,
+}
+impl GraphNode {
+    pub fn var_or_term(&self) -> Option<VarOrTerm> {
+        self.syntax.children().find_map(VarOrTerm::cast)
+    }
+    pub fn triples_node(&self) -> Option<TriplesNode> {
+        self.syntax.children().find_map(TriplesNode::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::GraphNode => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// PropertyListPathNotEmpty = (VerbPath | VerbSimple) ObjectListPath (';' ((VerbPath | VerbSimple) ObjectList)?)*
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PropertyListPathNotEmpty {
+    syntax: SyntaxNode,
+}
+impl PropertyListPathNotEmpty {
+    pub fn verb_paths(&self) -> impl Iterator<Item = VerbPath> {
+        self.syntax.children().filter_map(VerbPath::cast)
+    }
+    pub fn verb_simples(&self) -> impl Iterator<Item = VerbSimple> {
+        self.syntax.children().filter_map(VerbSimple::cast)
+    }
+    pub fn object_list_path(&self) -> Option<ObjectListPath> {
+        self.syntax.children().find_map(ObjectListPath::cast)
+    }
+    pub fn object_lists(&self) -> impl Iterator<Item = ObjectList> {
+        self.syntax.children().filter_map(ObjectList::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::PropertyListPathNotEmpty => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// TriplesNodePath = CollectionPath | BlankNodePropertyListPath
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TriplesNodePath {
+    syntax: SyntaxNode,
+}
+impl TriplesNodePath {
+    pub fn collection_path(&self) -> Option<CollectionPath> {
+        self.syntax.children().find_map(CollectionPath::cast)
+    }
+    pub fn blank_node_property_list_path(&self) -> Option<BlankNodePropertyListPath> {
+        self.syntax
+            .children()
+            .find_map(BlankNodePropertyListPath::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::TriplesNodePath => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// PropertyListPath = PropertyListPathNotEmpty?
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PropertyListPath {
+    syntax: SyntaxNode,
+}
+impl PropertyListPath {
+    pub fn property_list_path_not_empty(&self) -> Option<PropertyListPathNotEmpty> {
+        self.syntax
+            .children()
+            .find_map(PropertyListPathNotEmpty::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::PropertyListPath => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// VerbPath = Path
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VerbPath {
+    syntax: SyntaxNode,
+}
+impl VerbPath {
+    pub fn path(&self) -> Option<Path> {
+        self.syntax.children().find_map(Path::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::VerbPath => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// VerbSimple = Var
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VerbSimple {
+    syntax: SyntaxNode,
+}
+impl VerbSimple {
+    pub fn var(&self) -> Option<Var> {
+        self.syntax.children().find_map(Var::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::VerbSimple => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// ObjectListPath = ObjectPath (',' ObjectPath)*
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ObjectListPath {
+    syntax: SyntaxNode,
+}
+impl ObjectListPath {
+    pub fn object_paths(&self) -> impl Iterator<Item = ObjectPath> {
+        self.syntax.children().filter_map(ObjectPath::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::ObjectListPath => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// Path = PathAlternative
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Path {
+    syntax: SyntaxNode,
+}
+impl Path {
+    pub fn path_alternative(&self) -> Option<PathAlternative> {
+        self.syntax.children().find_map(PathAlternative::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::Path => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// ObjectPath = GraphNodePath
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ObjectPath {
+    syntax: SyntaxNode,
+}
+impl ObjectPath {
+    pub fn graph_node_path(&self) -> Option<GraphNodePath> {
+        self.syntax.children().find_map(GraphNodePath::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::ObjectPath => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// GraphNodePath = VarOrTerm | TriplesNodePath
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GraphNodePath {
+    syntax: SyntaxNode,
+}
+impl GraphNodePath {
+    pub fn var_or_term(&self) -> Option<VarOrTerm> {
+        self.syntax.children().find_map(VarOrTerm::cast)
+    }
+    pub fn triples_node_path(&self) -> Option<TriplesNodePath> {
+        self.syntax.children().find_map(TriplesNodePath::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::GraphNodePath => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// PathAlternative = PathSequence ('|' PathSequence)*
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PathAlternative {
+    syntax: SyntaxNode,
+}
+impl PathAlternative {
+    pub fn path_sequences(&self) -> impl Iterator<Item = PathSequence> {
+        self.syntax.children().filter_map(PathSequence::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::PathAlternative => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// PathSequence = PathEltOrInverse ('/' PathEltOrInverse)*
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PathSequence {
+    syntax: SyntaxNode,
+}
+impl PathSequence {
+    pub fn path_elt_or_inverses(&self) -> impl Iterator<Item = PathEltOrInverse> {
+        self.syntax.children().filter_map(PathEltOrInverse::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::PathSequence => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// PathEltOrInverse = inverse:'^'? PathElt
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PathEltOrInverse {
+    syntax: SyntaxNode,
+}
+impl PathEltOrInverse {
+    pub fn inverse(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::Zirkumflex])
+    }
+    pub fn path_elt(&self) -> Option<PathElt> {
+        self.syntax.children().find_map(PathElt::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::PathEltOrInverse => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// PathElt = PathPrimary PathMod?
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PathElt {
+    syntax: SyntaxNode,
+}
+impl PathElt {
+    pub fn path_primary(&self) -> Option<PathPrimary> {
+        self.syntax.children().find_map(PathPrimary::cast)
+    }
+    pub fn path_mod(&self) -> Option<PathMod> {
+        self.syntax.children().find_map(PathMod::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::PathElt => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// PathPrimary = iri | a:'a' | '!' PathNegatedPropertySet | '(' Path ')'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PathPrimary {
+    syntax: SyntaxNode,
+}
+impl PathPrimary {
+    pub fn iri(&self) -> Option<Iri> {
+        self.syntax.children().find_map(Iri::cast)
+    }
+    pub fn a(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::a])
+    }
+    pub fn path_negated_property_set(&self) -> Option<PathNegatedPropertySet> {
+        self.syntax
+            .children()
+            .find_map(PathNegatedPropertySet::cast)
+    }
+    pub fn path(&self) -> Option<Path> {
+        self.syntax.children().find_map(Path::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::PathPrimary => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// PathMod = modifier:('?' | '*' | '+')
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PathMod {
+    syntax: SyntaxNode,
+}
+impl PathMod {
+    pub fn modifier(&self) -> Option<SyntaxToken> {
+        token(
+            &self.syntax,
+            &[SyntaxKind::QuestionMark, SyntaxKind::Star, SyntaxKind::Plus],
+        )
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::PathMod => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// PathNegatedPropertySet = PathOneInPropertySet | '(' (PathOneInPropertySet ('|' PathOneInPropertySet)*)? ')'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PathNegatedPropertySet {
+    syntax: SyntaxNode,
+}
+impl PathNegatedPropertySet {
+    pub fn path_one_in_property_sets(&self) -> impl Iterator<Item = PathOneInPropertySet> {
+        self.syntax
+            .children()
+            .filter_map(PathOneInPropertySet::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::PathNegatedPropertySet => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// PathOneInPropertySet = inverse:'^'? (iri | a:'a')
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PathOneInPropertySet {
+    syntax: SyntaxNode,
+}
+impl PathOneInPropertySet {
+    pub fn inverse(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::Zirkumflex])
+    }
+    pub fn iri(&self) -> Option<Iri> {
+        self.syntax.children().find_map(Iri::cast)
+    }
+    pub fn a(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::a])
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::PathOneInPropertySet => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// Integer = value:'INTEGER'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Integer {
+    syntax: SyntaxNode,
+}
+impl Integer {
+    pub fn value(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::INTEGER])
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::Integer => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// Collection = '(' GraphNode* ')'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Collection {
+    syntax: SyntaxNode,
+}
+impl Collection {
+    pub fn graph_nodes(&self) -> impl Iterator<Item = GraphNode> {
+        self.syntax.children().filter_map(GraphNode::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::Collection => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// BlankNodePropertyList = '[' PropertyListNotEmpty ']'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BlankNodePropertyList {
+    syntax: SyntaxNode,
+}
+impl BlankNodePropertyList {
+    pub fn property_list_not_empty(&self) -> Option<PropertyListNotEmpty> {
+        self.syntax.children().find_map(PropertyListNotEmpty::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::BlankNodePropertyList => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// CollectionPath = '(' GraphNodePath* ')'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CollectionPath {
+    syntax: SyntaxNode,
+}
+impl CollectionPath {
+    pub fn graph_node_paths(&self) -> impl Iterator<Item = GraphNodePath> {
+        self.syntax.children().filter_map(GraphNodePath::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::CollectionPath => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// BlankNodePropertyListPath = '[' PropertyListPathNotEmpty ']'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BlankNodePropertyListPath {
+    syntax: SyntaxNode,
+}
+impl BlankNodePropertyListPath {
+    pub fn property_list_path_not_empty(&self) -> Option<PropertyListPathNotEmpty> {
+        self.syntax
+            .children()
+            .find_map(PropertyListPathNotEmpty::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::BlankNodePropertyListPath => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// GraphTerm = iri | RDFLiteral | NumericLiteral | BooleanLiteral | BlankNode | nil:'NIL'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GraphTerm {
+    syntax: SyntaxNode,
+}
+impl GraphTerm {
+    pub fn iri(&self) -> Option<Iri> {
+        self.syntax.children().find_map(Iri::cast)
+    }
+    pub fn rdf_literal(&self) -> Option<RDFLiteral> {
+        self.syntax.children().find_map(RDFLiteral::cast)
+    }
+    pub fn numeric_literal(&self) -> Option<NumericLiteral> {
+        self.syntax.children().find_map(NumericLiteral::cast)
+    }
+    pub fn boolean_literal(&self) -> Option<BooleanLiteral> {
+        self.syntax.children().find_map(BooleanLiteral::cast)
+    }
+    pub fn blank_node(&self) -> Option<BlankNode> {
+        self.syntax.children().find_map(BlankNode::cast)
+    }
+    pub fn nil(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::NIL])
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::GraphTerm => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// BlankNode = label:('BLANK_NODE_LABEL' | 'ANON')
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BlankNode {
+    syntax: SyntaxNode,
+}
+impl BlankNode {
+    pub fn label(&self) -> Option<SyntaxToken> {
+        token(
+            &self.syntax,
+            &[SyntaxKind::BLANK_NODE_LABEL, SyntaxKind::ANON],
+        )
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::BlankNode => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// ConditionalOrExpression = ConditionalAndExpression ('||' ConditionalAndExpression)*
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ConditionalOrExpression {
+    syntax: SyntaxNode,
+}
+impl ConditionalOrExpression {
+    pub fn conditional_and_expressions(&self) -> impl Iterator<Item = ConditionalAndExpression> {
+        self.syntax
+            .children()
+            .filter_map(ConditionalAndExpression::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::ConditionalOrExpression => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// Expr = BinaryExpr | UnaryExpr | InExpr | PrimaryExpression | NumericLiteralPositive | NumericLiteralNegative
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Expr {
+    BinaryExpr(BinaryExpr),
+    UnaryExpr(UnaryExpr),
+    InExpr(InExpr),
+    PrimaryExpression(PrimaryExpression),
+    NumericLiteralPositive(NumericLiteralPositive),
+    NumericLiteralNegative(NumericLiteralNegative),
+}
+impl Expr {
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::BinaryExpr => Some(Self::BinaryExpr(BinaryExpr { syntax: node })),
+            SyntaxKind::UnaryExpr => Some(Self::UnaryExpr(UnaryExpr { syntax: node })),
+            SyntaxKind::InExpr => Some(Self::InExpr(InExpr { syntax: node })),
+            SyntaxKind::PrimaryExpression => {
+                Some(Self::PrimaryExpression(PrimaryExpression { syntax: node }))
+            }
+            SyntaxKind::NumericLiteralPositive => {
+                Some(Self::NumericLiteralPositive(NumericLiteralPositive {
+                    syntax: node,
+                }))
+            }
+            SyntaxKind::NumericLiteralNegative => {
+                Some(Self::NumericLiteralNegative(NumericLiteralNegative {
+                    syntax: node,
+                }))
+            }
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        match self {
+            Self::BinaryExpr(node) => node.syntax(),
+            Self::UnaryExpr(node) => node.syntax(),
+            Self::InExpr(node) => node.syntax(),
+            Self::PrimaryExpression(node) => node.syntax(),
+            Self::NumericLiteralPositive(node) => node.syntax(),
+            Self::NumericLiteralNegative(node) => node.syntax(),
+        }
+    }
+}
+/// BinaryExpr = lhs:Expr op:('||' | '&&' | '=' | '!=' | '<' | '>' | '<=' | '>=' | '+' | '-' | '*' | '/')? rhs:Expr
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BinaryExpr {
+    syntax: SyntaxNode,
+}
+impl BinaryExpr {
+    pub fn lhs(&self) -> Option<Expr> {
+        self.syntax.children().filter_map(Expr::cast).nth(0usize)
+    }
+    pub fn op(&self) -> Option<SyntaxToken> {
+        token(
+            &self.syntax,
+            &[
+                SyntaxKind::DoublePipe,
+                SyntaxKind::DoubleAnd,
+                SyntaxKind::Equals,
+                SyntaxKind::ExclamationMarkEquals,
+                SyntaxKind::Less,
+                SyntaxKind::More,
+                SyntaxKind::LessEquals,
+                SyntaxKind::MoreEquals,
+                SyntaxKind::Plus,
+                SyntaxKind::Minus,
+                SyntaxKind::Star,
+                SyntaxKind::Slash,
+            ],
+        )
+    }
+    pub fn rhs(&self) -> Option<Expr> {
+        self.syntax.children().filter_map(Expr::cast).nth(1usize)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::BinaryExpr => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// UnaryExpr = op:('!' | '+' | '-') PrimaryExpression
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UnaryExpr {
+    syntax: SyntaxNode,
+}
+impl UnaryExpr {
+    pub fn op(&self) -> Option<SyntaxToken> {
+        token(
+            &self.syntax,
+            &[
+                SyntaxKind::ExclamationMark,
+                SyntaxKind::Plus,
+                SyntaxKind::Minus,
+            ],
+        )
+    }
+    pub fn primary_expression(&self) -> Option<PrimaryExpression> {
+        self.syntax.children().find_map(PrimaryExpression::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::UnaryExpr => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// InExpr = Expr not:'NOT'? 'IN' ExpressionList
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InExpr {
+    syntax: SyntaxNode,
+}
+impl InExpr {
+    pub fn expr(&self) -> Option<Expr> {
+        self.syntax.children().find_map(Expr::cast)
+    }
+    pub fn not(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::NOT])
+    }
+    pub fn expression_list(&self) -> Option<ExpressionList> {
+        self.syntax.children().find_map(ExpressionList::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::InExpr => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// PrimaryExpression = BrackettedExpression | BuiltInCall | iriOrFunction | RDFLiteral | NumericLiteral | BooleanLiteral | Var
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PrimaryExpression {
+    syntax: SyntaxNode,
+}
+impl PrimaryExpression {
+    pub fn bracketted_expression(&self) -> Option<BrackettedExpression> {
+        self.syntax.children().find_map(BrackettedExpression::cast)
+    }
+    pub fn built_in_call(&self) -> Option<BuiltInCall> {
+        self.syntax.children().find_map(BuiltInCall::cast)
+    }
+    pub fn iri_or_function(&self) -> Option<IriOrFunction> {
+        self.syntax.children().find_map(IriOrFunction::cast)
+    }
+    pub fn rdf_literal(&self) -> Option<RDFLiteral> {
+        self.syntax.children().find_map(RDFLiteral::cast)
+    }
+    pub fn numeric_literal(&self) -> Option<NumericLiteral> {
+        self.syntax.children().find_map(NumericLiteral::cast)
+    }
+    pub fn boolean_literal(&self) -> Option<BooleanLiteral> {
+        self.syntax.children().find_map(BooleanLiteral::cast)
+    }
+    pub fn var(&self) -> Option<Var> {
+        self.syntax.children().find_map(Var::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::PrimaryExpression => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// NumericLiteralPositive = value:('INTEGER_POSITIVE' | 'DECIMAL_POSITIVE' | 'DOUBLE_POSITIVE')
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NumericLiteralPositive {
+    syntax: SyntaxNode,
+}
+impl NumericLiteralPositive {
+    pub fn value(&self) -> Option<SyntaxToken> {
+        token(
+            &self.syntax,
+            &[
+                SyntaxKind::INTEGER_POSITIVE,
+                SyntaxKind::DECIMAL_POSITIVE,
+                SyntaxKind::DOUBLE_POSITIVE,
+            ],
+        )
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::NumericLiteralPositive => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// NumericLiteralNegative = value:('INTEGER_NEGATIVE' | 'DECIMAL_NEGATIVE' | 'DOUBLE_NEGATIVE')
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NumericLiteralNegative {
+    syntax: SyntaxNode,
+}
+impl NumericLiteralNegative {
+    pub fn value(&self) -> Option<SyntaxToken> {
+        token(
+            &self.syntax,
+            &[
+                SyntaxKind::INTEGER_NEGATIVE,
+                SyntaxKind::DECIMAL_NEGATIVE,
+                SyntaxKind::DOUBLE_NEGATIVE,
+            ],
+        )
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::NumericLiteralNegative => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// ConditionalAndExpression = ValueLogical ('&&' ValueLogical)*
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ConditionalAndExpression {
+    syntax: SyntaxNode,
+}
+impl ConditionalAndExpression {
+    pub fn value_logicals(&self) -> impl Iterator<Item = ValueLogical> {
+        self.syntax.children().filter_map(ValueLogical::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::ConditionalAndExpression => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// ValueLogical = RelationalExpression
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ValueLogical {
+    syntax: SyntaxNode,
+}
+impl ValueLogical {
+    pub fn relational_expression(&self) -> Option<RelationalExpression> {
+        self.syntax.children().find_map(RelationalExpression::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::ValueLogical => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// RelationalExpression = lhs:NumericExpression (op:('=' | '!=' | '<' | '>' | '<=' | '>=') rhs:NumericExpression | not:'NOT'? 'IN' ExpressionList)?
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RelationalExpression {
+    syntax: SyntaxNode,
+}
+impl RelationalExpression {
+    pub fn lhs(&self) -> Option<NumericExpression> {
+        self.syntax
+            .children()
+            .filter_map(NumericExpression::cast)
+            .nth(0usize)
+    }
+    pub fn op(&self) -> Option<SyntaxToken> {
+        token(
+            &self.syntax,
+            &[
+                SyntaxKind::Equals,
+                SyntaxKind::ExclamationMarkEquals,
+                SyntaxKind::Less,
+                SyntaxKind::More,
+                SyntaxKind::LessEquals,
+                SyntaxKind::MoreEquals,
+            ],
+        )
+    }
+    pub fn rhs(&self) -> Option<NumericExpression> {
+        self.syntax
+            .children()
+            .filter_map(NumericExpression::cast)
+            .nth(1usize)
+    }
+    pub fn not(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::NOT])
+    }
+    pub fn expression_list(&self) -> Option<ExpressionList> {
+        self.syntax.children().find_map(ExpressionList::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::RelationalExpression => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// NumericExpression = AdditiveExpression
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NumericExpression {
+    syntax: SyntaxNode,
+}
+impl NumericExpression {
+    pub fn additive_expression(&self) -> Option<AdditiveExpression> {
+        self.syntax.children().find_map(AdditiveExpression::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::NumericExpression => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// AdditiveExpression = MultiplicativeExpression (('+' | '-') MultiplicativeExpression | (NumericLiteralPositive | NumericLiteralNegative) (('*' | '/') UnaryExpression)*)*
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AdditiveExpression {
+    syntax: SyntaxNode,
+}
+impl AdditiveExpression {
+    pub fn multiplicative_expressions(&self) -> impl Iterator<Item = MultiplicativeExpression> {
+        self.syntax
+            .children()
+            .filter_map(MultiplicativeExpression::cast)
+    }
+    pub fn numeric_literal_positives(&self) -> impl Iterator<Item = NumericLiteralPositive> {
+        self.syntax
+            .children()
+            .filter_map(NumericLiteralPositive::cast)
+    }
+    pub fn numeric_literal_negatives(&self) -> impl Iterator<Item = NumericLiteralNegative> {
+        self.syntax
+            .children()
+            .filter_map(NumericLiteralNegative::cast)
+    }
+    pub fn unary_expressions(&self) -> impl Iterator<Item = UnaryExpression> {
+        self.syntax.children().filter_map(UnaryExpression::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::AdditiveExpression => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// MultiplicativeExpression = UnaryExpression (('*' | '/') UnaryExpression)*
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MultiplicativeExpression {
+    syntax: SyntaxNode,
+}
+impl MultiplicativeExpression {
+    pub fn unary_expressions(&self) -> impl Iterator<Item = UnaryExpression> {
+        self.syntax.children().filter_map(UnaryExpression::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::MultiplicativeExpression => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// UnaryExpression = op:('!' | '+' | '-')? PrimaryExpression
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UnaryExpression {
+    syntax: SyntaxNode,
+}
+impl UnaryExpression {
+    pub fn op(&self) -> Option<SyntaxToken> {
+        token(
+            &self.syntax,
+            &[
+                SyntaxKind::ExclamationMark,
+                SyntaxKind::Plus,
+                SyntaxKind::Minus,
+            ],
+        )
+    }
+    pub fn primary_expression(&self) -> Option<PrimaryExpression> {
+        self.syntax.children().find_map(PrimaryExpression::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::UnaryExpression => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// iriOrFunction = iri ArgList?
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IriOrFunction {
+    syntax: SyntaxNode,
+}
+impl IriOrFunction {
+    pub fn iri(&self) -> Option<Iri> {
+        self.syntax.children().find_map(Iri::cast)
+    }
+    pub fn arg_list(&self) -> Option<ArgList> {
+        self.syntax.children().find_map(ArgList::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::iriOrFunction => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// Aggregate = function:('COUNT' | 'SUM' | 'MIN' | 'MAX' | 'AVG' | 'SAMPLE' | 'GROUP_CONCAT') '(' distinct:'DISTINCT'? (star:'*' | Expression) (';' 'SEPARATOR' '=' separator:String)? ')'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Aggregate {
+    syntax: SyntaxNode,
+}
+impl Aggregate {
+    pub fn function(&self) -> Option<SyntaxToken> {
+        token(
+            &self.syntax,
+            &[
+                SyntaxKind::COUNT,
+                SyntaxKind::SUM,
+                SyntaxKind::MIN,
+                SyntaxKind::MAX,
+                SyntaxKind::AVG,
+                SyntaxKind::SAMPLE,
+                SyntaxKind::GROUP_CONCAT,
+            ],
+        )
+    }
+    pub fn distinct(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::DISTINCT])
+    }
+    pub fn star(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::Star])
+    }
+    pub fn expression(&self) -> Option<Expression> {
+        self.syntax.children().find_map(Expression::cast)
+    }
+    pub fn separator(&self) -> Option<String> {
+        self.syntax.children().find_map(String::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::Aggregate => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// SubstringExpression = 'SUBSTR' '(' source:Expression ',' start:Expression (',' length:Expression)? ')'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SubstringExpression {
+    syntax: SyntaxNode,
+}
+impl SubstringExpression {
+    pub fn source(&self) -> Option<Expression> {
+        self.syntax
+            .children()
+            .filter_map(Expression::cast)
+            .nth(0usize)
+    }
+    pub fn start(&self) -> Option<Expression> {
+        self.syntax
+            .children()
+            .filter_map(Expression::cast)
+            .nth(1usize)
+    }
+    pub fn length(&self) -> Option<Expression> {
+        self.syntax
+            .children()
+            .filter_map(Expression::cast)
+            .nth(2usize)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::SubstringExpression => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// StrReplaceExpression = 'REPLACE' '(' arg:Expression ',' pattern:Expression ',' replacement:Expression (',' flags:Expression)? ')'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StrReplaceExpression {
+    syntax: SyntaxNode,
+}
+impl StrReplaceExpression {
+    pub fn arg(&self) -> Option<Expression> {
+        self.syntax
+            .children()
+            .filter_map(Expression::cast)
+            .nth(0usize)
+    }
+    pub fn pattern(&self) -> Option<Expression> {
+        self.syntax
+            .children()
+            .filter_map(Expression::cast)
+            .nth(1usize)
+    }
+    pub fn replacement(&self) -> Option<Expression> {
+        self.syntax
+            .children()
+            .filter_map(Expression::cast)
+            .nth(2usize)
+    }
+    pub fn flags(&self) -> Option<Expression> {
+        self.syntax
+            .children()
+            .filter_map(Expression::cast)
+            .nth(3usize)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::StrReplaceExpression => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// RegexExpression = 'REGEX' '(' text:Expression ',' pattern:Expression (',' flags:Expression)? ')'
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RegexExpression {
+    syntax: SyntaxNode,
+}
+impl RegexExpression {
+    pub fn text(&self) -> Option<Expression> {
+        self.syntax
+            .children()
+            .filter_map(Expression::cast)
+            .nth(0usize)
+    }
+    pub fn pattern(&self) -> Option<Expression> {
+        self.syntax
+            .children()
+            .filter_map(Expression::cast)
+            .nth(1usize)
+    }
+    pub fn flags(&self) -> Option<Expression> {
+        self.syntax
+            .children()
+            .filter_map(Expression::cast)
+            .nth(2usize)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::RegexExpression => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// ExistsFunc = 'EXISTS' GroupGraphPattern
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExistsFunc {
+    syntax: SyntaxNode,
+}
+impl ExistsFunc {
+    pub fn group_graph_pattern(&self) -> Option<GroupGraphPattern> {
+        self.syntax.children().find_map(GroupGraphPattern::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::ExistsFunc => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// NotExistsFunc = 'NOT' 'EXISTS' GroupGraphPattern
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NotExistsFunc {
+    syntax: SyntaxNode,
+}
+impl NotExistsFunc {
+    pub fn group_graph_pattern(&self) -> Option<GroupGraphPattern> {
+        self.syntax.children().find_map(GroupGraphPattern::cast)
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::NotExistsFunc => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// String = value:('STRING_LITERAL1' | 'STRING_LITERAL2' | 'STRING_LITERAL_LONG1' | 'STRING_LITERAL_LONG2')
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct String {
+    syntax: SyntaxNode,
+}
+impl String {
+    pub fn value(&self) -> Option<SyntaxToken> {
+        token(
+            &self.syntax,
+            &[
+                SyntaxKind::STRING_LITERAL1,
+                SyntaxKind::STRING_LITERAL2,
+                SyntaxKind::STRING_LITERAL_LONG1,
+                SyntaxKind::STRING_LITERAL_LONG2,
+            ],
+        )
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::String => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// NumericLiteralUnsigned = value:('INTEGER' | 'DECIMAL' | 'DOUBLE')
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NumericLiteralUnsigned {
+    syntax: SyntaxNode,
+}
+impl NumericLiteralUnsigned {
+    pub fn value(&self) -> Option<SyntaxToken> {
+        token(
+            &self.syntax,
+            &[SyntaxKind::INTEGER, SyntaxKind::DECIMAL, SyntaxKind::DOUBLE],
+        )
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::NumericLiteralUnsigned => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+/// PrefixedName = name:('PNAME_LN' | 'PNAME_NS')
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PrefixedName {
+    syntax: SyntaxNode,
+}
+impl PrefixedName {
+    pub fn name(&self) -> Option<SyntaxToken> {
+        token(&self.syntax, &[SyntaxKind::PNAME_LN, SyntaxKind::PNAME_NS])
+    }
+    pub fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::PrefixedName => Some(Self { syntax: node }),
+            _ => None,
+        }
+    }
+    pub fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
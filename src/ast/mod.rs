@@ -1,29 +1,16 @@
-use crate::{print_full_tree, syntax_kind::SyntaxKind, SyntaxNode};
+mod generated;
 
-#[derive(Debug)]
-pub struct TriplesBlock {
-    raw: SyntaxNode,
-}
+use crate::{parser::unescape, syntax_kind::SyntaxKind, SyntaxNode, SyntaxToken};
+
+pub use generated::*;
 
 impl TriplesBlock {
+    /// The triples of this block, separated by `.`.
     pub fn triples(&self) -> Vec<Triples> {
-        println!("{}", print_full_tree(&self.raw, 0));
-        self.raw
-            .children()
-            .filter_map(|child| match child.kind() {
-                SyntaxKind::TriplesSameSubjectPath => Some(vec![Triples::cast(child).unwrap()]),
-                SyntaxKind::TriplesBlock => Some(TriplesBlock::cast(child).unwrap().triples()),
-                _ => None,
-            })
-            .flatten()
+        self.triples_same_subject_paths()
+            .filter_map(|node| Triples::cast(node.syntax().clone()))
             .collect()
     }
-    pub fn cast(node: SyntaxNode) -> Option<Self> {
-        match node.kind() {
-            SyntaxKind::TriplesBlock => Some(Self { raw: node }),
-            _ => None,
-        }
-    }
 }
 
 #[derive(Debug)]
@@ -33,10 +20,7 @@ pub struct Triples {
 
 impl Triples {
     pub fn subject(&self) -> Option<VarOrTerm> {
-        self.raw
-            .first_child()
-            .map(|child| VarOrTerm::cast(child))
-            .flatten()
+        self.raw.first_child().and_then(VarOrTerm::cast)
     }
 
     pub fn cast(node: SyntaxNode) -> Option<Self> {
@@ -47,18 +31,48 @@ impl Triples {
     }
 }
 
-#[derive(Debug)]
-pub struct VarOrTerm {
-    raw: SyntaxNode,
-}
+impl PrefixedName {
+    /// The prefix without the trailing `:`.
+    pub fn prefix(&self) -> std::string::String {
+        let text = self.text();
+        text[..text.find(':').unwrap_or(text.len())].to_string()
+    }
 
-impl VarOrTerm {
-    pub fn cast(node: SyntaxNode) -> Option<Self> {
-        match node.kind() {
-            SyntaxKind::VarOrTerm => Some(Self { raw: node }),
-            _ => None,
+    /// The local name with its backslash escapes removed, e.g. `foo,bar` for `ex:foo\,bar`.
+    /// Percent-encoded characters are part of the IRI and are kept as written.
+    pub fn local_name(&self) -> std::string::String {
+        let text = self.text();
+        let local = &text[text.find(':').map_or(text.len(), |index| index + 1)..];
+        let mut name = std::string::String::with_capacity(local.len());
+        let mut chars = local.chars();
+        while let Some(char) = chars.next() {
+            match char {
+                '\\' => name.extend(chars.next()),
+                _ => name.push(char),
+            }
         }
+        name
     }
+
+    /// The name with its codepoint escapes replaced, which apply before `\` escapes.
+    fn text(&self) -> std::string::String {
+        let Some(token) = self.name() else {
+            return std::string::String::new();
+        };
+        match unescape(token.text()) {
+            Some((unescaped, _)) => unescaped,
+            None => token.text().to_string(),
+        }
+    }
+}
+
+/// The first child token of `parent` of any of the `kinds`.
+fn token(parent: &SyntaxNode, kinds: &[SyntaxKind]) -> Option<SyntaxToken> {
+    parent
+        .children_with_tokens()
+        .filter_map(|child| child.into_token())
+        .find(|token| kinds.contains(&token.kind()))
 }
 
+#[cfg(test)]
 mod test;
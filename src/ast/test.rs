@@ -1,25 +1,148 @@
-use rowan::TextRange;
-
-use crate::{ast::TriplesBlock, parse_query, print_full_tree, SyntaxNode};
-
-fn walk(node: SyntaxNode, mut path: Vec<usize>) -> Option<SyntaxNode> {
-    if path.is_empty() {
-        return Some(node);
-    }
-    let head = path.remove(0);
-    if let Some(child) = node.children().nth(head) {
-        return walk(child, path);
-    }
-    return None;
+use crate::{
+    ast::{
+        BinaryExpr, Expr, Operation, PrefixDecl, PrefixedName, QueryForm, QueryUnit,
+        StrReplaceExpression, UpdateUnit,
+    },
+    parse_query, parse_update, parse_with_options, ParseOptions, SyntaxNode,
+};
+
+fn text(node: &SyntaxNode) -> String {
+    node.text().to_string().trim().to_string()
 }
 
 #[test]
 fn triples_block() {
     let input = "SELECT * { ?s ?p ?o . ?a ?b ?c .     ?x ?y ?z}";
-    let root = parse_query(input);
-    let node = walk(root, vec![0, 0, 1, 0, 0, 0]).unwrap();
-    let triples_block = TriplesBlock::cast(node).unwrap();
-    triples_block.triples();
-    println!("{:?}", triples_block.triples());
-    println!("{:?}", triples_block.triples()[0].subject());
+    let root = QueryUnit::cast(parse_query(input)).unwrap();
+    let Some(QueryForm::SelectQuery(query)) = root.query().and_then(|query| query.query_form())
+    else {
+        panic!("expected a select query");
+    };
+    let triples_block = query
+        .where_clause()
+        .and_then(|clause| clause.group_graph_pattern())
+        .and_then(|pattern| pattern.group_graph_pattern_sub())
+        .and_then(|sub| sub.triples_blocks().next())
+        .unwrap();
+    let subjects: Vec<_> = triples_block
+        .triples()
+        .iter()
+        .map(|triples| text(triples.subject().unwrap().syntax()))
+        .collect();
+    assert_eq!(subjects, ["?s", "?a", "?x"]);
+}
+
+#[test]
+fn query_forms() {
+    let forms: Vec<_> = [
+        "SELECT * {}",
+        "CONSTRUCT {} WHERE {}",
+        "DESCRIBE <a>",
+        "ASK {}",
+    ]
+    .into_iter()
+    .map(|input| {
+        QueryUnit::cast(parse_query(input))
+            .and_then(|root| root.query())
+            .and_then(|query| query.query_form())
+            .unwrap()
+    })
+    .collect();
+    assert!(matches!(
+        forms.as_slice(),
+        [
+            QueryForm::SelectQuery(_),
+            QueryForm::ConstructQuery(_),
+            QueryForm::DescribeQuery(_),
+            QueryForm::AskQuery(_)
+        ]
+    ));
+}
+
+#[test]
+fn labeled_children() {
+    let root =
+        parse_query("PREFIX ex: <http://ex/> SELECT * { BIND(REPLACE(?a, 'x', 'y') AS ?b) }");
+    let prefix = root.descendants().find_map(PrefixDecl::cast).unwrap();
+    assert_eq!(prefix.prefix().unwrap().text(), "ex:");
+    assert_eq!(prefix.iri().unwrap().text(), "<http://ex/>");
+
+    let replace = root
+        .descendants()
+        .find_map(StrReplaceExpression::cast)
+        .unwrap();
+    let arguments = [replace.arg(), replace.pattern(), replace.replacement()]
+        .map(|argument| text(argument.unwrap().syntax()));
+    assert_eq!(arguments, ["?a", "'x'", "'y'"]);
+    assert!(replace.flags().is_none());
+
+    let root = UpdateUnit::cast(parse_update("ADD SILENT GRAPH <a> TO DEFAULT")).unwrap();
+    let operation = root
+        .update()
+        .and_then(|update| update.update_ones().next())
+        .and_then(|update| update.operation());
+    let Some(Operation::Add(add)) = operation else {
+        panic!("expected an add operation");
+    };
+    assert!(add.silent().is_some());
+    assert_eq!(text(add.source().unwrap().syntax()), "GRAPH <a>");
+    assert!(add.target().unwrap().default().is_some());
+}
+
+#[test]
+fn compact_expressions() {
+    let options = ParseOptions {
+        compact_expressions: true,
+        ..ParseOptions::default()
+    };
+    let root = parse_with_options("SELECT * { FILTER(?a + 1 * ?b) }", None, options).syntax();
+    let sum = root.descendants().find_map(BinaryExpr::cast).unwrap();
+    assert_eq!(sum.op().unwrap().text(), "+");
+    assert!(matches!(sum.lhs(), Some(Expr::PrimaryExpression(_))));
+    let Some(Expr::BinaryExpr(product)) = sum.rhs() else {
+        panic!("expected a product");
+    };
+    assert_eq!(product.op().unwrap().text(), "*");
+    assert_eq!(text(product.rhs().unwrap().syntax()), "?b");
+
+    // The sign of a literal after an operand is the operator.
+    let root = parse_with_options("SELECT * { FILTER(?a -1) }", None, options).syntax();
+    let difference = root.descendants().find_map(BinaryExpr::cast).unwrap();
+    assert_eq!(difference.op().unwrap().text(), "-");
+    assert_eq!(text(difference.lhs().unwrap().syntax()), "?a");
+    assert_eq!(text(difference.rhs().unwrap().syntax()), "1");
+}
+
+#[test]
+fn prefixed_name_local_name() {
+    let input = r"SELECT * { ex:foo\,bar ex:caf%C3%A9 :a:b . ex:a\.b\~c ex: ex:x.y }";
+    let names: Vec<_> = parse_query(input)
+        .descendants()
+        .filter_map(PrefixedName::cast)
+        .map(|name| (name.prefix(), name.local_name()))
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            ("ex".to_string(), "foo,bar".to_string()),
+            ("ex".to_string(), "caf%C3%A9".to_string()),
+            ("".to_string(), "a:b".to_string()),
+            ("ex".to_string(), "a.b~c".to_string()),
+            ("ex".to_string(), "".to_string()),
+            ("ex".to_string(), "x.y".to_string()),
+        ]
+    );
+    let input = r"SELECT * { \u0065x:\u0041bc ex:a\U00000042\,c }";
+    let names: Vec<_> = parse_query(input)
+        .descendants()
+        .filter_map(PrefixedName::cast)
+        .map(|name| (name.prefix(), name.local_name()))
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            ("ex".to_string(), "Abc".to_string()),
+            ("ex".to_string(), "aB,c".to_string()),
+        ]
+    );
 }
@@ -5,20 +5,71 @@ mod syntax_node;
 
 #[cfg(target_arch = "wasm32")]
 use js_sys::{Array, Object, Reflect};
+pub use rowan::NodeCache;
 #[cfg(target_arch = "wasm32")]
 use rowan::TextSize;
 use syntax_kind::SyntaxKind;
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
 
+pub use parser::{
+    Diagnostic, DocumentKind, LexError, LexErrorKind, Parse, ParseOptions, Severity, TextEdit,
+    Tokenizer, TopEntryPoint, WRAPPER_KINDS,
+};
 pub use syntax_node::*;
 
+/// Splits `input` into tokens, see [`Tokenizer`].
+pub fn tokenize(input: &str) -> Tokenizer<'_> {
+    Tokenizer::new(input)
+}
+
+/// Parses `input` as a query or an update, whichever its first keyword after
+/// the prologue starts. The choice is reported by [`Parse::document_kind`].
+pub fn parse(input: &str) -> Parse {
+    parser::parse_document(input)
+}
+
 pub fn parse_query(input: &str) -> SyntaxNode {
-    SyntaxNode::new_root(parser::parse_text(input, parser::TopEntryPoint::QueryUnit))
+    parse_query_with_diagnostics(input).syntax()
 }
 
 pub fn parse_update(input: &str) -> SyntaxNode {
-    SyntaxNode::new_root(parser::parse_text(input, parser::TopEntryPoint::UpdateUnit))
+    parse_update_with_diagnostics(input).syntax()
+}
+
+pub fn parse_query_with_diagnostics(input: &str) -> Parse {
+    parser::parse_text(input, parser::TopEntryPoint::QueryUnit)
+}
+
+pub fn parse_update_with_diagnostics(input: &str) -> Parse {
+    parser::parse_text(input, parser::TopEntryPoint::UpdateUnit)
+}
+
+/// Parses `input` as the nonterminal `entry`, e.g. a single `Expression` or `Iri`.
+/// Input that is left after the nonterminal is reported as a diagnostic.
+pub fn parse_entry(input: &str, entry: TopEntryPoint) -> Parse {
+    parser::parse_text(input, entry)
+}
+
+/// Parses `input` as the nonterminal `entry`, or as a query or an update like
+/// [`parse`] if there is none, within the limits of `options`.
+pub fn parse_with_options(
+    input: &str,
+    entry: Option<TopEntryPoint>,
+    options: ParseOptions,
+) -> Parse {
+    parser::parse_with_options(input, entry, options)
+}
+
+/// Like [`parse_with_options`], but identical subtrees of all documents parsed with
+/// the same `cache` are stored once, e.g. the prologues of a batch of similar queries.
+pub fn parse_with_cache(
+    input: &str,
+    entry: Option<TopEntryPoint>,
+    options: ParseOptions,
+    cache: &mut NodeCache,
+) -> Parse {
+    parser::parse_with_cache(input, entry, options, cache)
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -26,7 +77,7 @@ pub fn parse_update(input: &str) -> SyntaxNode {
 pub fn get_parse_tree(input: &str, offset: u32) -> JsValue {
     use parser::TopEntryPoint;
 
-    let root = SyntaxNode::new_root(parser::parse_text(input, TopEntryPoint::QueryUnit));
+    let root = parser::parse_text(input, TopEntryPoint::QueryUnit).syntax();
     build_js_tree(&root, TextSize::new(offset))
 }
 
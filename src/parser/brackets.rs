@@ -0,0 +1,148 @@
+use crate::SyntaxKind;
+
+use super::{lexer::Token, Diagnostic};
+
+/// The closing bracket of an opening bracket.
+fn closing(kind: SyntaxKind) -> Option<SyntaxKind> {
+    match kind {
+        SyntaxKind::LCurly => Some(SyntaxKind::RCurly),
+        SyntaxKind::LParen => Some(SyntaxKind::RParen),
+        SyntaxKind::LBrack => Some(SyntaxKind::RBrack),
+        _ => None,
+    }
+}
+
+/// The opening bracket of a closing bracket.
+fn opening(kind: SyntaxKind) -> Option<SyntaxKind> {
+    match kind {
+        SyntaxKind::RCurly => Some(SyntaxKind::LCurly),
+        SyntaxKind::RParen => Some(SyntaxKind::LParen),
+        SyntaxKind::RBrack => Some(SyntaxKind::LBrack),
+        _ => None,
+    }
+}
+
+/// The stack of unclosed opening brackets that `kind` belongs to in [`Brackets::new`].
+fn stack(kind: SyntaxKind) -> Option<usize> {
+    match kind {
+        SyntaxKind::LCurly | SyntaxKind::RCurly => Some(0),
+        SyntaxKind::LParen | SyntaxKind::RParen => Some(1),
+        SyntaxKind::LBrack | SyntaxKind::RBrack => Some(2),
+        _ => None,
+    }
+}
+
+pub(super) fn is_opening(kind: SyntaxKind) -> bool {
+    closing(kind).is_some()
+}
+
+pub(super) fn is_closing(kind: SyntaxKind) -> bool {
+    opening(kind).is_some()
+}
+
+/// Matching brackets of the token stream, computed before parsing.
+///
+/// A closing bracket is matched with the innermost unclosed opening bracket of its kind.
+/// Opening brackets in between stay unclosed.
+/// Closing brackets without an opening bracket of their kind stay unmatched.
+pub(super) struct Brackets {
+    /// The position of the matching bracket of every bracket token.
+    matching: Vec<Option<usize>>,
+    /// Positions and kinds of the consumed opening brackets that are not closed yet, innermost last.
+    open: Vec<(usize, SyntaxKind)>,
+    /// The index into `open` of every opening bracket that is not closed yet.
+    depth: Vec<Option<usize>>,
+    /// The positions in `open` split by the stack of their kind, innermost last.
+    open_by_kind: [Vec<usize>; 3],
+}
+
+impl Brackets {
+    pub(super) fn new(tokens: &[Token]) -> Self {
+        let mut matching = vec![None; tokens.len()];
+        // Positions of the unclosed opening brackets of every kind, innermost last.
+        let mut stacks: [Vec<usize>; 3] = Default::default();
+        for (pos, token) in tokens.iter().enumerate() {
+            let Some(index) = stack(token.kind) else {
+                continue;
+            };
+            if is_opening(token.kind) {
+                stacks[index].push(pos);
+                continue;
+            }
+            let Some(open) = stacks[index].pop() else {
+                continue;
+            };
+            // Opening brackets of other kinds between the pair stay unclosed.
+            for stack in &mut stacks {
+                while stack.last().is_some_and(|inner| *inner > open) {
+                    stack.pop();
+                }
+            }
+            matching[open] = Some(pos);
+            matching[pos] = Some(open);
+        }
+        Self {
+            matching,
+            open: Vec::new(),
+            depth: vec![None; tokens.len()],
+            open_by_kind: Default::default(),
+        }
+    }
+
+    /// Tracks the token at `pos` being consumed by the parser.
+    pub(super) fn advance(&mut self, pos: usize, kind: SyntaxKind) {
+        if let Some(index) = stack(kind).filter(|_| is_opening(kind)) {
+            self.depth[pos] = Some(self.open.len());
+            self.open.push((pos, kind));
+            self.open_by_kind[index].push(pos);
+        } else if let Some(depth) = self.matching(pos).and_then(|open| self.depth[open]) {
+            // Every bracket is pushed and popped once, so closing stays amortized constant time.
+            for (inner, kind) in self.open.drain(depth..) {
+                self.depth[inner] = None;
+                if let Some(index) = stack(kind) {
+                    self.open_by_kind[index].pop();
+                }
+            }
+        }
+    }
+
+    /// The number of consumed opening brackets that are not closed yet.
+    pub(super) fn depth(&self) -> usize {
+        self.open.len()
+    }
+
+    pub(super) fn matching(&self, pos: usize) -> Option<usize> {
+        self.matching.get(pos).copied().flatten()
+    }
+
+    /// Whether the bracket at `pos` has no partner.
+    pub(super) fn is_unbalanced(&self, pos: usize, kind: SyntaxKind) -> bool {
+        (closing(kind).is_some() || is_closing(kind)) && self.matching(pos).is_none()
+    }
+
+    /// Whether the closing bracket at `pos` closes a bracket the parser is inside of.
+    pub(super) fn closes_open(&self, pos: usize) -> bool {
+        self.matching(pos)
+            .is_some_and(|open| self.depth[open].is_some())
+    }
+
+    /// Whether the innermost open bracket that `closer` would close has no partner.
+    pub(super) fn innermost_unclosed(&self, closer: SyntaxKind) -> bool {
+        stack(closer)
+            .and_then(|index| self.open_by_kind[index].last())
+            .is_some_and(|open| self.matching(*open).is_none())
+    }
+
+    /// Reports every bracket without a partner.
+    pub(super) fn diagnostics(&self, tokens: &[Token]) -> Vec<Diagnostic> {
+        tokens
+            .iter()
+            .enumerate()
+            .filter(|(pos, token)| self.is_unbalanced(*pos, token.kind))
+            .map(|(_, token)| match closing(token.kind) {
+                Some(closer) => Diagnostic::unclosed(token.range, token.kind, closer),
+                None => Diagnostic::unmatched(token.range, token.kind),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,85 @@
+use rowan::{GreenNode, GreenNodeData, GreenToken, Language, NodeOrToken};
+
+use crate::{Sparql, SyntaxKind};
+
+/// Rules that only pass a single child through in most trees,
+/// like the chain `VarOrTerm` → `GraphTerm` → `iri` → `PrefixedName`.
+pub const WRAPPER_KINDS: &[SyntaxKind] = &[
+    SyntaxKind::VarOrTerm,
+    SyntaxKind::VarOrIri,
+    SyntaxKind::GraphTerm,
+    SyntaxKind::iri,
+    SyntaxKind::Verb,
+    SyntaxKind::VerbSimple,
+    SyntaxKind::VerbPath,
+    SyntaxKind::Object,
+    SyntaxKind::ObjectPath,
+    SyntaxKind::GraphNode,
+    SyntaxKind::GraphNodePath,
+    SyntaxKind::Path,
+    SyntaxKind::PathAlternative,
+    SyntaxKind::PathSequence,
+    SyntaxKind::PathEltOrInverse,
+    SyntaxKind::PathElt,
+    SyntaxKind::PathPrimary,
+    SyntaxKind::ConditionalOrExpression,
+    SyntaxKind::ConditionalAndExpression,
+    SyntaxKind::ValueLogical,
+    SyntaxKind::RelationalExpression,
+    SyntaxKind::NumericExpression,
+    SyntaxKind::AdditiveExpression,
+    SyntaxKind::MultiplicativeExpression,
+    SyntaxKind::UnaryExpression,
+    SyntaxKind::PrimaryExpression,
+];
+
+/// Replaces every node of a kind in `wrappers` whose only child is a node by that child.
+///
+/// A wrapper around a single token is kept, so that every token stays inside of the
+/// innermost rule it belongs to. The root is never replaced.
+/// Subtrees without wrappers to elide are shared with the original tree.
+pub(super) fn collapse(root: &GreenNodeData, wrappers: &[SyntaxKind]) -> GreenNode {
+    match collapse_children(root, wrappers) {
+        Some(children) => GreenNode::new(root.kind(), children),
+        None => root.to_owned(),
+    }
+}
+
+/// `node` with its wrappers elided, `None` if there are none.
+fn collapse_node(node: &GreenNodeData, wrappers: &[SyntaxKind]) -> Option<GreenNode> {
+    let kind = Sparql::kind_from_raw(node.kind());
+    let mut children = node.children();
+    if let (Some(NodeOrToken::Node(child)), None, true) =
+        (children.next(), children.next(), wrappers.contains(&kind))
+    {
+        return Some(collapse_node(child, wrappers).unwrap_or_else(|| child.to_owned()));
+    }
+    collapse_children(node, wrappers).map(|children| GreenNode::new(node.kind(), children))
+}
+
+/// The children of `node` with their wrappers elided, `None` if there are none.
+fn collapse_children(
+    node: &GreenNodeData,
+    wrappers: &[SyntaxKind],
+) -> Option<Vec<NodeOrToken<GreenNode, GreenToken>>> {
+    let collapsed: Vec<_> = node
+        .children()
+        .map(|child| match child {
+            NodeOrToken::Node(child) => collapse_node(child, wrappers),
+            NodeOrToken::Token(_) => None,
+        })
+        .collect();
+    if collapsed.iter().all(Option::is_none) {
+        return None;
+    }
+    let children = node
+        .children()
+        .zip(collapsed)
+        .map(|(child, collapsed)| match (child, collapsed) {
+            (_, Some(collapsed)) => NodeOrToken::Node(collapsed),
+            (NodeOrToken::Node(child), None) => NodeOrToken::Node(child.to_owned()),
+            (NodeOrToken::Token(token), None) => NodeOrToken::Token(token.to_owned()),
+        })
+        .collect();
+    Some(children)
+}
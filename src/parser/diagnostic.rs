@@ -0,0 +1,102 @@
+use std::fmt;
+
+use rowan::TextRange;
+
+use crate::SyntaxKind;
+
+use super::lexer::LexError;
+
+/// How serious a diagnostic is. Lexing and parsing only find errors so far.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Error,
+}
+
+/// A problem found while lexing or parsing a document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub range: TextRange,
+    pub severity: Severity,
+    pub message: String,
+    /// The innermost grammar rule that was being parsed.
+    /// `None` for lexer errors and unbalanced brackets, which are found outside of any rule.
+    pub rule: Option<SyntaxKind>,
+    /// The token kinds that would have been accepted at `range`.
+    pub expected: Vec<SyntaxKind>,
+}
+
+impl Diagnostic {
+    pub(super) fn expected(range: TextRange, expected: &[SyntaxKind], found: SyntaxKind) -> Self {
+        let message = match expected {
+            [] => format!("unexpected {found:?}"),
+            [kind] => format!("expected {kind:?}, found {found:?}"),
+            [kinds @ .., last] => format!(
+                "expected {} or {last:?}, found {found:?}",
+                kinds
+                    .iter()
+                    .map(|kind| format!("{kind:?}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        };
+        Self {
+            range,
+            severity: Severity::Error,
+            message,
+            rule: None,
+            expected: expected.to_vec(),
+        }
+    }
+
+    pub(super) fn unclosed(range: TextRange, opener: SyntaxKind, closer: SyntaxKind) -> Self {
+        Self {
+            range,
+            severity: Severity::Error,
+            message: format!("unclosed {opener:?}"),
+            rule: None,
+            expected: vec![closer],
+        }
+    }
+
+    pub(super) fn unmatched(range: TextRange, closer: SyntaxKind) -> Self {
+        Self {
+            range,
+            severity: Severity::Error,
+            message: format!("unmatched {closer:?}"),
+            rule: None,
+            expected: Vec::new(),
+        }
+    }
+
+    pub(super) fn too_deep(range: TextRange, max_nesting: usize) -> Self {
+        Self {
+            range,
+            severity: Severity::Error,
+            message: format!("brackets nested deeper than {max_nesting} levels"),
+            rule: None,
+            expected: Vec::new(),
+        }
+    }
+}
+
+impl From<LexError> for Diagnostic {
+    fn from(error: LexError) -> Self {
+        Self {
+            range: error.range,
+            severity: Severity::Error,
+            message: error.kind.to_string(),
+            rule: None,
+            expected: Vec::new(),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}: {}", self.range, self.message)?;
+        if let Some(rule) = self.rule {
+            write!(f, " in {rule:?}")?;
+        }
+        Ok(())
+    }
+}
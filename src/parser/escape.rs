@@ -0,0 +1,81 @@
+//! Codepoint escape processing, see [SPARQL 1.1 Query, 19.2](https://www.w3.org/TR/sparql11-query/#codepointEscape).
+//!
+//! `\uXXXX` and `\UXXXXXXXX` are replaced before the query is lexed, anywhere in the text.
+//! The [`OffsetMap`] translates offsets in the unescaped text back to the original text.
+
+use rowan::{TextRange, TextSize};
+
+/// Maps offsets in the unescaped text to offsets in the original text.
+/// Holds one `(unescaped, original)` pair for the end of every processed escape.
+#[derive(Debug, Default)]
+pub(crate) struct OffsetMap {
+    anchors: Vec<(TextSize, TextSize)>,
+}
+
+impl OffsetMap {
+    pub(super) fn original_offset(&self, offset: TextSize) -> TextSize {
+        let index = self
+            .anchors
+            .partition_point(|(unescaped, _)| *unescaped <= offset);
+        match index.checked_sub(1) {
+            Some(index) => {
+                let (unescaped, original) = self.anchors[index];
+                original + (offset - unescaped)
+            }
+            None => offset,
+        }
+    }
+
+    pub(super) fn original_range(&self, range: TextRange) -> TextRange {
+        TextRange::new(
+            self.original_offset(range.start()),
+            self.original_offset(range.end()),
+        )
+    }
+}
+
+/// Replaces all codepoint escapes in `text`.
+/// Returns `None` if there is nothing to replace, so that the common case does not allocate.
+/// Escapes that do not denote a valid `char` are kept as written.
+pub(crate) fn unescape(text: &str) -> Option<(String, OffsetMap)> {
+    let mut rest = text.find('\\')?;
+    let mut unescaped = String::new();
+    let mut map = OffsetMap::default();
+    let mut copied = 0;
+    while let Some(found) = text[rest..].find('\\') {
+        let start = rest + found;
+        match codepoint_escape(&text[start..]) {
+            Some((char, len)) => {
+                unescaped.push_str(&text[copied..start]);
+                unescaped.push(char);
+                copied = start + len;
+                rest = copied;
+                map.anchors.push((
+                    TextSize::of(unescaped.as_str()),
+                    TextSize::new(copied as u32),
+                ));
+            }
+            None => rest = start + 1,
+        }
+    }
+    if map.anchors.is_empty() {
+        return None;
+    }
+    unescaped.push_str(&text[copied..]);
+    Some((unescaped, map))
+}
+
+/// The character of the codepoint escape at the start of `text` and the length of the escape.
+pub(super) fn codepoint_escape(text: &str) -> Option<(char, usize)> {
+    let digits = match text.get(..2)? {
+        "\\u" => 4,
+        "\\U" => 8,
+        _ => return None,
+    };
+    let hex = text.get(2..2 + digits)?;
+    if !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
+    }
+    let char = char::from_u32(u32::from_str_radix(hex, 16).ok()?)?;
+    Some((char, 2 + digits))
+}
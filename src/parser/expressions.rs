@@ -0,0 +1,149 @@
+//! Precedence climbing for expressions, used with [`ParseOptions::compact_expressions`].
+//!
+//! The grammar has one rule per precedence level, from `ConditionalOrExpression` down to
+//! `UnaryExpression`. Here every operator produces a single `BinaryExpr`, `UnaryExpr` or
+//! `InExpr` node around its operands instead, the operands are `PrimaryExpression`s.
+//!
+//! [`ParseOptions::compact_expressions`]: super::ParseOptions::compact_expressions
+
+use crate::SyntaxKind;
+
+use super::{
+    grammar::{parse_ExpressionList, parse_PrimaryExpression, FIRST_UnaryExpression},
+    Event, MarkClosed, Parser, TokenSet,
+};
+
+// Binding powers of the infix operators, from the loosest to the tightest.
+const OR: u8 = 1;
+const AND: u8 = 2;
+const RELATIONAL: u8 = 3;
+const ADDITIVE: u8 = 4;
+const MULTIPLICATIVE: u8 = 5;
+
+const RELATIONAL_OPERATORS: TokenSet = TokenSet::new(&[
+    SyntaxKind::Equals,
+    SyntaxKind::ExclamationMarkEquals,
+    SyntaxKind::Less,
+    SyntaxKind::More,
+    SyntaxKind::LessEquals,
+    SyntaxKind::MoreEquals,
+]);
+
+/// Expression -> ConditionalOrExpression, without a node per precedence level.
+pub(super) fn parse_compact_expression(p: &mut Parser) {
+    let lhs = parse_unary(p);
+    parse_infix(p, lhs, OR);
+}
+
+/// The binding power of the operator at the current token.
+///
+/// A signed numeric literal after an operand is an addition or subtraction,
+/// e.g. `?a -1` lexes as `?a` and `-1`.
+fn binding_power(p: &mut Parser) -> Option<u8> {
+    match p.nth(0) {
+        SyntaxKind::DoublePipe => Some(OR),
+        SyntaxKind::DoubleAnd => Some(AND),
+        SyntaxKind::IN | SyntaxKind::NOT => Some(RELATIONAL),
+        SyntaxKind::Plus | SyntaxKind::Minus => Some(ADDITIVE),
+        SyntaxKind::Star | SyntaxKind::Slash => Some(MULTIPLICATIVE),
+        kind if RELATIONAL_OPERATORS.contains(kind) => Some(RELATIONAL),
+        kind if split_sign(kind).is_some() => Some(ADDITIVE),
+        _ => None,
+    }
+}
+
+/// Parses the operators that bind at least as tight as `min` after `lhs`.
+fn parse_infix(p: &mut Parser, mut lhs: MarkClosed, min: u8) -> MarkClosed {
+    // The power of the last operator of this loop. Its right operand took every operator
+    // that binds tighter, unless a comparison stopped there: RelationalExpression allows
+    // a single comparison, so `?a = ?b = ?c` and `?a && ?b = ?c = ?d` are errors.
+    let mut last = None;
+    while let Some(power) = binding_power(p).filter(|power| *power >= min) {
+        if last.is_some_and(|last| power > last || power == RELATIONAL && last == RELATIONAL) {
+            break;
+        }
+        last = Some(power);
+        let m = p.open_before(lhs);
+        let kind = match p.nth(0) {
+            // 'IN' ExpressionList | 'NOT' 'IN' ExpressionList
+            SyntaxKind::IN | SyntaxKind::NOT => {
+                if p.at(SyntaxKind::NOT) {
+                    p.expect(SyntaxKind::NOT);
+                }
+                p.expect(SyntaxKind::IN);
+                parse_ExpressionList(p);
+                SyntaxKind::InExpr
+            }
+            // ( NumericLiteralPositive | NumericLiteralNegative ) ( ( '*' | '/' ) UnaryExpression )*
+            // The sign becomes the operator, `?a -1` is parsed like `?a - 1`.
+            kind if split_sign(kind).is_some() => {
+                p.events.push(Event::Sign);
+                let literal = parse_unsigned_literal(p);
+                parse_infix(p, literal, MULTIPLICATIVE);
+                SyntaxKind::BinaryExpr
+            }
+            operator => {
+                p.expect(operator);
+                let rhs = parse_unary(p);
+                // All operators associate to the left.
+                parse_infix(p, rhs, power + 1);
+                SyntaxKind::BinaryExpr
+            }
+        };
+        lhs = p.close(m, kind);
+    }
+    lhs
+}
+
+/// UnaryExpression -> '!' PrimaryExpression | '+' PrimaryExpression | '-' PrimaryExpression | PrimaryExpression
+fn parse_unary(p: &mut Parser) -> MarkClosed {
+    match p.nth(0) {
+        operator @ (SyntaxKind::ExclamationMark | SyntaxKind::Plus | SyntaxKind::Minus) => {
+            let m = p.open();
+            p.expect(operator);
+            parse_PrimaryExpression(p);
+            p.close(m, SyntaxKind::UnaryExpr)
+        }
+        kind => {
+            // Reported like in the cascade, where the operand is a UnaryExpression.
+            // The error of the PrimaryExpression at the same token is then suppressed.
+            if !FIRST_UnaryExpression.contains(kind) {
+                p.error(&FIRST_UnaryExpression.iter().collect::<Vec<_>>());
+            }
+            parse_closed(p, parse_PrimaryExpression)
+        }
+    }
+}
+
+/// The literal at the current token without its sign, which was split off by `Event::Sign`.
+///
+/// PrimaryExpression -> NumericLiteral -> NumericLiteralUnsigned
+fn parse_unsigned_literal(p: &mut Parser) -> MarkClosed {
+    let primary = p.open();
+    let literal = p.open();
+    let unsigned = p.open();
+    p.advance();
+    p.close(unsigned, SyntaxKind::NumericLiteralUnsigned);
+    p.close(literal, SyntaxKind::NumericLiteral);
+    p.close(primary, SyntaxKind::PrimaryExpression)
+}
+
+/// The operator and the unsigned literal that a signed numeric literal is split into.
+pub(super) fn split_sign(kind: SyntaxKind) -> Option<(SyntaxKind, SyntaxKind)> {
+    match kind {
+        SyntaxKind::INTEGER_POSITIVE => Some((SyntaxKind::Plus, SyntaxKind::INTEGER)),
+        SyntaxKind::DECIMAL_POSITIVE => Some((SyntaxKind::Plus, SyntaxKind::DECIMAL)),
+        SyntaxKind::DOUBLE_POSITIVE => Some((SyntaxKind::Plus, SyntaxKind::DOUBLE)),
+        SyntaxKind::INTEGER_NEGATIVE => Some((SyntaxKind::Minus, SyntaxKind::INTEGER)),
+        SyntaxKind::DECIMAL_NEGATIVE => Some((SyntaxKind::Minus, SyntaxKind::DECIMAL)),
+        SyntaxKind::DOUBLE_NEGATIVE => Some((SyntaxKind::Minus, SyntaxKind::DOUBLE)),
+        _ => None,
+    }
+}
+
+/// Parses a rule that always produces a node and marks that node.
+fn parse_closed(p: &mut Parser, rule: fn(&mut Parser)) -> MarkClosed {
+    let index = p.events.len();
+    rule(p);
+    MarkClosed { index }
+}
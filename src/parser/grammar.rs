@@ -1,5 +1,5 @@
-#![allow(non_snake_case)]
-use super::Parser;
+#![allow(non_snake_case, non_upper_case_globals)]
+use super::{expressions::parse_compact_expression, Parser, TokenSet};
 use crate::SyntaxKind;
 /// [0] QueryUnit -> Query
 pub(super) fn parse_QueryUnit(p: &mut Parser) {
@@ -24,25 +24,45 @@ pub(super) fn parse_Query(p: &mut Parser) {
         SyntaxKind::ASK => {
             parse_AskQuery(p);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::SELECT,
+                SyntaxKind::CONSTRUCT,
+                SyntaxKind::DESCRIBE,
+                SyntaxKind::ASK,
+            ]);
         }
     };
     parse_ValuesClause(p);
     p.close(marker, SyntaxKind::Query);
 }
+const FIRST_Prologue: TokenSet = TokenSet::new(&[SyntaxKind::BASE, SyntaxKind::PREFIX]);
+const RECOVERY_Prologue: TokenSet = TokenSet::new(&[
+    SyntaxKind::SELECT,
+    SyntaxKind::CONSTRUCT,
+    SyntaxKind::DESCRIBE,
+    SyntaxKind::ASK,
+    SyntaxKind::LOAD,
+    SyntaxKind::CLEAR,
+    SyntaxKind::DROP,
+    SyntaxKind::CREATE,
+    SyntaxKind::ADD,
+    SyntaxKind::MOVE,
+    SyntaxKind::COPY,
+    SyntaxKind::INSERT_DATA,
+    SyntaxKind::DELETE_DATA,
+    SyntaxKind::DELETE_WHERE,
+    SyntaxKind::WITH,
+    SyntaxKind::DELETE,
+    SyntaxKind::INSERT,
+]);
 /// [2] Prologue -> (BaseDecl | PrefixDecl)*
 pub(super) fn parse_Prologue(p: &mut Parser) {
-    if !p.at_any(&[SyntaxKind::BASE, SyntaxKind::PREFIX]) {
+    if !p.at_any(FIRST_Prologue) {
         return;
     }
-    let marker = p.open();
-    while [SyntaxKind::BASE, SyntaxKind::PREFIX].contains(&p.nth(0)) {
+    let marker = p.open_rule(RECOVERY_Prologue);
+    while p.at_any(FIRST_Prologue) {
         match p.nth(0) {
             SyntaxKind::BASE => {
                 parse_BaseDecl(p);
@@ -50,94 +70,93 @@ pub(super) fn parse_Prologue(p: &mut Parser) {
             SyntaxKind::PREFIX => {
                 parse_PrefixDecl(p);
             }
-            SyntaxKind::Eof => {
-                eprintln!("Unexpected Eof");
-                p.close(marker, SyntaxKind::Error);
-                return;
-            }
             _ => {
-                p.advance_with_error("Expected ....");
+                p.err_recover(&[SyntaxKind::BASE, SyntaxKind::PREFIX]);
             }
         };
     }
     p.close(marker, SyntaxKind::Prologue);
 }
+const RECOVERY_SelectQuery: TokenSet = TokenSet::new(&[SyntaxKind::VALUES]);
 /// [3] SelectQuery -> SelectClause DatasetClause* WhereClause SolutionModifier
 pub(super) fn parse_SelectQuery(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_SelectQuery);
     parse_SelectClause(p);
-    while [SyntaxKind::FROM].contains(&p.nth(0)) {
+    while p.at_any(FIRST_DatasetClause) {
         parse_DatasetClause(p);
     }
     parse_WhereClause(p);
     parse_SolutionModifier(p);
     p.close(marker, SyntaxKind::SelectQuery);
 }
+const FIRST_ConstructQuery_0: TokenSet = TokenSet::new(&[
+    SyntaxKind::IRIREF,
+    SyntaxKind::PNAME_NS,
+    SyntaxKind::LParen,
+    SyntaxKind::INTEGER,
+    SyntaxKind::NIL,
+    SyntaxKind::LBrack,
+    SyntaxKind::VAR1,
+    SyntaxKind::VAR2,
+    SyntaxKind::DECIMAL,
+    SyntaxKind::DOUBLE,
+    SyntaxKind::INTEGER_POSITIVE,
+    SyntaxKind::DECIMAL_POSITIVE,
+    SyntaxKind::DOUBLE_POSITIVE,
+    SyntaxKind::INTEGER_NEGATIVE,
+    SyntaxKind::DECIMAL_NEGATIVE,
+    SyntaxKind::DOUBLE_NEGATIVE,
+    SyntaxKind::True,
+    SyntaxKind::False,
+    SyntaxKind::STRING_LITERAL1,
+    SyntaxKind::STRING_LITERAL2,
+    SyntaxKind::STRING_LITERAL_LONG1,
+    SyntaxKind::STRING_LITERAL_LONG2,
+    SyntaxKind::PNAME_LN,
+    SyntaxKind::BLANK_NODE_LABEL,
+    SyntaxKind::ANON,
+]);
+const RECOVERY_ConstructQuery: TokenSet = TokenSet::new(&[SyntaxKind::VALUES]);
 /// [4] ConstructQuery -> 'CONSTRUCT' (ConstructTemplate DatasetClause* WhereClause SolutionModifier | DatasetClause* 'WHERE' '{' TriplesTemplate? '}' SolutionModifier)
 pub(super) fn parse_ConstructQuery(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_ConstructQuery);
     p.expect(SyntaxKind::CONSTRUCT);
     match p.nth(0) {
         SyntaxKind::LCurly => {
             parse_ConstructTemplate(p);
-            while [SyntaxKind::FROM].contains(&p.nth(0)) {
+            while p.at_any(FIRST_DatasetClause) {
                 parse_DatasetClause(p);
             }
             parse_WhereClause(p);
             parse_SolutionModifier(p);
         }
         SyntaxKind::WHERE | SyntaxKind::FROM => {
-            while [SyntaxKind::FROM].contains(&p.nth(0)) {
+            while p.at_any(FIRST_DatasetClause) {
                 parse_DatasetClause(p);
             }
             p.expect(SyntaxKind::WHERE);
             p.expect(SyntaxKind::LCurly);
-            if p.at_any(&[
-                SyntaxKind::IRIREF,
-                SyntaxKind::PNAME_NS,
-                SyntaxKind::LParen,
-                SyntaxKind::INTEGER,
-                SyntaxKind::NIL,
-                SyntaxKind::LBrack,
-                SyntaxKind::VAR1,
-                SyntaxKind::VAR2,
-                SyntaxKind::DECIMAL,
-                SyntaxKind::DOUBLE,
-                SyntaxKind::INTEGER_POSITIVE,
-                SyntaxKind::DECIMAL_POSITIVE,
-                SyntaxKind::DOUBLE_POSITIVE,
-                SyntaxKind::INTEGER_NEGATIVE,
-                SyntaxKind::DECIMAL_NEGATIVE,
-                SyntaxKind::DOUBLE_NEGATIVE,
-                SyntaxKind::True,
-                SyntaxKind::False,
-                SyntaxKind::STRING_LITERAL1,
-                SyntaxKind::STRING_LITERAL2,
-                SyntaxKind::STRING_LITERAL_LONG1,
-                SyntaxKind::STRING_LITERAL_LONG2,
-                SyntaxKind::PNAME_LN,
-                SyntaxKind::BLANK_NODE_LABEL,
-                SyntaxKind::ANON,
-            ]) {
+            if p.at_any(FIRST_TriplesTemplate) {
                 parse_TriplesTemplate(p);
             }
+            while p.recover_in_braces(FIRST_ConstructQuery_0) {
+                if p.at_any(FIRST_TriplesTemplate) {
+                    parse_TriplesTemplate(p);
+                }
+            }
             p.expect(SyntaxKind::RCurly);
             parse_SolutionModifier(p);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[SyntaxKind::WHERE, SyntaxKind::LCurly, SyntaxKind::FROM]);
         }
     };
     p.close(marker, SyntaxKind::ConstructQuery);
 }
+const RECOVERY_DescribeQuery: TokenSet = TokenSet::new(&[SyntaxKind::VALUES]);
 /// [5] DescribeQuery -> 'DESCRIBE' (VarOrIri VarOrIri* | '*') DatasetClause* WhereClause? SolutionModifier
 pub(super) fn parse_DescribeQuery(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_DescribeQuery);
     p.expect(SyntaxKind::DESCRIBE);
     match p.nth(0) {
         SyntaxKind::IRIREF
@@ -146,57 +165,53 @@ pub(super) fn parse_DescribeQuery(p: &mut Parser) {
         | SyntaxKind::VAR2
         | SyntaxKind::PNAME_LN => {
             parse_VarOrIri(p);
-            while [
-                SyntaxKind::IRIREF,
-                SyntaxKind::PNAME_NS,
-                SyntaxKind::VAR1,
-                SyntaxKind::VAR2,
-                SyntaxKind::PNAME_LN,
-            ]
-            .contains(&p.nth(0))
-            {
+            while p.at_any(FIRST_VarOrIri) {
                 parse_VarOrIri(p);
             }
         }
         SyntaxKind::Star => {
             p.expect(SyntaxKind::Star);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::IRIREF,
+                SyntaxKind::PNAME_NS,
+                SyntaxKind::Star,
+                SyntaxKind::VAR1,
+                SyntaxKind::VAR2,
+                SyntaxKind::PNAME_LN,
+            ]);
         }
     };
-    while [SyntaxKind::FROM].contains(&p.nth(0)) {
+    while p.at_any(FIRST_DatasetClause) {
         parse_DatasetClause(p);
     }
-    if p.at_any(&[SyntaxKind::WHERE, SyntaxKind::LCurly]) {
+    if p.at_any(FIRST_WhereClause) {
         parse_WhereClause(p);
     }
     parse_SolutionModifier(p);
     p.close(marker, SyntaxKind::DescribeQuery);
 }
+const RECOVERY_AskQuery: TokenSet = TokenSet::new(&[SyntaxKind::VALUES]);
 /// [6] AskQuery -> 'ASK' DatasetClause* WhereClause SolutionModifier
 pub(super) fn parse_AskQuery(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_AskQuery);
     p.expect(SyntaxKind::ASK);
-    while [SyntaxKind::FROM].contains(&p.nth(0)) {
+    while p.at_any(FIRST_DatasetClause) {
         parse_DatasetClause(p);
     }
     parse_WhereClause(p);
     parse_SolutionModifier(p);
     p.close(marker, SyntaxKind::AskQuery);
 }
+const FIRST_ValuesClause: TokenSet = TokenSet::new(&[SyntaxKind::VALUES]);
 /// [7] ValuesClause -> ('VALUES' DataBlock)?
 pub(super) fn parse_ValuesClause(p: &mut Parser) {
-    if !p.at_any(&[SyntaxKind::VALUES]) {
+    if !p.at_any(FIRST_ValuesClause) {
         return;
     }
     let marker = p.open();
-    if p.at_any(&[SyntaxKind::VALUES]) {
+    if p.at_any(FIRST_ValuesClause) {
         p.expect(SyntaxKind::VALUES);
         parse_DataBlock(p);
     }
@@ -204,95 +219,128 @@ pub(super) fn parse_ValuesClause(p: &mut Parser) {
 }
 /// [8] UpdateUnit -> Update
 pub(super) fn parse_UpdateUnit(p: &mut Parser) {
-    if !p.at_any(&[
-        SyntaxKind::BASE,
-        SyntaxKind::PREFIX,
-        SyntaxKind::LOAD,
-        SyntaxKind::CLEAR,
-        SyntaxKind::DROP,
-        SyntaxKind::CREATE,
-        SyntaxKind::ADD,
-        SyntaxKind::MOVE,
-        SyntaxKind::COPY,
-        SyntaxKind::INSERT_DATA,
-        SyntaxKind::DELETE_DATA,
-        SyntaxKind::DELETE_WHERE,
-        SyntaxKind::WITH,
-        SyntaxKind::DELETE,
-        SyntaxKind::INSERT,
-    ]) {
-        return;
-    }
     let marker = p.open();
     parse_Update(p);
     p.close(marker, SyntaxKind::UpdateUnit);
 }
+const FIRST_Update: TokenSet = TokenSet::new(&[
+    SyntaxKind::BASE,
+    SyntaxKind::PREFIX,
+    SyntaxKind::LOAD,
+    SyntaxKind::CLEAR,
+    SyntaxKind::DROP,
+    SyntaxKind::CREATE,
+    SyntaxKind::ADD,
+    SyntaxKind::MOVE,
+    SyntaxKind::COPY,
+    SyntaxKind::INSERT_DATA,
+    SyntaxKind::DELETE_DATA,
+    SyntaxKind::DELETE_WHERE,
+    SyntaxKind::WITH,
+    SyntaxKind::DELETE,
+    SyntaxKind::INSERT,
+]);
+const FIRST_Update_0: TokenSet = TokenSet::new(&[SyntaxKind::Semicolon]);
+const FIRST_Update_1: TokenSet = TokenSet::new(&[
+    SyntaxKind::LOAD,
+    SyntaxKind::CLEAR,
+    SyntaxKind::DROP,
+    SyntaxKind::CREATE,
+    SyntaxKind::ADD,
+    SyntaxKind::MOVE,
+    SyntaxKind::COPY,
+    SyntaxKind::INSERT_DATA,
+    SyntaxKind::DELETE_DATA,
+    SyntaxKind::DELETE_WHERE,
+    SyntaxKind::WITH,
+    SyntaxKind::DELETE,
+    SyntaxKind::INSERT,
+]);
 /// [9] Update -> Prologue (UpdateOne (';' Update)?)?
 pub(super) fn parse_Update(p: &mut Parser) {
-    if !p.at_any(&[
-        SyntaxKind::BASE,
-        SyntaxKind::PREFIX,
-        SyntaxKind::LOAD,
-        SyntaxKind::CLEAR,
-        SyntaxKind::DROP,
-        SyntaxKind::CREATE,
-        SyntaxKind::ADD,
-        SyntaxKind::MOVE,
-        SyntaxKind::COPY,
-        SyntaxKind::INSERT_DATA,
-        SyntaxKind::DELETE_DATA,
-        SyntaxKind::DELETE_WHERE,
-        SyntaxKind::WITH,
-        SyntaxKind::DELETE,
-        SyntaxKind::INSERT,
-    ]) {
+    if !p.at_any(FIRST_Update) {
         return;
     }
     let marker = p.open();
-    parse_Prologue(p);
-    if p.at_any(&[
-        SyntaxKind::LOAD,
-        SyntaxKind::CLEAR,
-        SyntaxKind::DROP,
-        SyntaxKind::CREATE,
-        SyntaxKind::ADD,
-        SyntaxKind::MOVE,
-        SyntaxKind::COPY,
-        SyntaxKind::INSERT_DATA,
-        SyntaxKind::DELETE_DATA,
-        SyntaxKind::DELETE_WHERE,
-        SyntaxKind::WITH,
-        SyntaxKind::DELETE,
-        SyntaxKind::INSERT,
-    ]) {
-        parse_UpdateOne(p);
-        if p.at_any(&[SyntaxKind::Semicolon]) {
-            p.expect(SyntaxKind::Semicolon);
-            parse_Update(p);
+    loop {
+        parse_Prologue(p);
+        if p.at_any(FIRST_Update_1) {
+            parse_UpdateOne(p);
+            if p.at_any(FIRST_Update_0) {
+                p.expect(SyntaxKind::Semicolon);
+                continue;
+            }
         }
+        break;
     }
     p.close(marker, SyntaxKind::Update);
 }
+const RECOVERY_BaseDecl: TokenSet = TokenSet::new(&[
+    SyntaxKind::BASE,
+    SyntaxKind::PREFIX,
+    SyntaxKind::SELECT,
+    SyntaxKind::CONSTRUCT,
+    SyntaxKind::DESCRIBE,
+    SyntaxKind::ASK,
+    SyntaxKind::LOAD,
+    SyntaxKind::CLEAR,
+    SyntaxKind::DROP,
+    SyntaxKind::CREATE,
+    SyntaxKind::ADD,
+    SyntaxKind::MOVE,
+    SyntaxKind::COPY,
+    SyntaxKind::INSERT_DATA,
+    SyntaxKind::DELETE_DATA,
+    SyntaxKind::DELETE_WHERE,
+    SyntaxKind::WITH,
+    SyntaxKind::DELETE,
+    SyntaxKind::INSERT,
+]);
 /// [10] BaseDecl -> 'BASE' 'IRIREF'
 pub(super) fn parse_BaseDecl(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_BaseDecl);
     p.expect(SyntaxKind::BASE);
     p.expect(SyntaxKind::IRIREF);
     p.close(marker, SyntaxKind::BaseDecl);
 }
+const RECOVERY_PrefixDecl: TokenSet = TokenSet::new(&[
+    SyntaxKind::BASE,
+    SyntaxKind::PREFIX,
+    SyntaxKind::SELECT,
+    SyntaxKind::CONSTRUCT,
+    SyntaxKind::DESCRIBE,
+    SyntaxKind::ASK,
+    SyntaxKind::LOAD,
+    SyntaxKind::CLEAR,
+    SyntaxKind::DROP,
+    SyntaxKind::CREATE,
+    SyntaxKind::ADD,
+    SyntaxKind::MOVE,
+    SyntaxKind::COPY,
+    SyntaxKind::INSERT_DATA,
+    SyntaxKind::DELETE_DATA,
+    SyntaxKind::DELETE_WHERE,
+    SyntaxKind::WITH,
+    SyntaxKind::DELETE,
+    SyntaxKind::INSERT,
+]);
 /// [11] PrefixDecl -> 'PREFIX' 'PNAME_NS' 'IRIREF'
 pub(super) fn parse_PrefixDecl(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_PrefixDecl);
     p.expect(SyntaxKind::PREFIX);
     p.expect(SyntaxKind::PNAME_NS);
     p.expect(SyntaxKind::IRIREF);
     p.close(marker, SyntaxKind::PrefixDecl);
 }
+const FIRST_SelectClause_0: TokenSet = TokenSet::new(&[SyntaxKind::DISTINCT, SyntaxKind::REDUCED]);
+const FIRST_SelectClause_1: TokenSet =
+    TokenSet::new(&[SyntaxKind::LParen, SyntaxKind::VAR1, SyntaxKind::VAR2]);
+const RECOVERY_SelectClause: TokenSet = TokenSet::new(&[SyntaxKind::WHERE, SyntaxKind::FROM]);
 /// [12] SelectClause -> 'SELECT' ('DISTINCT' | 'REDUCED')? ((Var | '(' Expression 'AS' Var ')') (Var | '(' Expression 'AS' Var ')')* | '*')
 pub(super) fn parse_SelectClause(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_SelectClause);
     p.expect(SyntaxKind::SELECT);
-    if p.at_any(&[SyntaxKind::DISTINCT, SyntaxKind::REDUCED]) {
+    if p.at_any(FIRST_SelectClause_0) {
         match p.nth(0) {
             SyntaxKind::DISTINCT => {
                 p.expect(SyntaxKind::DISTINCT);
@@ -300,13 +348,8 @@ pub(super) fn parse_SelectClause(p: &mut Parser) {
             SyntaxKind::REDUCED => {
                 p.expect(SyntaxKind::REDUCED);
             }
-            SyntaxKind::Eof => {
-                eprintln!("Unexpected Eof");
-                p.close(marker, SyntaxKind::Error);
-                return;
-            }
             _ => {
-                p.advance_with_error("Expected ....");
+                p.err_recover(&[SyntaxKind::DISTINCT, SyntaxKind::REDUCED]);
             }
         };
     }
@@ -323,16 +366,11 @@ pub(super) fn parse_SelectClause(p: &mut Parser) {
                     parse_Var(p);
                     p.expect(SyntaxKind::RParen);
                 }
-                SyntaxKind::Eof => {
-                    eprintln!("Unexpected Eof");
-                    p.close(marker, SyntaxKind::Error);
-                    return;
-                }
                 _ => {
-                    p.advance_with_error("Expected ....");
+                    p.err_recover(&[SyntaxKind::LParen, SyntaxKind::VAR1, SyntaxKind::VAR2]);
                 }
             };
-            while [SyntaxKind::LParen, SyntaxKind::VAR1, SyntaxKind::VAR2].contains(&p.nth(0)) {
+            while p.at_any(FIRST_SelectClause_1) {
                 match p.nth(0) {
                     SyntaxKind::VAR1 | SyntaxKind::VAR2 => {
                         parse_Var(p);
@@ -344,13 +382,8 @@ pub(super) fn parse_SelectClause(p: &mut Parser) {
                         parse_Var(p);
                         p.expect(SyntaxKind::RParen);
                     }
-                    SyntaxKind::Eof => {
-                        eprintln!("Unexpected Eof");
-                        p.close(marker, SyntaxKind::Error);
-                        return;
-                    }
                     _ => {
-                        p.advance_with_error("Expected ....");
+                        p.err_recover(&[SyntaxKind::LParen, SyntaxKind::VAR1, SyntaxKind::VAR2]);
                     }
                 };
             }
@@ -358,20 +391,31 @@ pub(super) fn parse_SelectClause(p: &mut Parser) {
         SyntaxKind::Star => {
             p.expect(SyntaxKind::Star);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::LParen,
+                SyntaxKind::Star,
+                SyntaxKind::VAR1,
+                SyntaxKind::VAR2,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::SelectClause);
 }
+const FIRST_DatasetClause: TokenSet = TokenSet::new(&[SyntaxKind::FROM]);
+const RECOVERY_DatasetClause: TokenSet = TokenSet::new(&[
+    SyntaxKind::WHERE,
+    SyntaxKind::FROM,
+    SyntaxKind::GROUP,
+    SyntaxKind::HAVING,
+    SyntaxKind::ORDER,
+    SyntaxKind::LIMIT,
+    SyntaxKind::OFFSET,
+    SyntaxKind::VALUES,
+]);
 /// [13] DatasetClause -> 'FROM' (DefaultGraphClause | NamedGraphClause)
 pub(super) fn parse_DatasetClause(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_DatasetClause);
     p.expect(SyntaxKind::FROM);
     match p.nth(0) {
         SyntaxKind::IRIREF | SyntaxKind::PNAME_NS | SyntaxKind::PNAME_LN => {
@@ -380,48 +424,60 @@ pub(super) fn parse_DatasetClause(p: &mut Parser) {
         SyntaxKind::NAMED => {
             parse_NamedGraphClause(p);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::IRIREF,
+                SyntaxKind::PNAME_NS,
+                SyntaxKind::NAMED,
+                SyntaxKind::PNAME_LN,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::DatasetClause);
 }
+const FIRST_WhereClause: TokenSet = TokenSet::new(&[SyntaxKind::WHERE, SyntaxKind::LCurly]);
+const FIRST_WhereClause_0: TokenSet = TokenSet::new(&[SyntaxKind::WHERE]);
+const RECOVERY_WhereClause: TokenSet = TokenSet::new(&[
+    SyntaxKind::GROUP,
+    SyntaxKind::HAVING,
+    SyntaxKind::ORDER,
+    SyntaxKind::LIMIT,
+    SyntaxKind::OFFSET,
+    SyntaxKind::VALUES,
+]);
 /// [14] WhereClause -> 'WHERE'? GroupGraphPattern
 pub(super) fn parse_WhereClause(p: &mut Parser) {
-    let marker = p.open();
-    if p.at_any(&[SyntaxKind::WHERE]) {
+    let marker = p.open_rule(RECOVERY_WhereClause);
+    if p.at_any(FIRST_WhereClause_0) {
         p.expect(SyntaxKind::WHERE);
     }
     parse_GroupGraphPattern(p);
     p.close(marker, SyntaxKind::WhereClause);
 }
+const FIRST_SolutionModifier: TokenSet = TokenSet::new(&[
+    SyntaxKind::GROUP,
+    SyntaxKind::HAVING,
+    SyntaxKind::ORDER,
+    SyntaxKind::LIMIT,
+    SyntaxKind::OFFSET,
+]);
+const RECOVERY_SolutionModifier: TokenSet = TokenSet::new(&[SyntaxKind::VALUES]);
 /// [15] SolutionModifier -> GroupClause? HavingClause? OrderClause? LimitOffsetClauses?
 pub(super) fn parse_SolutionModifier(p: &mut Parser) {
-    if !p.at_any(&[
-        SyntaxKind::GROUP,
-        SyntaxKind::HAVING,
-        SyntaxKind::ORDER,
-        SyntaxKind::LIMIT,
-        SyntaxKind::OFFSET,
-    ]) {
+    if !p.at_any(FIRST_SolutionModifier) {
         return;
     }
-    let marker = p.open();
-    if p.at_any(&[SyntaxKind::GROUP]) {
+    let marker = p.open_rule(RECOVERY_SolutionModifier);
+    if p.at_any(FIRST_GroupClause) {
         parse_GroupClause(p);
     }
-    if p.at_any(&[SyntaxKind::HAVING]) {
+    if p.at_any(FIRST_HavingClause) {
         parse_HavingClause(p);
     }
-    if p.at_any(&[SyntaxKind::ORDER]) {
+    if p.at_any(FIRST_OrderClause) {
         parse_OrderClause(p);
     }
-    if p.at_any(&[SyntaxKind::LIMIT, SyntaxKind::OFFSET]) {
+    if p.at_any(FIRST_LimitOffsetClauses) {
         parse_LimitOffsetClauses(p);
     }
     p.close(marker, SyntaxKind::SolutionModifier);
@@ -435,9 +491,28 @@ pub(super) fn parse_SubSelect(p: &mut Parser) {
     parse_ValuesClause(p);
     p.close(marker, SyntaxKind::SubSelect);
 }
+const FIRST_Var: TokenSet = TokenSet::new(&[SyntaxKind::VAR1, SyntaxKind::VAR2]);
+const RECOVERY_Var: TokenSet = TokenSet::new(&[
+    SyntaxKind::WHERE,
+    SyntaxKind::FROM,
+    SyntaxKind::GROUP,
+    SyntaxKind::HAVING,
+    SyntaxKind::ORDER,
+    SyntaxKind::LIMIT,
+    SyntaxKind::OFFSET,
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [17] Var -> 'VAR1' | 'VAR2'
 pub(super) fn parse_Var(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_Var);
     match p.nth(0) {
         SyntaxKind::VAR1 => {
             p.expect(SyntaxKind::VAR1);
@@ -445,100 +520,129 @@ pub(super) fn parse_Var(p: &mut Parser) {
         SyntaxKind::VAR2 => {
             p.expect(SyntaxKind::VAR2);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[SyntaxKind::VAR1, SyntaxKind::VAR2]);
         }
     };
     p.close(marker, SyntaxKind::Var);
 }
+const RECOVERY_Expression: TokenSet = TokenSet::new(&[SyntaxKind::Semicolon, SyntaxKind::Comma]);
 /// [18] Expression -> ConditionalOrExpression
 pub(super) fn parse_Expression(p: &mut Parser) {
-    let marker = p.open();
-    parse_ConditionalOrExpression(p);
+    let marker = p.open_rule(RECOVERY_Expression);
+    if p.options.compact_expressions {
+        parse_compact_expression(p);
+    } else {
+        parse_ConditionalOrExpression(p);
+    }
     p.close(marker, SyntaxKind::Expression);
 }
+const FIRST_ConstructTemplate_0: TokenSet = TokenSet::new(&[
+    SyntaxKind::IRIREF,
+    SyntaxKind::PNAME_NS,
+    SyntaxKind::LParen,
+    SyntaxKind::INTEGER,
+    SyntaxKind::NIL,
+    SyntaxKind::LBrack,
+    SyntaxKind::VAR1,
+    SyntaxKind::VAR2,
+    SyntaxKind::DECIMAL,
+    SyntaxKind::DOUBLE,
+    SyntaxKind::INTEGER_POSITIVE,
+    SyntaxKind::DECIMAL_POSITIVE,
+    SyntaxKind::DOUBLE_POSITIVE,
+    SyntaxKind::INTEGER_NEGATIVE,
+    SyntaxKind::DECIMAL_NEGATIVE,
+    SyntaxKind::DOUBLE_NEGATIVE,
+    SyntaxKind::True,
+    SyntaxKind::False,
+    SyntaxKind::STRING_LITERAL1,
+    SyntaxKind::STRING_LITERAL2,
+    SyntaxKind::STRING_LITERAL_LONG1,
+    SyntaxKind::STRING_LITERAL_LONG2,
+    SyntaxKind::PNAME_LN,
+    SyntaxKind::BLANK_NODE_LABEL,
+    SyntaxKind::ANON,
+]);
+const RECOVERY_ConstructTemplate: TokenSet = TokenSet::new(&[SyntaxKind::WHERE, SyntaxKind::FROM]);
 /// [19] ConstructTemplate -> '{' ConstructTriples? '}'
 pub(super) fn parse_ConstructTemplate(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_ConstructTemplate);
     p.expect(SyntaxKind::LCurly);
-    if p.at_any(&[
-        SyntaxKind::IRIREF,
-        SyntaxKind::PNAME_NS,
-        SyntaxKind::LParen,
-        SyntaxKind::INTEGER,
-        SyntaxKind::NIL,
-        SyntaxKind::LBrack,
-        SyntaxKind::VAR1,
-        SyntaxKind::VAR2,
-        SyntaxKind::DECIMAL,
-        SyntaxKind::DOUBLE,
-        SyntaxKind::INTEGER_POSITIVE,
-        SyntaxKind::DECIMAL_POSITIVE,
-        SyntaxKind::DOUBLE_POSITIVE,
-        SyntaxKind::INTEGER_NEGATIVE,
-        SyntaxKind::DECIMAL_NEGATIVE,
-        SyntaxKind::DOUBLE_NEGATIVE,
-        SyntaxKind::True,
-        SyntaxKind::False,
-        SyntaxKind::STRING_LITERAL1,
-        SyntaxKind::STRING_LITERAL2,
-        SyntaxKind::STRING_LITERAL_LONG1,
-        SyntaxKind::STRING_LITERAL_LONG2,
-        SyntaxKind::PNAME_LN,
-        SyntaxKind::BLANK_NODE_LABEL,
-        SyntaxKind::ANON,
-    ]) {
+    if p.at_any(FIRST_ConstructTriples) {
         parse_ConstructTriples(p);
     }
+    while p.recover_in_braces(FIRST_ConstructTemplate_0) {
+        if p.at_any(FIRST_ConstructTriples) {
+            parse_ConstructTriples(p);
+        }
+    }
     p.expect(SyntaxKind::RCurly);
     p.close(marker, SyntaxKind::ConstructTemplate);
 }
+const FIRST_TriplesTemplate: TokenSet = TokenSet::new(&[
+    SyntaxKind::IRIREF,
+    SyntaxKind::PNAME_NS,
+    SyntaxKind::LParen,
+    SyntaxKind::INTEGER,
+    SyntaxKind::NIL,
+    SyntaxKind::LBrack,
+    SyntaxKind::VAR1,
+    SyntaxKind::VAR2,
+    SyntaxKind::DECIMAL,
+    SyntaxKind::DOUBLE,
+    SyntaxKind::INTEGER_POSITIVE,
+    SyntaxKind::DECIMAL_POSITIVE,
+    SyntaxKind::DOUBLE_POSITIVE,
+    SyntaxKind::INTEGER_NEGATIVE,
+    SyntaxKind::DECIMAL_NEGATIVE,
+    SyntaxKind::DOUBLE_NEGATIVE,
+    SyntaxKind::True,
+    SyntaxKind::False,
+    SyntaxKind::STRING_LITERAL1,
+    SyntaxKind::STRING_LITERAL2,
+    SyntaxKind::STRING_LITERAL_LONG1,
+    SyntaxKind::STRING_LITERAL_LONG2,
+    SyntaxKind::PNAME_LN,
+    SyntaxKind::BLANK_NODE_LABEL,
+    SyntaxKind::ANON,
+]);
+const FIRST_TriplesTemplate_0: TokenSet = TokenSet::new(&[SyntaxKind::Dot]);
 /// [20] TriplesTemplate -> TriplesSameSubject ('.' TriplesTemplate?)?
 pub(super) fn parse_TriplesTemplate(p: &mut Parser) {
     let marker = p.open();
-    parse_TriplesSameSubject(p);
-    if p.at_any(&[SyntaxKind::Dot]) {
-        p.expect(SyntaxKind::Dot);
-        if p.at_any(&[
-            SyntaxKind::IRIREF,
-            SyntaxKind::PNAME_NS,
-            SyntaxKind::LParen,
-            SyntaxKind::INTEGER,
-            SyntaxKind::NIL,
-            SyntaxKind::LBrack,
-            SyntaxKind::VAR1,
-            SyntaxKind::VAR2,
-            SyntaxKind::DECIMAL,
-            SyntaxKind::DOUBLE,
-            SyntaxKind::INTEGER_POSITIVE,
-            SyntaxKind::DECIMAL_POSITIVE,
-            SyntaxKind::DOUBLE_POSITIVE,
-            SyntaxKind::INTEGER_NEGATIVE,
-            SyntaxKind::DECIMAL_NEGATIVE,
-            SyntaxKind::DOUBLE_NEGATIVE,
-            SyntaxKind::True,
-            SyntaxKind::False,
-            SyntaxKind::STRING_LITERAL1,
-            SyntaxKind::STRING_LITERAL2,
-            SyntaxKind::STRING_LITERAL_LONG1,
-            SyntaxKind::STRING_LITERAL_LONG2,
-            SyntaxKind::PNAME_LN,
-            SyntaxKind::BLANK_NODE_LABEL,
-            SyntaxKind::ANON,
-        ]) {
-            parse_TriplesTemplate(p);
+    loop {
+        parse_TriplesSameSubject(p);
+        if p.at_any(FIRST_TriplesTemplate_0) {
+            p.expect(SyntaxKind::Dot);
+            if p.at_any(FIRST_TriplesTemplate) {
+                continue;
+            }
         }
+        break;
     }
     p.close(marker, SyntaxKind::TriplesTemplate);
 }
+const FIRST_VarOrIri: TokenSet = TokenSet::new(&[
+    SyntaxKind::IRIREF,
+    SyntaxKind::PNAME_NS,
+    SyntaxKind::VAR1,
+    SyntaxKind::VAR2,
+    SyntaxKind::PNAME_LN,
+]);
+const RECOVERY_VarOrIri: TokenSet = TokenSet::new(&[
+    SyntaxKind::WHERE,
+    SyntaxKind::FROM,
+    SyntaxKind::GROUP,
+    SyntaxKind::HAVING,
+    SyntaxKind::ORDER,
+    SyntaxKind::LIMIT,
+    SyntaxKind::OFFSET,
+    SyntaxKind::VALUES,
+]);
 /// [21] VarOrIri -> Var | iri
 pub(super) fn parse_VarOrIri(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_VarOrIri);
     match p.nth(0) {
         SyntaxKind::VAR1 | SyntaxKind::VAR2 => {
             parse_Var(p);
@@ -546,39 +650,91 @@ pub(super) fn parse_VarOrIri(p: &mut Parser) {
         SyntaxKind::IRIREF | SyntaxKind::PNAME_NS | SyntaxKind::PNAME_LN => {
             parse_iri(p);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::IRIREF,
+                SyntaxKind::PNAME_NS,
+                SyntaxKind::VAR1,
+                SyntaxKind::VAR2,
+                SyntaxKind::PNAME_LN,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::VarOrIri);
 }
+const RECOVERY_DefaultGraphClause: TokenSet = TokenSet::new(&[
+    SyntaxKind::WHERE,
+    SyntaxKind::FROM,
+    SyntaxKind::GROUP,
+    SyntaxKind::HAVING,
+    SyntaxKind::ORDER,
+    SyntaxKind::LIMIT,
+    SyntaxKind::OFFSET,
+    SyntaxKind::VALUES,
+]);
 /// [22] DefaultGraphClause -> SourceSelector
 pub(super) fn parse_DefaultGraphClause(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_DefaultGraphClause);
     parse_SourceSelector(p);
     p.close(marker, SyntaxKind::DefaultGraphClause);
 }
+const RECOVERY_NamedGraphClause: TokenSet = TokenSet::new(&[
+    SyntaxKind::WHERE,
+    SyntaxKind::FROM,
+    SyntaxKind::GROUP,
+    SyntaxKind::HAVING,
+    SyntaxKind::ORDER,
+    SyntaxKind::LIMIT,
+    SyntaxKind::OFFSET,
+    SyntaxKind::VALUES,
+]);
 /// [23] NamedGraphClause -> 'NAMED' SourceSelector
 pub(super) fn parse_NamedGraphClause(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_NamedGraphClause);
     p.expect(SyntaxKind::NAMED);
     parse_SourceSelector(p);
     p.close(marker, SyntaxKind::NamedGraphClause);
 }
+const RECOVERY_SourceSelector: TokenSet = TokenSet::new(&[
+    SyntaxKind::WHERE,
+    SyntaxKind::FROM,
+    SyntaxKind::GROUP,
+    SyntaxKind::HAVING,
+    SyntaxKind::ORDER,
+    SyntaxKind::LIMIT,
+    SyntaxKind::OFFSET,
+    SyntaxKind::VALUES,
+]);
 /// [24] SourceSelector -> iri
 pub(super) fn parse_SourceSelector(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_SourceSelector);
     parse_iri(p);
     p.close(marker, SyntaxKind::SourceSelector);
 }
+const RECOVERY_iri: TokenSet = TokenSet::new(&[
+    SyntaxKind::WHERE,
+    SyntaxKind::FROM,
+    SyntaxKind::GROUP,
+    SyntaxKind::HAVING,
+    SyntaxKind::ORDER,
+    SyntaxKind::LIMIT,
+    SyntaxKind::OFFSET,
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::DELETE,
+    SyntaxKind::INSERT,
+    SyntaxKind::USING,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [25] iri -> 'IRIREF' | PrefixedName
 pub(super) fn parse_iri(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_iri);
     match p.nth(0) {
         SyntaxKind::IRIREF => {
             p.expect(SyntaxKind::IRIREF);
@@ -586,20 +742,72 @@ pub(super) fn parse_iri(p: &mut Parser) {
         SyntaxKind::PNAME_NS | SyntaxKind::PNAME_LN => {
             parse_PrefixedName(p);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::IRIREF,
+                SyntaxKind::PNAME_NS,
+                SyntaxKind::PNAME_LN,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::iri);
 }
+const FIRST_GroupGraphPattern_0: TokenSet = TokenSet::new(&[
+    SyntaxKind::IRIREF,
+    SyntaxKind::PNAME_NS,
+    SyntaxKind::SELECT,
+    SyntaxKind::LParen,
+    SyntaxKind::LCurly,
+    SyntaxKind::INTEGER,
+    SyntaxKind::VALUES,
+    SyntaxKind::GRAPH,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::NIL,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::LBrack,
+    SyntaxKind::VAR1,
+    SyntaxKind::VAR2,
+    SyntaxKind::DECIMAL,
+    SyntaxKind::DOUBLE,
+    SyntaxKind::INTEGER_POSITIVE,
+    SyntaxKind::DECIMAL_POSITIVE,
+    SyntaxKind::DOUBLE_POSITIVE,
+    SyntaxKind::INTEGER_NEGATIVE,
+    SyntaxKind::DECIMAL_NEGATIVE,
+    SyntaxKind::DOUBLE_NEGATIVE,
+    SyntaxKind::True,
+    SyntaxKind::False,
+    SyntaxKind::STRING_LITERAL1,
+    SyntaxKind::STRING_LITERAL2,
+    SyntaxKind::STRING_LITERAL_LONG1,
+    SyntaxKind::STRING_LITERAL_LONG2,
+    SyntaxKind::PNAME_LN,
+    SyntaxKind::BLANK_NODE_LABEL,
+    SyntaxKind::ANON,
+]);
+const RECOVERY_GroupGraphPattern: TokenSet = TokenSet::new(&[
+    SyntaxKind::GROUP,
+    SyntaxKind::HAVING,
+    SyntaxKind::ORDER,
+    SyntaxKind::LIMIT,
+    SyntaxKind::OFFSET,
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::UNION,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [26] GroupGraphPattern -> '{' (SubSelect | GroupGraphPatternSub) '}'
 pub(super) fn parse_GroupGraphPattern(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_GroupGraphPattern);
     p.expect(SyntaxKind::LCurly);
     match p.nth(0) {
         SyntaxKind::SELECT => {
@@ -640,291 +848,208 @@ pub(super) fn parse_GroupGraphPattern(p: &mut Parser) {
         | SyntaxKind::ANON => {
             parse_GroupGraphPatternSub(p);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {}
     };
-    while !p.at_any(&[SyntaxKind::RCurly, SyntaxKind::Eof]) {
-        p.advance_with_error("Not a valid GroupGraphPattern");
+    while p.recover_in_braces(FIRST_GroupGraphPattern_0) {
+        match p.nth(0) {
+            SyntaxKind::SELECT => {
+                parse_SubSelect(p);
+            }
+            SyntaxKind::IRIREF
+            | SyntaxKind::PNAME_NS
+            | SyntaxKind::LParen
+            | SyntaxKind::LCurly
+            | SyntaxKind::INTEGER
+            | SyntaxKind::VALUES
+            | SyntaxKind::GRAPH
+            | SyntaxKind::OPTIONAL
+            | SyntaxKind::SERVICE
+            | SyntaxKind::BIND
+            | SyntaxKind::NIL
+            | SyntaxKind::MINUS
+            | SyntaxKind::FILTER
+            | SyntaxKind::LBrack
+            | SyntaxKind::VAR1
+            | SyntaxKind::VAR2
+            | SyntaxKind::DECIMAL
+            | SyntaxKind::DOUBLE
+            | SyntaxKind::INTEGER_POSITIVE
+            | SyntaxKind::DECIMAL_POSITIVE
+            | SyntaxKind::DOUBLE_POSITIVE
+            | SyntaxKind::INTEGER_NEGATIVE
+            | SyntaxKind::DECIMAL_NEGATIVE
+            | SyntaxKind::DOUBLE_NEGATIVE
+            | SyntaxKind::True
+            | SyntaxKind::False
+            | SyntaxKind::STRING_LITERAL1
+            | SyntaxKind::STRING_LITERAL2
+            | SyntaxKind::STRING_LITERAL_LONG1
+            | SyntaxKind::STRING_LITERAL_LONG2
+            | SyntaxKind::PNAME_LN
+            | SyntaxKind::BLANK_NODE_LABEL
+            | SyntaxKind::ANON => {
+                parse_GroupGraphPatternSub(p);
+            }
+            _ => {}
+        };
     }
     p.expect(SyntaxKind::RCurly);
     p.close(marker, SyntaxKind::GroupGraphPattern);
 }
+const FIRST_GroupClause: TokenSet = TokenSet::new(&[SyntaxKind::GROUP]);
+const RECOVERY_GroupClause: TokenSet = TokenSet::new(&[
+    SyntaxKind::HAVING,
+    SyntaxKind::ORDER,
+    SyntaxKind::LIMIT,
+    SyntaxKind::OFFSET,
+    SyntaxKind::VALUES,
+]);
 /// [27] GroupClause -> 'GROUP' 'BY' GroupCondition GroupCondition*
 pub(super) fn parse_GroupClause(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_GroupClause);
     p.expect(SyntaxKind::GROUP);
     p.expect(SyntaxKind::BY);
     parse_GroupCondition(p);
-    while [
-        SyntaxKind::IRIREF,
-        SyntaxKind::PNAME_NS,
-        SyntaxKind::LParen,
-        SyntaxKind::VAR1,
-        SyntaxKind::VAR2,
-        SyntaxKind::NOT,
-        SyntaxKind::STR,
-        SyntaxKind::LANG,
-        SyntaxKind::LANGMATCHES,
-        SyntaxKind::DATATYPE,
-        SyntaxKind::BOUND,
-        SyntaxKind::IRI,
-        SyntaxKind::URI,
-        SyntaxKind::BNODE,
-        SyntaxKind::RAND,
-        SyntaxKind::ABS,
-        SyntaxKind::CEIL,
-        SyntaxKind::FLOOR,
-        SyntaxKind::ROUND,
-        SyntaxKind::CONCAT,
-        SyntaxKind::STRLEN,
-        SyntaxKind::UCASE,
-        SyntaxKind::LCASE,
-        SyntaxKind::ENCODE_FOR_URI,
-        SyntaxKind::CONTAINS,
-        SyntaxKind::STRSTARTS,
-        SyntaxKind::STRENDS,
-        SyntaxKind::STRBEFORE,
-        SyntaxKind::STRAFTER,
-        SyntaxKind::YEAR,
-        SyntaxKind::MONTH,
-        SyntaxKind::DAY,
-        SyntaxKind::HOURS,
-        SyntaxKind::MINUTES,
-        SyntaxKind::SECONDS,
-        SyntaxKind::TIMEZONE,
-        SyntaxKind::TZ,
-        SyntaxKind::NOW,
-        SyntaxKind::UUID,
-        SyntaxKind::STRUUID,
-        SyntaxKind::MD5,
-        SyntaxKind::SHA1,
-        SyntaxKind::SHA256,
-        SyntaxKind::SHA384,
-        SyntaxKind::SHA512,
-        SyntaxKind::COALESCE,
-        SyntaxKind::IF,
-        SyntaxKind::STRLANG,
-        SyntaxKind::STRDT,
-        SyntaxKind::sameTerm,
-        SyntaxKind::isIRI,
-        SyntaxKind::isURI,
-        SyntaxKind::isBLANK,
-        SyntaxKind::isLITERAL,
-        SyntaxKind::isNUMERIC,
-        SyntaxKind::REGEX,
-        SyntaxKind::SUBSTR,
-        SyntaxKind::REPLACE,
-        SyntaxKind::EXISTS,
-        SyntaxKind::COUNT,
-        SyntaxKind::SUM,
-        SyntaxKind::MIN,
-        SyntaxKind::MAX,
-        SyntaxKind::AVG,
-        SyntaxKind::SAMPLE,
-        SyntaxKind::GROUP_CONCAT,
-        SyntaxKind::PNAME_LN,
-    ]
-    .contains(&p.nth(0))
-    {
+    while p.at_any(FIRST_GroupCondition) {
         parse_GroupCondition(p);
     }
     p.close(marker, SyntaxKind::GroupClause);
 }
+const FIRST_HavingClause: TokenSet = TokenSet::new(&[SyntaxKind::HAVING]);
+const RECOVERY_HavingClause: TokenSet = TokenSet::new(&[
+    SyntaxKind::ORDER,
+    SyntaxKind::LIMIT,
+    SyntaxKind::OFFSET,
+    SyntaxKind::VALUES,
+]);
 /// [28] HavingClause -> 'HAVING' HavingCondition HavingCondition*
 pub(super) fn parse_HavingClause(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_HavingClause);
     p.expect(SyntaxKind::HAVING);
     parse_HavingCondition(p);
-    while [
-        SyntaxKind::IRIREF,
-        SyntaxKind::PNAME_NS,
-        SyntaxKind::LParen,
-        SyntaxKind::NOT,
-        SyntaxKind::STR,
-        SyntaxKind::LANG,
-        SyntaxKind::LANGMATCHES,
-        SyntaxKind::DATATYPE,
-        SyntaxKind::BOUND,
-        SyntaxKind::IRI,
-        SyntaxKind::URI,
-        SyntaxKind::BNODE,
-        SyntaxKind::RAND,
-        SyntaxKind::ABS,
-        SyntaxKind::CEIL,
-        SyntaxKind::FLOOR,
-        SyntaxKind::ROUND,
-        SyntaxKind::CONCAT,
-        SyntaxKind::STRLEN,
-        SyntaxKind::UCASE,
-        SyntaxKind::LCASE,
-        SyntaxKind::ENCODE_FOR_URI,
-        SyntaxKind::CONTAINS,
-        SyntaxKind::STRSTARTS,
-        SyntaxKind::STRENDS,
-        SyntaxKind::STRBEFORE,
-        SyntaxKind::STRAFTER,
-        SyntaxKind::YEAR,
-        SyntaxKind::MONTH,
-        SyntaxKind::DAY,
-        SyntaxKind::HOURS,
-        SyntaxKind::MINUTES,
-        SyntaxKind::SECONDS,
-        SyntaxKind::TIMEZONE,
-        SyntaxKind::TZ,
-        SyntaxKind::NOW,
-        SyntaxKind::UUID,
-        SyntaxKind::STRUUID,
-        SyntaxKind::MD5,
-        SyntaxKind::SHA1,
-        SyntaxKind::SHA256,
-        SyntaxKind::SHA384,
-        SyntaxKind::SHA512,
-        SyntaxKind::COALESCE,
-        SyntaxKind::IF,
-        SyntaxKind::STRLANG,
-        SyntaxKind::STRDT,
-        SyntaxKind::sameTerm,
-        SyntaxKind::isIRI,
-        SyntaxKind::isURI,
-        SyntaxKind::isBLANK,
-        SyntaxKind::isLITERAL,
-        SyntaxKind::isNUMERIC,
-        SyntaxKind::REGEX,
-        SyntaxKind::SUBSTR,
-        SyntaxKind::REPLACE,
-        SyntaxKind::EXISTS,
-        SyntaxKind::COUNT,
-        SyntaxKind::SUM,
-        SyntaxKind::MIN,
-        SyntaxKind::MAX,
-        SyntaxKind::AVG,
-        SyntaxKind::SAMPLE,
-        SyntaxKind::GROUP_CONCAT,
-        SyntaxKind::PNAME_LN,
-    ]
-    .contains(&p.nth(0))
-    {
+    while p.at_any(FIRST_HavingCondition) {
         parse_HavingCondition(p);
     }
     p.close(marker, SyntaxKind::HavingClause);
 }
+const FIRST_OrderClause: TokenSet = TokenSet::new(&[SyntaxKind::ORDER]);
+const RECOVERY_OrderClause: TokenSet =
+    TokenSet::new(&[SyntaxKind::LIMIT, SyntaxKind::OFFSET, SyntaxKind::VALUES]);
 /// [29] OrderClause -> 'ORDER' 'BY' OrderCondition OrderCondition*
 pub(super) fn parse_OrderClause(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_OrderClause);
     p.expect(SyntaxKind::ORDER);
     p.expect(SyntaxKind::BY);
     parse_OrderCondition(p);
-    while [
-        SyntaxKind::IRIREF,
-        SyntaxKind::PNAME_NS,
-        SyntaxKind::LParen,
-        SyntaxKind::ASC,
-        SyntaxKind::DESC,
-        SyntaxKind::VAR1,
-        SyntaxKind::VAR2,
-        SyntaxKind::NOT,
-        SyntaxKind::STR,
-        SyntaxKind::LANG,
-        SyntaxKind::LANGMATCHES,
-        SyntaxKind::DATATYPE,
-        SyntaxKind::BOUND,
-        SyntaxKind::IRI,
-        SyntaxKind::URI,
-        SyntaxKind::BNODE,
-        SyntaxKind::RAND,
-        SyntaxKind::ABS,
-        SyntaxKind::CEIL,
-        SyntaxKind::FLOOR,
-        SyntaxKind::ROUND,
-        SyntaxKind::CONCAT,
-        SyntaxKind::STRLEN,
-        SyntaxKind::UCASE,
-        SyntaxKind::LCASE,
-        SyntaxKind::ENCODE_FOR_URI,
-        SyntaxKind::CONTAINS,
-        SyntaxKind::STRSTARTS,
-        SyntaxKind::STRENDS,
-        SyntaxKind::STRBEFORE,
-        SyntaxKind::STRAFTER,
-        SyntaxKind::YEAR,
-        SyntaxKind::MONTH,
-        SyntaxKind::DAY,
-        SyntaxKind::HOURS,
-        SyntaxKind::MINUTES,
-        SyntaxKind::SECONDS,
-        SyntaxKind::TIMEZONE,
-        SyntaxKind::TZ,
-        SyntaxKind::NOW,
-        SyntaxKind::UUID,
-        SyntaxKind::STRUUID,
-        SyntaxKind::MD5,
-        SyntaxKind::SHA1,
-        SyntaxKind::SHA256,
-        SyntaxKind::SHA384,
-        SyntaxKind::SHA512,
-        SyntaxKind::COALESCE,
-        SyntaxKind::IF,
-        SyntaxKind::STRLANG,
-        SyntaxKind::STRDT,
-        SyntaxKind::sameTerm,
-        SyntaxKind::isIRI,
-        SyntaxKind::isURI,
-        SyntaxKind::isBLANK,
-        SyntaxKind::isLITERAL,
-        SyntaxKind::isNUMERIC,
-        SyntaxKind::REGEX,
-        SyntaxKind::SUBSTR,
-        SyntaxKind::REPLACE,
-        SyntaxKind::EXISTS,
-        SyntaxKind::COUNT,
-        SyntaxKind::SUM,
-        SyntaxKind::MIN,
-        SyntaxKind::MAX,
-        SyntaxKind::AVG,
-        SyntaxKind::SAMPLE,
-        SyntaxKind::GROUP_CONCAT,
-        SyntaxKind::PNAME_LN,
-    ]
-    .contains(&p.nth(0))
-    {
+    while p.at_any(FIRST_OrderCondition) {
         parse_OrderCondition(p);
     }
     p.close(marker, SyntaxKind::OrderClause);
 }
+const FIRST_LimitOffsetClauses: TokenSet = TokenSet::new(&[SyntaxKind::LIMIT, SyntaxKind::OFFSET]);
+const RECOVERY_LimitOffsetClauses: TokenSet = TokenSet::new(&[SyntaxKind::VALUES]);
 /// [30] LimitOffsetClauses -> LimitClause OffsetClause? | OffsetClause LimitClause?
 pub(super) fn parse_LimitOffsetClauses(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_LimitOffsetClauses);
     match p.nth(0) {
         SyntaxKind::LIMIT => {
             parse_LimitClause(p);
-            if p.at_any(&[SyntaxKind::OFFSET]) {
+            if p.at_any(FIRST_OffsetClause) {
                 parse_OffsetClause(p);
             }
         }
         SyntaxKind::OFFSET => {
             parse_OffsetClause(p);
-            if p.at_any(&[SyntaxKind::LIMIT]) {
+            if p.at_any(FIRST_LimitClause) {
                 parse_LimitClause(p);
             }
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[SyntaxKind::LIMIT, SyntaxKind::OFFSET]);
         }
     };
     p.close(marker, SyntaxKind::LimitOffsetClauses);
 }
+const FIRST_GroupCondition: TokenSet = TokenSet::new(&[
+    SyntaxKind::IRIREF,
+    SyntaxKind::PNAME_NS,
+    SyntaxKind::LParen,
+    SyntaxKind::VAR1,
+    SyntaxKind::VAR2,
+    SyntaxKind::NOT,
+    SyntaxKind::STR,
+    SyntaxKind::LANG,
+    SyntaxKind::LANGMATCHES,
+    SyntaxKind::DATATYPE,
+    SyntaxKind::BOUND,
+    SyntaxKind::IRI,
+    SyntaxKind::URI,
+    SyntaxKind::BNODE,
+    SyntaxKind::RAND,
+    SyntaxKind::ABS,
+    SyntaxKind::CEIL,
+    SyntaxKind::FLOOR,
+    SyntaxKind::ROUND,
+    SyntaxKind::CONCAT,
+    SyntaxKind::STRLEN,
+    SyntaxKind::UCASE,
+    SyntaxKind::LCASE,
+    SyntaxKind::ENCODE_FOR_URI,
+    SyntaxKind::CONTAINS,
+    SyntaxKind::STRSTARTS,
+    SyntaxKind::STRENDS,
+    SyntaxKind::STRBEFORE,
+    SyntaxKind::STRAFTER,
+    SyntaxKind::YEAR,
+    SyntaxKind::MONTH,
+    SyntaxKind::DAY,
+    SyntaxKind::HOURS,
+    SyntaxKind::MINUTES,
+    SyntaxKind::SECONDS,
+    SyntaxKind::TIMEZONE,
+    SyntaxKind::TZ,
+    SyntaxKind::NOW,
+    SyntaxKind::UUID,
+    SyntaxKind::STRUUID,
+    SyntaxKind::MD5,
+    SyntaxKind::SHA1,
+    SyntaxKind::SHA256,
+    SyntaxKind::SHA384,
+    SyntaxKind::SHA512,
+    SyntaxKind::COALESCE,
+    SyntaxKind::IF,
+    SyntaxKind::STRLANG,
+    SyntaxKind::STRDT,
+    SyntaxKind::sameTerm,
+    SyntaxKind::isIRI,
+    SyntaxKind::isURI,
+    SyntaxKind::isBLANK,
+    SyntaxKind::isLITERAL,
+    SyntaxKind::isNUMERIC,
+    SyntaxKind::REGEX,
+    SyntaxKind::SUBSTR,
+    SyntaxKind::REPLACE,
+    SyntaxKind::EXISTS,
+    SyntaxKind::COUNT,
+    SyntaxKind::SUM,
+    SyntaxKind::MIN,
+    SyntaxKind::MAX,
+    SyntaxKind::AVG,
+    SyntaxKind::SAMPLE,
+    SyntaxKind::GROUP_CONCAT,
+    SyntaxKind::PNAME_LN,
+]);
+const FIRST_GroupCondition_0: TokenSet = TokenSet::new(&[SyntaxKind::AS]);
+const RECOVERY_GroupCondition: TokenSet = TokenSet::new(&[
+    SyntaxKind::HAVING,
+    SyntaxKind::ORDER,
+    SyntaxKind::LIMIT,
+    SyntaxKind::OFFSET,
+    SyntaxKind::VALUES,
+]);
 /// [31] GroupCondition -> BuiltInCall | FunctionCall | '(' Expression ('AS' Var)? ')' | Var
 pub(super) fn parse_GroupCondition(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_GroupCondition);
     match p.nth(0) {
         SyntaxKind::NOT
         | SyntaxKind::STR
@@ -995,7 +1120,7 @@ pub(super) fn parse_GroupCondition(p: &mut Parser) {
         SyntaxKind::LParen => {
             p.expect(SyntaxKind::LParen);
             parse_Expression(p);
-            if p.at_any(&[SyntaxKind::AS]) {
+            if p.at_any(FIRST_GroupCondition_0) {
                 p.expect(SyntaxKind::AS);
                 parse_Var(p);
             }
@@ -1004,20 +1129,98 @@ pub(super) fn parse_GroupCondition(p: &mut Parser) {
         SyntaxKind::VAR1 | SyntaxKind::VAR2 => {
             parse_Var(p);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::IRIREF,
+                SyntaxKind::PNAME_NS,
+                SyntaxKind::LParen,
+                SyntaxKind::VAR1,
+                SyntaxKind::VAR2,
+                SyntaxKind::NOT,
+                SyntaxKind::STR,
+                SyntaxKind::LANG,
+                SyntaxKind::LANGMATCHES,
+                SyntaxKind::DATATYPE,
+                SyntaxKind::BOUND,
+                SyntaxKind::IRI,
+                SyntaxKind::URI,
+                SyntaxKind::BNODE,
+                SyntaxKind::RAND,
+                SyntaxKind::ABS,
+                SyntaxKind::CEIL,
+                SyntaxKind::FLOOR,
+                SyntaxKind::ROUND,
+                SyntaxKind::CONCAT,
+                SyntaxKind::STRLEN,
+                SyntaxKind::UCASE,
+                SyntaxKind::LCASE,
+                SyntaxKind::ENCODE_FOR_URI,
+                SyntaxKind::CONTAINS,
+                SyntaxKind::STRSTARTS,
+                SyntaxKind::STRENDS,
+                SyntaxKind::STRBEFORE,
+                SyntaxKind::STRAFTER,
+                SyntaxKind::YEAR,
+                SyntaxKind::MONTH,
+                SyntaxKind::DAY,
+                SyntaxKind::HOURS,
+                SyntaxKind::MINUTES,
+                SyntaxKind::SECONDS,
+                SyntaxKind::TIMEZONE,
+                SyntaxKind::TZ,
+                SyntaxKind::NOW,
+                SyntaxKind::UUID,
+                SyntaxKind::STRUUID,
+                SyntaxKind::MD5,
+                SyntaxKind::SHA1,
+                SyntaxKind::SHA256,
+                SyntaxKind::SHA384,
+                SyntaxKind::SHA512,
+                SyntaxKind::COALESCE,
+                SyntaxKind::IF,
+                SyntaxKind::STRLANG,
+                SyntaxKind::STRDT,
+                SyntaxKind::sameTerm,
+                SyntaxKind::isIRI,
+                SyntaxKind::isURI,
+                SyntaxKind::isBLANK,
+                SyntaxKind::isLITERAL,
+                SyntaxKind::isNUMERIC,
+                SyntaxKind::REGEX,
+                SyntaxKind::SUBSTR,
+                SyntaxKind::REPLACE,
+                SyntaxKind::EXISTS,
+                SyntaxKind::COUNT,
+                SyntaxKind::SUM,
+                SyntaxKind::MIN,
+                SyntaxKind::MAX,
+                SyntaxKind::AVG,
+                SyntaxKind::SAMPLE,
+                SyntaxKind::GROUP_CONCAT,
+                SyntaxKind::PNAME_LN,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::GroupCondition);
 }
+const RECOVERY_BuiltInCall: TokenSet = TokenSet::new(&[
+    SyntaxKind::HAVING,
+    SyntaxKind::ORDER,
+    SyntaxKind::LIMIT,
+    SyntaxKind::OFFSET,
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [32] BuiltInCall -> Aggregate | 'STR' '(' Expression ')' | 'LANG' '(' Expression ')' | 'LANGMATCHES' '(' Expression ',' Expression ')' | 'DATATYPE' '(' Expression ')' | 'BOUND' '(' Var ')' | 'IRI' '(' Expression ')' | 'URI' '(' Expression ')' | 'BNODE' ('(' Expression ')' | 'NIL') | 'RAND' 'NIL' | 'ABS' '(' Expression ')' | 'CEIL' '(' Expression ')' | 'FLOOR' '(' Expression ')' | 'ROUND' '(' Expression ')' | 'CONCAT' ExpressionList | SubstringExpression | 'STRLEN' '(' Expression ')' | StrReplaceExpression | 'UCASE' '(' Expression ')' | 'LCASE' '(' Expression ')' | 'ENCODE_FOR_URI' '(' Expression ')' | 'CONTAINS' '(' Expression ',' Expression ')' | 'STRSTARTS' '(' Expression ',' Expression ')' | 'STRENDS' '(' Expression ',' Expression ')' | 'STRBEFORE' '(' Expression ',' Expression ')' | 'STRAFTER' '(' Expression ',' Expression ')' | 'YEAR' '(' Expression ')' | 'MONTH' '(' Expression ')' | 'DAY' '(' Expression ')' | 'HOURS' '(' Expression ')' | 'MINUTES' '(' Expression ')' | 'SECONDS' '(' Expression ')' | 'TIMEZONE' '(' Expression ')' | 'TZ' '(' Expression ')' | 'NOW' 'NIL' | 'UUID' 'NIL' | 'STRUUID' 'NIL' | 'MD5' '(' Expression ')' | 'SHA1' '(' Expression ')' | 'SHA256' '(' Expression ')' | 'SHA384' '(' Expression ')' | 'SHA512' '(' Expression ')' | 'COALESCE' ExpressionList | 'IF' '(' Expression ',' Expression ',' Expression ')' | 'STRLANG' '(' Expression ',' Expression ')' | 'STRDT' '(' Expression ',' Expression ')' | 'sameTerm' '(' Expression ',' Expression ')' | 'isIRI' '(' Expression ')' | 'isURI' '(' Expression ')' | 'isBLANK' '(' Expression ')' | 'isLITERAL' '(' Expression ')' | 'isNUMERIC' '(' Expression ')' | RegexExpression | ExistsFunc | NotExistsFunc
 pub(super) fn parse_BuiltInCall(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_BuiltInCall);
     match p.nth(0) {
         SyntaxKind::COUNT
         | SyntaxKind::SUM
@@ -1044,7 +1247,7 @@ pub(super) fn parse_BuiltInCall(p: &mut Parser) {
             p.expect(SyntaxKind::LANGMATCHES);
             p.expect(SyntaxKind::LParen);
             parse_Expression(p);
-            p.expect(SyntaxKind::Colon);
+            p.expect(SyntaxKind::Comma);
             parse_Expression(p);
             p.expect(SyntaxKind::RParen);
         }
@@ -1083,13 +1286,8 @@ pub(super) fn parse_BuiltInCall(p: &mut Parser) {
                 SyntaxKind::NIL => {
                     p.expect(SyntaxKind::NIL);
                 }
-                SyntaxKind::Eof => {
-                    eprintln!("Unexpected Eof");
-                    p.close(marker, SyntaxKind::Error);
-                    return;
-                }
                 _ => {
-                    p.advance_with_error("Expected ....");
+                    p.err_recover(&[SyntaxKind::LParen, SyntaxKind::NIL]);
                 }
             };
         }
@@ -1159,7 +1357,7 @@ pub(super) fn parse_BuiltInCall(p: &mut Parser) {
             p.expect(SyntaxKind::CONTAINS);
             p.expect(SyntaxKind::LParen);
             parse_Expression(p);
-            p.expect(SyntaxKind::Colon);
+            p.expect(SyntaxKind::Comma);
             parse_Expression(p);
             p.expect(SyntaxKind::RParen);
         }
@@ -1167,7 +1365,7 @@ pub(super) fn parse_BuiltInCall(p: &mut Parser) {
             p.expect(SyntaxKind::STRSTARTS);
             p.expect(SyntaxKind::LParen);
             parse_Expression(p);
-            p.expect(SyntaxKind::Colon);
+            p.expect(SyntaxKind::Comma);
             parse_Expression(p);
             p.expect(SyntaxKind::RParen);
         }
@@ -1175,7 +1373,7 @@ pub(super) fn parse_BuiltInCall(p: &mut Parser) {
             p.expect(SyntaxKind::STRENDS);
             p.expect(SyntaxKind::LParen);
             parse_Expression(p);
-            p.expect(SyntaxKind::Colon);
+            p.expect(SyntaxKind::Comma);
             parse_Expression(p);
             p.expect(SyntaxKind::RParen);
         }
@@ -1183,7 +1381,7 @@ pub(super) fn parse_BuiltInCall(p: &mut Parser) {
             p.expect(SyntaxKind::STRBEFORE);
             p.expect(SyntaxKind::LParen);
             parse_Expression(p);
-            p.expect(SyntaxKind::Colon);
+            p.expect(SyntaxKind::Comma);
             parse_Expression(p);
             p.expect(SyntaxKind::RParen);
         }
@@ -1191,7 +1389,7 @@ pub(super) fn parse_BuiltInCall(p: &mut Parser) {
             p.expect(SyntaxKind::STRAFTER);
             p.expect(SyntaxKind::LParen);
             parse_Expression(p);
-            p.expect(SyntaxKind::Colon);
+            p.expect(SyntaxKind::Comma);
             parse_Expression(p);
             p.expect(SyntaxKind::RParen);
         }
@@ -1293,9 +1491,9 @@ pub(super) fn parse_BuiltInCall(p: &mut Parser) {
             p.expect(SyntaxKind::IF);
             p.expect(SyntaxKind::LParen);
             parse_Expression(p);
-            p.expect(SyntaxKind::Colon);
+            p.expect(SyntaxKind::Comma);
             parse_Expression(p);
-            p.expect(SyntaxKind::Colon);
+            p.expect(SyntaxKind::Comma);
             parse_Expression(p);
             p.expect(SyntaxKind::RParen);
         }
@@ -1303,7 +1501,7 @@ pub(super) fn parse_BuiltInCall(p: &mut Parser) {
             p.expect(SyntaxKind::STRLANG);
             p.expect(SyntaxKind::LParen);
             parse_Expression(p);
-            p.expect(SyntaxKind::Colon);
+            p.expect(SyntaxKind::Comma);
             parse_Expression(p);
             p.expect(SyntaxKind::RParen);
         }
@@ -1311,7 +1509,7 @@ pub(super) fn parse_BuiltInCall(p: &mut Parser) {
             p.expect(SyntaxKind::STRDT);
             p.expect(SyntaxKind::LParen);
             parse_Expression(p);
-            p.expect(SyntaxKind::Colon);
+            p.expect(SyntaxKind::Comma);
             parse_Expression(p);
             p.expect(SyntaxKind::RParen);
         }
@@ -1319,7 +1517,7 @@ pub(super) fn parse_BuiltInCall(p: &mut Parser) {
             p.expect(SyntaxKind::sameTerm);
             p.expect(SyntaxKind::LParen);
             parse_Expression(p);
-            p.expect(SyntaxKind::Colon);
+            p.expect(SyntaxKind::Comma);
             parse_Expression(p);
             p.expect(SyntaxKind::RParen);
         }
@@ -1362,33 +1560,188 @@ pub(super) fn parse_BuiltInCall(p: &mut Parser) {
         SyntaxKind::NOT => {
             parse_NotExistsFunc(p);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::NOT,
+                SyntaxKind::STR,
+                SyntaxKind::LANG,
+                SyntaxKind::LANGMATCHES,
+                SyntaxKind::DATATYPE,
+                SyntaxKind::BOUND,
+                SyntaxKind::IRI,
+                SyntaxKind::URI,
+                SyntaxKind::BNODE,
+                SyntaxKind::RAND,
+                SyntaxKind::ABS,
+                SyntaxKind::CEIL,
+                SyntaxKind::FLOOR,
+                SyntaxKind::ROUND,
+                SyntaxKind::CONCAT,
+                SyntaxKind::STRLEN,
+                SyntaxKind::UCASE,
+                SyntaxKind::LCASE,
+                SyntaxKind::ENCODE_FOR_URI,
+                SyntaxKind::CONTAINS,
+                SyntaxKind::STRSTARTS,
+                SyntaxKind::STRENDS,
+                SyntaxKind::STRBEFORE,
+                SyntaxKind::STRAFTER,
+                SyntaxKind::YEAR,
+                SyntaxKind::MONTH,
+                SyntaxKind::DAY,
+                SyntaxKind::HOURS,
+                SyntaxKind::MINUTES,
+                SyntaxKind::SECONDS,
+                SyntaxKind::TIMEZONE,
+                SyntaxKind::TZ,
+                SyntaxKind::NOW,
+                SyntaxKind::UUID,
+                SyntaxKind::STRUUID,
+                SyntaxKind::MD5,
+                SyntaxKind::SHA1,
+                SyntaxKind::SHA256,
+                SyntaxKind::SHA384,
+                SyntaxKind::SHA512,
+                SyntaxKind::COALESCE,
+                SyntaxKind::IF,
+                SyntaxKind::STRLANG,
+                SyntaxKind::STRDT,
+                SyntaxKind::sameTerm,
+                SyntaxKind::isIRI,
+                SyntaxKind::isURI,
+                SyntaxKind::isBLANK,
+                SyntaxKind::isLITERAL,
+                SyntaxKind::isNUMERIC,
+                SyntaxKind::REGEX,
+                SyntaxKind::SUBSTR,
+                SyntaxKind::REPLACE,
+                SyntaxKind::EXISTS,
+                SyntaxKind::COUNT,
+                SyntaxKind::SUM,
+                SyntaxKind::MIN,
+                SyntaxKind::MAX,
+                SyntaxKind::AVG,
+                SyntaxKind::SAMPLE,
+                SyntaxKind::GROUP_CONCAT,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::BuiltInCall);
 }
+const RECOVERY_FunctionCall: TokenSet = TokenSet::new(&[
+    SyntaxKind::HAVING,
+    SyntaxKind::ORDER,
+    SyntaxKind::LIMIT,
+    SyntaxKind::OFFSET,
+    SyntaxKind::VALUES,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+]);
 /// [33] FunctionCall -> iri ArgList
 pub(super) fn parse_FunctionCall(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_FunctionCall);
     parse_iri(p);
     parse_ArgList(p);
     p.close(marker, SyntaxKind::FunctionCall);
 }
+const FIRST_HavingCondition: TokenSet = TokenSet::new(&[
+    SyntaxKind::IRIREF,
+    SyntaxKind::PNAME_NS,
+    SyntaxKind::LParen,
+    SyntaxKind::NOT,
+    SyntaxKind::STR,
+    SyntaxKind::LANG,
+    SyntaxKind::LANGMATCHES,
+    SyntaxKind::DATATYPE,
+    SyntaxKind::BOUND,
+    SyntaxKind::IRI,
+    SyntaxKind::URI,
+    SyntaxKind::BNODE,
+    SyntaxKind::RAND,
+    SyntaxKind::ABS,
+    SyntaxKind::CEIL,
+    SyntaxKind::FLOOR,
+    SyntaxKind::ROUND,
+    SyntaxKind::CONCAT,
+    SyntaxKind::STRLEN,
+    SyntaxKind::UCASE,
+    SyntaxKind::LCASE,
+    SyntaxKind::ENCODE_FOR_URI,
+    SyntaxKind::CONTAINS,
+    SyntaxKind::STRSTARTS,
+    SyntaxKind::STRENDS,
+    SyntaxKind::STRBEFORE,
+    SyntaxKind::STRAFTER,
+    SyntaxKind::YEAR,
+    SyntaxKind::MONTH,
+    SyntaxKind::DAY,
+    SyntaxKind::HOURS,
+    SyntaxKind::MINUTES,
+    SyntaxKind::SECONDS,
+    SyntaxKind::TIMEZONE,
+    SyntaxKind::TZ,
+    SyntaxKind::NOW,
+    SyntaxKind::UUID,
+    SyntaxKind::STRUUID,
+    SyntaxKind::MD5,
+    SyntaxKind::SHA1,
+    SyntaxKind::SHA256,
+    SyntaxKind::SHA384,
+    SyntaxKind::SHA512,
+    SyntaxKind::COALESCE,
+    SyntaxKind::IF,
+    SyntaxKind::STRLANG,
+    SyntaxKind::STRDT,
+    SyntaxKind::sameTerm,
+    SyntaxKind::isIRI,
+    SyntaxKind::isURI,
+    SyntaxKind::isBLANK,
+    SyntaxKind::isLITERAL,
+    SyntaxKind::isNUMERIC,
+    SyntaxKind::REGEX,
+    SyntaxKind::SUBSTR,
+    SyntaxKind::REPLACE,
+    SyntaxKind::EXISTS,
+    SyntaxKind::COUNT,
+    SyntaxKind::SUM,
+    SyntaxKind::MIN,
+    SyntaxKind::MAX,
+    SyntaxKind::AVG,
+    SyntaxKind::SAMPLE,
+    SyntaxKind::GROUP_CONCAT,
+    SyntaxKind::PNAME_LN,
+]);
+const RECOVERY_HavingCondition: TokenSet = TokenSet::new(&[
+    SyntaxKind::ORDER,
+    SyntaxKind::LIMIT,
+    SyntaxKind::OFFSET,
+    SyntaxKind::VALUES,
+]);
 /// [34] HavingCondition -> Constraint
 pub(super) fn parse_HavingCondition(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_HavingCondition);
     parse_Constraint(p);
     p.close(marker, SyntaxKind::HavingCondition);
 }
+const RECOVERY_Constraint: TokenSet = TokenSet::new(&[
+    SyntaxKind::ORDER,
+    SyntaxKind::LIMIT,
+    SyntaxKind::OFFSET,
+    SyntaxKind::VALUES,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+]);
 /// [35] Constraint -> BrackettedExpression | BuiltInCall | FunctionCall
 pub(super) fn parse_Constraint(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_Constraint);
     match p.nth(0) {
         SyntaxKind::LParen => {
             parse_BrackettedExpression(p);
@@ -1459,20 +1812,154 @@ pub(super) fn parse_Constraint(p: &mut Parser) {
         SyntaxKind::IRIREF | SyntaxKind::PNAME_NS | SyntaxKind::PNAME_LN => {
             parse_FunctionCall(p);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::IRIREF,
+                SyntaxKind::PNAME_NS,
+                SyntaxKind::LParen,
+                SyntaxKind::NOT,
+                SyntaxKind::STR,
+                SyntaxKind::LANG,
+                SyntaxKind::LANGMATCHES,
+                SyntaxKind::DATATYPE,
+                SyntaxKind::BOUND,
+                SyntaxKind::IRI,
+                SyntaxKind::URI,
+                SyntaxKind::BNODE,
+                SyntaxKind::RAND,
+                SyntaxKind::ABS,
+                SyntaxKind::CEIL,
+                SyntaxKind::FLOOR,
+                SyntaxKind::ROUND,
+                SyntaxKind::CONCAT,
+                SyntaxKind::STRLEN,
+                SyntaxKind::UCASE,
+                SyntaxKind::LCASE,
+                SyntaxKind::ENCODE_FOR_URI,
+                SyntaxKind::CONTAINS,
+                SyntaxKind::STRSTARTS,
+                SyntaxKind::STRENDS,
+                SyntaxKind::STRBEFORE,
+                SyntaxKind::STRAFTER,
+                SyntaxKind::YEAR,
+                SyntaxKind::MONTH,
+                SyntaxKind::DAY,
+                SyntaxKind::HOURS,
+                SyntaxKind::MINUTES,
+                SyntaxKind::SECONDS,
+                SyntaxKind::TIMEZONE,
+                SyntaxKind::TZ,
+                SyntaxKind::NOW,
+                SyntaxKind::UUID,
+                SyntaxKind::STRUUID,
+                SyntaxKind::MD5,
+                SyntaxKind::SHA1,
+                SyntaxKind::SHA256,
+                SyntaxKind::SHA384,
+                SyntaxKind::SHA512,
+                SyntaxKind::COALESCE,
+                SyntaxKind::IF,
+                SyntaxKind::STRLANG,
+                SyntaxKind::STRDT,
+                SyntaxKind::sameTerm,
+                SyntaxKind::isIRI,
+                SyntaxKind::isURI,
+                SyntaxKind::isBLANK,
+                SyntaxKind::isLITERAL,
+                SyntaxKind::isNUMERIC,
+                SyntaxKind::REGEX,
+                SyntaxKind::SUBSTR,
+                SyntaxKind::REPLACE,
+                SyntaxKind::EXISTS,
+                SyntaxKind::COUNT,
+                SyntaxKind::SUM,
+                SyntaxKind::MIN,
+                SyntaxKind::MAX,
+                SyntaxKind::AVG,
+                SyntaxKind::SAMPLE,
+                SyntaxKind::GROUP_CONCAT,
+                SyntaxKind::PNAME_LN,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::Constraint);
 }
+const FIRST_OrderCondition: TokenSet = TokenSet::new(&[
+    SyntaxKind::IRIREF,
+    SyntaxKind::PNAME_NS,
+    SyntaxKind::LParen,
+    SyntaxKind::ASC,
+    SyntaxKind::DESC,
+    SyntaxKind::VAR1,
+    SyntaxKind::VAR2,
+    SyntaxKind::NOT,
+    SyntaxKind::STR,
+    SyntaxKind::LANG,
+    SyntaxKind::LANGMATCHES,
+    SyntaxKind::DATATYPE,
+    SyntaxKind::BOUND,
+    SyntaxKind::IRI,
+    SyntaxKind::URI,
+    SyntaxKind::BNODE,
+    SyntaxKind::RAND,
+    SyntaxKind::ABS,
+    SyntaxKind::CEIL,
+    SyntaxKind::FLOOR,
+    SyntaxKind::ROUND,
+    SyntaxKind::CONCAT,
+    SyntaxKind::STRLEN,
+    SyntaxKind::UCASE,
+    SyntaxKind::LCASE,
+    SyntaxKind::ENCODE_FOR_URI,
+    SyntaxKind::CONTAINS,
+    SyntaxKind::STRSTARTS,
+    SyntaxKind::STRENDS,
+    SyntaxKind::STRBEFORE,
+    SyntaxKind::STRAFTER,
+    SyntaxKind::YEAR,
+    SyntaxKind::MONTH,
+    SyntaxKind::DAY,
+    SyntaxKind::HOURS,
+    SyntaxKind::MINUTES,
+    SyntaxKind::SECONDS,
+    SyntaxKind::TIMEZONE,
+    SyntaxKind::TZ,
+    SyntaxKind::NOW,
+    SyntaxKind::UUID,
+    SyntaxKind::STRUUID,
+    SyntaxKind::MD5,
+    SyntaxKind::SHA1,
+    SyntaxKind::SHA256,
+    SyntaxKind::SHA384,
+    SyntaxKind::SHA512,
+    SyntaxKind::COALESCE,
+    SyntaxKind::IF,
+    SyntaxKind::STRLANG,
+    SyntaxKind::STRDT,
+    SyntaxKind::sameTerm,
+    SyntaxKind::isIRI,
+    SyntaxKind::isURI,
+    SyntaxKind::isBLANK,
+    SyntaxKind::isLITERAL,
+    SyntaxKind::isNUMERIC,
+    SyntaxKind::REGEX,
+    SyntaxKind::SUBSTR,
+    SyntaxKind::REPLACE,
+    SyntaxKind::EXISTS,
+    SyntaxKind::COUNT,
+    SyntaxKind::SUM,
+    SyntaxKind::MIN,
+    SyntaxKind::MAX,
+    SyntaxKind::AVG,
+    SyntaxKind::SAMPLE,
+    SyntaxKind::GROUP_CONCAT,
+    SyntaxKind::PNAME_LN,
+]);
+const RECOVERY_OrderCondition: TokenSet =
+    TokenSet::new(&[SyntaxKind::LIMIT, SyntaxKind::OFFSET, SyntaxKind::VALUES]);
 /// [36] OrderCondition -> ('ASC' | 'DESC') BrackettedExpression | Constraint | Var
 pub(super) fn parse_OrderCondition(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_OrderCondition);
     match p.nth(0) {
         SyntaxKind::ASC | SyntaxKind::DESC => {
             match p.nth(0) {
@@ -1482,13 +1969,8 @@ pub(super) fn parse_OrderCondition(p: &mut Parser) {
                 SyntaxKind::DESC => {
                     p.expect(SyntaxKind::DESC);
                 }
-                SyntaxKind::Eof => {
-                    eprintln!("Unexpected Eof");
-                    p.close(marker, SyntaxKind::Error);
-                    return;
-                }
                 _ => {
-                    p.advance_with_error("Expected ....");
+                    p.err_recover(&[SyntaxKind::ASC, SyntaxKind::DESC]);
                 }
             };
             parse_BrackettedExpression(p);
@@ -1631,52 +2113,207 @@ pub(super) fn parse_OrderCondition(p: &mut Parser) {
                 SyntaxKind::VAR1 | SyntaxKind::VAR2 => {
                     parse_Var(p);
                 }
-                SyntaxKind::Eof => {
-                    eprintln!("Unexpected Eof");
-                    p.close(marker, SyntaxKind::Error);
-                    return;
-                }
                 _ => {
-                    p.advance_with_error("Expected ....");
+                    p.err_recover(&[
+                        SyntaxKind::IRIREF,
+                        SyntaxKind::PNAME_NS,
+                        SyntaxKind::LParen,
+                        SyntaxKind::VAR1,
+                        SyntaxKind::VAR2,
+                        SyntaxKind::NOT,
+                        SyntaxKind::STR,
+                        SyntaxKind::LANG,
+                        SyntaxKind::LANGMATCHES,
+                        SyntaxKind::DATATYPE,
+                        SyntaxKind::BOUND,
+                        SyntaxKind::IRI,
+                        SyntaxKind::URI,
+                        SyntaxKind::BNODE,
+                        SyntaxKind::RAND,
+                        SyntaxKind::ABS,
+                        SyntaxKind::CEIL,
+                        SyntaxKind::FLOOR,
+                        SyntaxKind::ROUND,
+                        SyntaxKind::CONCAT,
+                        SyntaxKind::STRLEN,
+                        SyntaxKind::UCASE,
+                        SyntaxKind::LCASE,
+                        SyntaxKind::ENCODE_FOR_URI,
+                        SyntaxKind::CONTAINS,
+                        SyntaxKind::STRSTARTS,
+                        SyntaxKind::STRENDS,
+                        SyntaxKind::STRBEFORE,
+                        SyntaxKind::STRAFTER,
+                        SyntaxKind::YEAR,
+                        SyntaxKind::MONTH,
+                        SyntaxKind::DAY,
+                        SyntaxKind::HOURS,
+                        SyntaxKind::MINUTES,
+                        SyntaxKind::SECONDS,
+                        SyntaxKind::TIMEZONE,
+                        SyntaxKind::TZ,
+                        SyntaxKind::NOW,
+                        SyntaxKind::UUID,
+                        SyntaxKind::STRUUID,
+                        SyntaxKind::MD5,
+                        SyntaxKind::SHA1,
+                        SyntaxKind::SHA256,
+                        SyntaxKind::SHA384,
+                        SyntaxKind::SHA512,
+                        SyntaxKind::COALESCE,
+                        SyntaxKind::IF,
+                        SyntaxKind::STRLANG,
+                        SyntaxKind::STRDT,
+                        SyntaxKind::sameTerm,
+                        SyntaxKind::isIRI,
+                        SyntaxKind::isURI,
+                        SyntaxKind::isBLANK,
+                        SyntaxKind::isLITERAL,
+                        SyntaxKind::isNUMERIC,
+                        SyntaxKind::REGEX,
+                        SyntaxKind::SUBSTR,
+                        SyntaxKind::REPLACE,
+                        SyntaxKind::EXISTS,
+                        SyntaxKind::COUNT,
+                        SyntaxKind::SUM,
+                        SyntaxKind::MIN,
+                        SyntaxKind::MAX,
+                        SyntaxKind::AVG,
+                        SyntaxKind::SAMPLE,
+                        SyntaxKind::GROUP_CONCAT,
+                        SyntaxKind::PNAME_LN,
+                    ]);
                 }
             };
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::IRIREF,
+                SyntaxKind::PNAME_NS,
+                SyntaxKind::LParen,
+                SyntaxKind::ASC,
+                SyntaxKind::DESC,
+                SyntaxKind::VAR1,
+                SyntaxKind::VAR2,
+                SyntaxKind::NOT,
+                SyntaxKind::STR,
+                SyntaxKind::LANG,
+                SyntaxKind::LANGMATCHES,
+                SyntaxKind::DATATYPE,
+                SyntaxKind::BOUND,
+                SyntaxKind::IRI,
+                SyntaxKind::URI,
+                SyntaxKind::BNODE,
+                SyntaxKind::RAND,
+                SyntaxKind::ABS,
+                SyntaxKind::CEIL,
+                SyntaxKind::FLOOR,
+                SyntaxKind::ROUND,
+                SyntaxKind::CONCAT,
+                SyntaxKind::STRLEN,
+                SyntaxKind::UCASE,
+                SyntaxKind::LCASE,
+                SyntaxKind::ENCODE_FOR_URI,
+                SyntaxKind::CONTAINS,
+                SyntaxKind::STRSTARTS,
+                SyntaxKind::STRENDS,
+                SyntaxKind::STRBEFORE,
+                SyntaxKind::STRAFTER,
+                SyntaxKind::YEAR,
+                SyntaxKind::MONTH,
+                SyntaxKind::DAY,
+                SyntaxKind::HOURS,
+                SyntaxKind::MINUTES,
+                SyntaxKind::SECONDS,
+                SyntaxKind::TIMEZONE,
+                SyntaxKind::TZ,
+                SyntaxKind::NOW,
+                SyntaxKind::UUID,
+                SyntaxKind::STRUUID,
+                SyntaxKind::MD5,
+                SyntaxKind::SHA1,
+                SyntaxKind::SHA256,
+                SyntaxKind::SHA384,
+                SyntaxKind::SHA512,
+                SyntaxKind::COALESCE,
+                SyntaxKind::IF,
+                SyntaxKind::STRLANG,
+                SyntaxKind::STRDT,
+                SyntaxKind::sameTerm,
+                SyntaxKind::isIRI,
+                SyntaxKind::isURI,
+                SyntaxKind::isBLANK,
+                SyntaxKind::isLITERAL,
+                SyntaxKind::isNUMERIC,
+                SyntaxKind::REGEX,
+                SyntaxKind::SUBSTR,
+                SyntaxKind::REPLACE,
+                SyntaxKind::EXISTS,
+                SyntaxKind::COUNT,
+                SyntaxKind::SUM,
+                SyntaxKind::MIN,
+                SyntaxKind::MAX,
+                SyntaxKind::AVG,
+                SyntaxKind::SAMPLE,
+                SyntaxKind::GROUP_CONCAT,
+                SyntaxKind::PNAME_LN,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::OrderCondition);
 }
+const RECOVERY_BrackettedExpression: TokenSet = TokenSet::new(&[
+    SyntaxKind::ORDER,
+    SyntaxKind::LIMIT,
+    SyntaxKind::OFFSET,
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [37] BrackettedExpression -> '(' Expression ')'
 pub(super) fn parse_BrackettedExpression(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_BrackettedExpression);
     p.expect(SyntaxKind::LParen);
     parse_Expression(p);
     p.expect(SyntaxKind::RParen);
     p.close(marker, SyntaxKind::BrackettedExpression);
 }
+const FIRST_LimitClause: TokenSet = TokenSet::new(&[SyntaxKind::LIMIT]);
+const RECOVERY_LimitClause: TokenSet = TokenSet::new(&[SyntaxKind::OFFSET, SyntaxKind::VALUES]);
 /// [38] LimitClause -> 'LIMIT' 'INTEGER'
 pub(super) fn parse_LimitClause(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_LimitClause);
     p.expect(SyntaxKind::LIMIT);
     p.expect(SyntaxKind::INTEGER);
     p.close(marker, SyntaxKind::LimitClause);
 }
+const FIRST_OffsetClause: TokenSet = TokenSet::new(&[SyntaxKind::OFFSET]);
+const RECOVERY_OffsetClause: TokenSet = TokenSet::new(&[SyntaxKind::LIMIT, SyntaxKind::VALUES]);
 /// [39] OffsetClause -> 'OFFSET' 'INTEGER'
 pub(super) fn parse_OffsetClause(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_OffsetClause);
     p.expect(SyntaxKind::OFFSET);
     p.expect(SyntaxKind::INTEGER);
     p.close(marker, SyntaxKind::OffsetClause);
 }
+const RECOVERY_DataBlock: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+]);
 /// [40] DataBlock -> InlineDataOneVar | InlineDataFull
 pub(super) fn parse_DataBlock(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_DataBlock);
     match p.nth(0) {
         SyntaxKind::VAR1 | SyntaxKind::VAR2 => {
             parse_InlineDataOneVar(p);
@@ -1684,20 +2321,21 @@ pub(super) fn parse_DataBlock(p: &mut Parser) {
         SyntaxKind::LParen | SyntaxKind::NIL => {
             parse_InlineDataFull(p);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::LParen,
+                SyntaxKind::NIL,
+                SyntaxKind::VAR1,
+                SyntaxKind::VAR2,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::DataBlock);
 }
+const RECOVERY_UpdateOne: TokenSet = TokenSet::new(&[SyntaxKind::Semicolon]);
 /// [41] UpdateOne -> Load | Clear | Drop | Add | Move | Copy | Create | InsertData | DeleteData | DeleteWhere | Modify
 pub(super) fn parse_UpdateOne(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_UpdateOne);
     match p.nth(0) {
         SyntaxKind::LOAD => {
             parse_Load(p);
@@ -1732,56 +2370,74 @@ pub(super) fn parse_UpdateOne(p: &mut Parser) {
         SyntaxKind::WITH | SyntaxKind::DELETE | SyntaxKind::INSERT => {
             parse_Modify(p);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::LOAD,
+                SyntaxKind::CLEAR,
+                SyntaxKind::DROP,
+                SyntaxKind::CREATE,
+                SyntaxKind::ADD,
+                SyntaxKind::MOVE,
+                SyntaxKind::COPY,
+                SyntaxKind::INSERT_DATA,
+                SyntaxKind::DELETE_DATA,
+                SyntaxKind::DELETE_WHERE,
+                SyntaxKind::WITH,
+                SyntaxKind::DELETE,
+                SyntaxKind::INSERT,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::UpdateOne);
 }
+const FIRST_Load_0: TokenSet = TokenSet::new(&[SyntaxKind::SILENT]);
+const FIRST_Load_1: TokenSet = TokenSet::new(&[SyntaxKind::INTO]);
+const RECOVERY_Load: TokenSet = TokenSet::new(&[SyntaxKind::Semicolon]);
 /// [42] Load -> 'LOAD' 'SILENT'? iri ('INTO' GraphRef)?
 pub(super) fn parse_Load(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_Load);
     p.expect(SyntaxKind::LOAD);
-    if p.at_any(&[SyntaxKind::SILENT]) {
+    if p.at_any(FIRST_Load_0) {
         p.expect(SyntaxKind::SILENT);
     }
     parse_iri(p);
-    if p.at_any(&[SyntaxKind::INTO]) {
+    if p.at_any(FIRST_Load_1) {
         p.expect(SyntaxKind::INTO);
         parse_GraphRef(p);
     }
     p.close(marker, SyntaxKind::Load);
 }
+const FIRST_Clear_0: TokenSet = TokenSet::new(&[SyntaxKind::SILENT]);
+const RECOVERY_Clear: TokenSet = TokenSet::new(&[SyntaxKind::Semicolon]);
 /// [43] Clear -> 'CLEAR' 'SILENT'? GraphRefAll
 pub(super) fn parse_Clear(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_Clear);
     p.expect(SyntaxKind::CLEAR);
-    if p.at_any(&[SyntaxKind::SILENT]) {
+    if p.at_any(FIRST_Clear_0) {
         p.expect(SyntaxKind::SILENT);
     }
     parse_GraphRefAll(p);
     p.close(marker, SyntaxKind::Clear);
 }
+const FIRST_Drop_0: TokenSet = TokenSet::new(&[SyntaxKind::SILENT]);
+const RECOVERY_Drop: TokenSet = TokenSet::new(&[SyntaxKind::Semicolon]);
 /// [44] Drop -> 'DROP' 'SILENT'? GraphRefAll
 pub(super) fn parse_Drop(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_Drop);
     p.expect(SyntaxKind::DROP);
-    if p.at_any(&[SyntaxKind::SILENT]) {
+    if p.at_any(FIRST_Drop_0) {
         p.expect(SyntaxKind::SILENT);
     }
     parse_GraphRefAll(p);
     p.close(marker, SyntaxKind::Drop);
 }
+const FIRST_Add_0: TokenSet = TokenSet::new(&[SyntaxKind::SILENT]);
+const RECOVERY_Add: TokenSet = TokenSet::new(&[SyntaxKind::Semicolon]);
 /// [45] Add -> 'ADD' 'SILENT'? GraphOrDefault 'TO' GraphOrDefault
 pub(super) fn parse_Add(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_Add);
     p.expect(SyntaxKind::ADD);
-    if p.at_any(&[SyntaxKind::SILENT]) {
+    if p.at_any(FIRST_Add_0) {
         p.expect(SyntaxKind::SILENT);
     }
     parse_GraphOrDefault(p);
@@ -1789,11 +2445,13 @@ pub(super) fn parse_Add(p: &mut Parser) {
     parse_GraphOrDefault(p);
     p.close(marker, SyntaxKind::Add);
 }
+const FIRST_Move_0: TokenSet = TokenSet::new(&[SyntaxKind::SILENT]);
+const RECOVERY_Move: TokenSet = TokenSet::new(&[SyntaxKind::Semicolon]);
 /// [46] Move -> 'MOVE' 'SILENT'? GraphOrDefault 'TO' GraphOrDefault
 pub(super) fn parse_Move(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_Move);
     p.expect(SyntaxKind::MOVE);
-    if p.at_any(&[SyntaxKind::SILENT]) {
+    if p.at_any(FIRST_Move_0) {
         p.expect(SyntaxKind::SILENT);
     }
     parse_GraphOrDefault(p);
@@ -1801,11 +2459,13 @@ pub(super) fn parse_Move(p: &mut Parser) {
     parse_GraphOrDefault(p);
     p.close(marker, SyntaxKind::Move);
 }
+const FIRST_Copy_0: TokenSet = TokenSet::new(&[SyntaxKind::SILENT]);
+const RECOVERY_Copy: TokenSet = TokenSet::new(&[SyntaxKind::Semicolon]);
 /// [47] Copy -> 'COPY' 'SILENT'? GraphOrDefault 'TO' GraphOrDefault
 pub(super) fn parse_Copy(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_Copy);
     p.expect(SyntaxKind::COPY);
-    if p.at_any(&[SyntaxKind::SILENT]) {
+    if p.at_any(FIRST_Copy_0) {
         p.expect(SyntaxKind::SILENT);
     }
     parse_GraphOrDefault(p);
@@ -1813,80 +2473,84 @@ pub(super) fn parse_Copy(p: &mut Parser) {
     parse_GraphOrDefault(p);
     p.close(marker, SyntaxKind::Copy);
 }
+const FIRST_Create_0: TokenSet = TokenSet::new(&[SyntaxKind::SILENT]);
+const RECOVERY_Create: TokenSet = TokenSet::new(&[SyntaxKind::Semicolon]);
 /// [48] Create -> 'CREATE' 'SILENT'? GraphRef
 pub(super) fn parse_Create(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_Create);
     p.expect(SyntaxKind::CREATE);
-    if p.at_any(&[SyntaxKind::SILENT]) {
+    if p.at_any(FIRST_Create_0) {
         p.expect(SyntaxKind::SILENT);
     }
     parse_GraphRef(p);
     p.close(marker, SyntaxKind::Create);
 }
+const RECOVERY_InsertData: TokenSet = TokenSet::new(&[SyntaxKind::Semicolon]);
 /// [49] InsertData -> 'INSERT_DATA' QuadData
 pub(super) fn parse_InsertData(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_InsertData);
     p.expect(SyntaxKind::INSERT_DATA);
     parse_QuadData(p);
     p.close(marker, SyntaxKind::InsertData);
 }
+const RECOVERY_DeleteData: TokenSet = TokenSet::new(&[SyntaxKind::Semicolon]);
 /// [50] DeleteData -> 'DELETE_DATA' QuadData
 pub(super) fn parse_DeleteData(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_DeleteData);
     p.expect(SyntaxKind::DELETE_DATA);
     parse_QuadData(p);
     p.close(marker, SyntaxKind::DeleteData);
 }
+const RECOVERY_DeleteWhere: TokenSet = TokenSet::new(&[SyntaxKind::Semicolon]);
 /// [51] DeleteWhere -> 'DELETE_WHERE' QuadPattern
 pub(super) fn parse_DeleteWhere(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_DeleteWhere);
     p.expect(SyntaxKind::DELETE_WHERE);
     parse_QuadPattern(p);
     p.close(marker, SyntaxKind::DeleteWhere);
 }
+const FIRST_Modify_0: TokenSet = TokenSet::new(&[SyntaxKind::WITH]);
+const RECOVERY_Modify: TokenSet = TokenSet::new(&[SyntaxKind::Semicolon]);
 /// [52] Modify -> ('WITH' iri)? (DeleteClause InsertClause? | InsertClause) UsingClause* 'WHERE' GroupGraphPattern
 pub(super) fn parse_Modify(p: &mut Parser) {
-    let marker = p.open();
-    if p.at_any(&[SyntaxKind::WITH]) {
+    let marker = p.open_rule(RECOVERY_Modify);
+    if p.at_any(FIRST_Modify_0) {
         p.expect(SyntaxKind::WITH);
         parse_iri(p);
     }
     match p.nth(0) {
         SyntaxKind::DELETE => {
             parse_DeleteClause(p);
-            if p.at_any(&[SyntaxKind::INSERT]) {
+            if p.at_any(FIRST_InsertClause) {
                 parse_InsertClause(p);
             }
         }
         SyntaxKind::INSERT => {
             parse_InsertClause(p);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[SyntaxKind::DELETE, SyntaxKind::INSERT]);
         }
     };
-    while [SyntaxKind::USING].contains(&p.nth(0)) {
+    while p.at_any(FIRST_UsingClause) {
         parse_UsingClause(p);
     }
     p.expect(SyntaxKind::WHERE);
     parse_GroupGraphPattern(p);
     p.close(marker, SyntaxKind::Modify);
 }
+const RECOVERY_GraphRef: TokenSet = TokenSet::new(&[SyntaxKind::Semicolon]);
 /// [53] GraphRef -> 'GRAPH' iri
 pub(super) fn parse_GraphRef(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_GraphRef);
     p.expect(SyntaxKind::GRAPH);
     parse_iri(p);
     p.close(marker, SyntaxKind::GraphRef);
 }
+const RECOVERY_GraphRefAll: TokenSet = TokenSet::new(&[SyntaxKind::Semicolon]);
 /// [54] GraphRefAll -> GraphRef | 'DEFAULT' | 'NAMED' | 'ALL'
 pub(super) fn parse_GraphRefAll(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_GraphRefAll);
     match p.nth(0) {
         SyntaxKind::GRAPH => {
             parse_GraphRef(p);
@@ -1900,74 +2564,96 @@ pub(super) fn parse_GraphRefAll(p: &mut Parser) {
         SyntaxKind::ALL => {
             p.expect(SyntaxKind::ALL);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::NAMED,
+                SyntaxKind::DEFAULT,
+                SyntaxKind::GRAPH,
+                SyntaxKind::ALL,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::GraphRefAll);
 }
+const FIRST_GraphOrDefault_0: TokenSet = TokenSet::new(&[SyntaxKind::GRAPH]);
+const RECOVERY_GraphOrDefault: TokenSet = TokenSet::new(&[SyntaxKind::Semicolon]);
 /// [55] GraphOrDefault -> 'DEFAULT' | 'GRAPH'? iri
 pub(super) fn parse_GraphOrDefault(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_GraphOrDefault);
     match p.nth(0) {
         SyntaxKind::DEFAULT => {
             p.expect(SyntaxKind::DEFAULT);
         }
         SyntaxKind::IRIREF | SyntaxKind::PNAME_NS | SyntaxKind::GRAPH | SyntaxKind::PNAME_LN => {
-            if p.at_any(&[SyntaxKind::GRAPH]) {
+            if p.at_any(FIRST_GraphOrDefault_0) {
                 p.expect(SyntaxKind::GRAPH);
             }
             parse_iri(p);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::IRIREF,
+                SyntaxKind::PNAME_NS,
+                SyntaxKind::DEFAULT,
+                SyntaxKind::GRAPH,
+                SyntaxKind::PNAME_LN,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::GraphOrDefault);
 }
+const RECOVERY_QuadData: TokenSet = TokenSet::new(&[SyntaxKind::Semicolon]);
 /// [56] QuadData -> '{' Quads '}'
 pub(super) fn parse_QuadData(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_QuadData);
     p.expect(SyntaxKind::LCurly);
     parse_Quads(p);
+    while p.recover_in_braces(FIRST_Quads) {
+        parse_Quads(p);
+    }
     p.expect(SyntaxKind::RCurly);
     p.close(marker, SyntaxKind::QuadData);
 }
+const RECOVERY_QuadPattern: TokenSet = TokenSet::new(&[
+    SyntaxKind::WHERE,
+    SyntaxKind::Semicolon,
+    SyntaxKind::INSERT,
+    SyntaxKind::USING,
+]);
 /// [57] QuadPattern -> '{' Quads '}'
 pub(super) fn parse_QuadPattern(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_QuadPattern);
     p.expect(SyntaxKind::LCurly);
     parse_Quads(p);
+    while p.recover_in_braces(FIRST_Quads) {
+        parse_Quads(p);
+    }
     p.expect(SyntaxKind::RCurly);
     p.close(marker, SyntaxKind::QuadPattern);
 }
+const RECOVERY_DeleteClause: TokenSet =
+    TokenSet::new(&[SyntaxKind::WHERE, SyntaxKind::INSERT, SyntaxKind::USING]);
 /// [58] DeleteClause -> 'DELETE' QuadPattern
 pub(super) fn parse_DeleteClause(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_DeleteClause);
     p.expect(SyntaxKind::DELETE);
     parse_QuadPattern(p);
     p.close(marker, SyntaxKind::DeleteClause);
 }
+const FIRST_InsertClause: TokenSet = TokenSet::new(&[SyntaxKind::INSERT]);
+const RECOVERY_InsertClause: TokenSet = TokenSet::new(&[SyntaxKind::WHERE, SyntaxKind::USING]);
 /// [59] InsertClause -> 'INSERT' QuadPattern
 pub(super) fn parse_InsertClause(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_InsertClause);
     p.expect(SyntaxKind::INSERT);
     parse_QuadPattern(p);
     p.close(marker, SyntaxKind::InsertClause);
 }
+const FIRST_UsingClause: TokenSet = TokenSet::new(&[SyntaxKind::USING]);
+const RECOVERY_UsingClause: TokenSet = TokenSet::new(&[SyntaxKind::WHERE, SyntaxKind::USING]);
 /// [60] UsingClause -> 'USING' (iri | 'NAMED' iri)
 pub(super) fn parse_UsingClause(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_UsingClause);
     p.expect(SyntaxKind::USING);
     match p.nth(0) {
         SyntaxKind::IRIREF | SyntaxKind::PNAME_NS | SyntaxKind::PNAME_LN => {
@@ -1977,157 +2663,116 @@ pub(super) fn parse_UsingClause(p: &mut Parser) {
             p.expect(SyntaxKind::NAMED);
             parse_iri(p);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::IRIREF,
+                SyntaxKind::PNAME_NS,
+                SyntaxKind::NAMED,
+                SyntaxKind::PNAME_LN,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::UsingClause);
 }
+const FIRST_Quads: TokenSet = TokenSet::new(&[
+    SyntaxKind::IRIREF,
+    SyntaxKind::PNAME_NS,
+    SyntaxKind::LParen,
+    SyntaxKind::INTEGER,
+    SyntaxKind::GRAPH,
+    SyntaxKind::NIL,
+    SyntaxKind::LBrack,
+    SyntaxKind::VAR1,
+    SyntaxKind::VAR2,
+    SyntaxKind::DECIMAL,
+    SyntaxKind::DOUBLE,
+    SyntaxKind::INTEGER_POSITIVE,
+    SyntaxKind::DECIMAL_POSITIVE,
+    SyntaxKind::DOUBLE_POSITIVE,
+    SyntaxKind::INTEGER_NEGATIVE,
+    SyntaxKind::DECIMAL_NEGATIVE,
+    SyntaxKind::DOUBLE_NEGATIVE,
+    SyntaxKind::True,
+    SyntaxKind::False,
+    SyntaxKind::STRING_LITERAL1,
+    SyntaxKind::STRING_LITERAL2,
+    SyntaxKind::STRING_LITERAL_LONG1,
+    SyntaxKind::STRING_LITERAL_LONG2,
+    SyntaxKind::PNAME_LN,
+    SyntaxKind::BLANK_NODE_LABEL,
+    SyntaxKind::ANON,
+]);
+const FIRST_Quads_0: TokenSet = TokenSet::new(&[SyntaxKind::Dot]);
+const FIRST_Quads_1: TokenSet = TokenSet::new(&[SyntaxKind::GRAPH]);
 /// [61] Quads -> TriplesTemplate? (QuadsNotTriples '.'? TriplesTemplate?)*
 pub(super) fn parse_Quads(p: &mut Parser) {
-    if !p.at_any(&[
-        SyntaxKind::IRIREF,
-        SyntaxKind::PNAME_NS,
-        SyntaxKind::LParen,
-        SyntaxKind::INTEGER,
-        SyntaxKind::GRAPH,
-        SyntaxKind::NIL,
-        SyntaxKind::LBrack,
-        SyntaxKind::VAR1,
-        SyntaxKind::VAR2,
-        SyntaxKind::DECIMAL,
-        SyntaxKind::DOUBLE,
-        SyntaxKind::INTEGER_POSITIVE,
-        SyntaxKind::DECIMAL_POSITIVE,
-        SyntaxKind::DOUBLE_POSITIVE,
-        SyntaxKind::INTEGER_NEGATIVE,
-        SyntaxKind::DECIMAL_NEGATIVE,
-        SyntaxKind::DOUBLE_NEGATIVE,
-        SyntaxKind::True,
-        SyntaxKind::False,
-        SyntaxKind::STRING_LITERAL1,
-        SyntaxKind::STRING_LITERAL2,
-        SyntaxKind::STRING_LITERAL_LONG1,
-        SyntaxKind::STRING_LITERAL_LONG2,
-        SyntaxKind::PNAME_LN,
-        SyntaxKind::BLANK_NODE_LABEL,
-        SyntaxKind::ANON,
-    ]) {
+    if !p.at_any(FIRST_Quads) {
         return;
     }
     let marker = p.open();
-    if p.at_any(&[
-        SyntaxKind::IRIREF,
-        SyntaxKind::PNAME_NS,
-        SyntaxKind::LParen,
-        SyntaxKind::INTEGER,
-        SyntaxKind::NIL,
-        SyntaxKind::LBrack,
-        SyntaxKind::VAR1,
-        SyntaxKind::VAR2,
-        SyntaxKind::DECIMAL,
-        SyntaxKind::DOUBLE,
-        SyntaxKind::INTEGER_POSITIVE,
-        SyntaxKind::DECIMAL_POSITIVE,
-        SyntaxKind::DOUBLE_POSITIVE,
-        SyntaxKind::INTEGER_NEGATIVE,
-        SyntaxKind::DECIMAL_NEGATIVE,
-        SyntaxKind::DOUBLE_NEGATIVE,
-        SyntaxKind::True,
-        SyntaxKind::False,
-        SyntaxKind::STRING_LITERAL1,
-        SyntaxKind::STRING_LITERAL2,
-        SyntaxKind::STRING_LITERAL_LONG1,
-        SyntaxKind::STRING_LITERAL_LONG2,
-        SyntaxKind::PNAME_LN,
-        SyntaxKind::BLANK_NODE_LABEL,
-        SyntaxKind::ANON,
-    ]) {
+    if p.at_any(FIRST_TriplesTemplate) {
         parse_TriplesTemplate(p);
     }
-    while [SyntaxKind::GRAPH].contains(&p.nth(0)) {
+    while p.at_any(FIRST_Quads_1) {
         parse_QuadsNotTriples(p);
-        if p.at_any(&[SyntaxKind::Dot]) {
+        if p.at_any(FIRST_Quads_0) {
             p.expect(SyntaxKind::Dot);
         }
-        if p.at_any(&[
-            SyntaxKind::IRIREF,
-            SyntaxKind::PNAME_NS,
-            SyntaxKind::LParen,
-            SyntaxKind::INTEGER,
-            SyntaxKind::NIL,
-            SyntaxKind::LBrack,
-            SyntaxKind::VAR1,
-            SyntaxKind::VAR2,
-            SyntaxKind::DECIMAL,
-            SyntaxKind::DOUBLE,
-            SyntaxKind::INTEGER_POSITIVE,
-            SyntaxKind::DECIMAL_POSITIVE,
-            SyntaxKind::DOUBLE_POSITIVE,
-            SyntaxKind::INTEGER_NEGATIVE,
-            SyntaxKind::DECIMAL_NEGATIVE,
-            SyntaxKind::DOUBLE_NEGATIVE,
-            SyntaxKind::True,
-            SyntaxKind::False,
-            SyntaxKind::STRING_LITERAL1,
-            SyntaxKind::STRING_LITERAL2,
-            SyntaxKind::STRING_LITERAL_LONG1,
-            SyntaxKind::STRING_LITERAL_LONG2,
-            SyntaxKind::PNAME_LN,
-            SyntaxKind::BLANK_NODE_LABEL,
-            SyntaxKind::ANON,
-        ]) {
+        if p.at_any(FIRST_TriplesTemplate) {
             parse_TriplesTemplate(p);
         }
     }
     p.close(marker, SyntaxKind::Quads);
 }
+const FIRST_QuadsNotTriples_0: TokenSet = TokenSet::new(&[
+    SyntaxKind::IRIREF,
+    SyntaxKind::PNAME_NS,
+    SyntaxKind::LParen,
+    SyntaxKind::INTEGER,
+    SyntaxKind::NIL,
+    SyntaxKind::LBrack,
+    SyntaxKind::VAR1,
+    SyntaxKind::VAR2,
+    SyntaxKind::DECIMAL,
+    SyntaxKind::DOUBLE,
+    SyntaxKind::INTEGER_POSITIVE,
+    SyntaxKind::DECIMAL_POSITIVE,
+    SyntaxKind::DOUBLE_POSITIVE,
+    SyntaxKind::INTEGER_NEGATIVE,
+    SyntaxKind::DECIMAL_NEGATIVE,
+    SyntaxKind::DOUBLE_NEGATIVE,
+    SyntaxKind::True,
+    SyntaxKind::False,
+    SyntaxKind::STRING_LITERAL1,
+    SyntaxKind::STRING_LITERAL2,
+    SyntaxKind::STRING_LITERAL_LONG1,
+    SyntaxKind::STRING_LITERAL_LONG2,
+    SyntaxKind::PNAME_LN,
+    SyntaxKind::BLANK_NODE_LABEL,
+    SyntaxKind::ANON,
+]);
+const RECOVERY_QuadsNotTriples: TokenSet = TokenSet::new(&[SyntaxKind::Dot]);
 /// [62] QuadsNotTriples -> 'GRAPH' VarOrIri '{' TriplesTemplate? '}'
 pub(super) fn parse_QuadsNotTriples(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_QuadsNotTriples);
     p.expect(SyntaxKind::GRAPH);
     parse_VarOrIri(p);
     p.expect(SyntaxKind::LCurly);
-    if p.at_any(&[
-        SyntaxKind::IRIREF,
-        SyntaxKind::PNAME_NS,
-        SyntaxKind::LParen,
-        SyntaxKind::INTEGER,
-        SyntaxKind::NIL,
-        SyntaxKind::LBrack,
-        SyntaxKind::VAR1,
-        SyntaxKind::VAR2,
-        SyntaxKind::DECIMAL,
-        SyntaxKind::DOUBLE,
-        SyntaxKind::INTEGER_POSITIVE,
-        SyntaxKind::DECIMAL_POSITIVE,
-        SyntaxKind::DOUBLE_POSITIVE,
-        SyntaxKind::INTEGER_NEGATIVE,
-        SyntaxKind::DECIMAL_NEGATIVE,
-        SyntaxKind::DOUBLE_NEGATIVE,
-        SyntaxKind::True,
-        SyntaxKind::False,
-        SyntaxKind::STRING_LITERAL1,
-        SyntaxKind::STRING_LITERAL2,
-        SyntaxKind::STRING_LITERAL_LONG1,
-        SyntaxKind::STRING_LITERAL_LONG2,
-        SyntaxKind::PNAME_LN,
-        SyntaxKind::BLANK_NODE_LABEL,
-        SyntaxKind::ANON,
-    ]) {
+    if p.at_any(FIRST_TriplesTemplate) {
         parse_TriplesTemplate(p);
     }
+    while p.recover_in_braces(FIRST_QuadsNotTriples_0) {
+        if p.at_any(FIRST_TriplesTemplate) {
+            parse_TriplesTemplate(p);
+        }
+    }
     p.expect(SyntaxKind::RCurly);
     p.close(marker, SyntaxKind::QuadsNotTriples);
 }
+const RECOVERY_TriplesSameSubject: TokenSet = TokenSet::new(&[SyntaxKind::Dot]);
 /// [63] TriplesSameSubject -> VarOrTerm PropertyListNotEmpty | TriplesNode PropertyList
 pub(super) fn parse_TriplesSameSubject(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_TriplesSameSubject);
     match p.nth(0) {
         SyntaxKind::IRIREF
         | SyntaxKind::PNAME_NS
@@ -2159,175 +2804,167 @@ pub(super) fn parse_TriplesSameSubject(p: &mut Parser) {
             parse_TriplesNode(p);
             parse_PropertyList(p);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::IRIREF,
+                SyntaxKind::PNAME_NS,
+                SyntaxKind::LParen,
+                SyntaxKind::INTEGER,
+                SyntaxKind::NIL,
+                SyntaxKind::LBrack,
+                SyntaxKind::VAR1,
+                SyntaxKind::VAR2,
+                SyntaxKind::DECIMAL,
+                SyntaxKind::DOUBLE,
+                SyntaxKind::INTEGER_POSITIVE,
+                SyntaxKind::DECIMAL_POSITIVE,
+                SyntaxKind::DOUBLE_POSITIVE,
+                SyntaxKind::INTEGER_NEGATIVE,
+                SyntaxKind::DECIMAL_NEGATIVE,
+                SyntaxKind::DOUBLE_NEGATIVE,
+                SyntaxKind::True,
+                SyntaxKind::False,
+                SyntaxKind::STRING_LITERAL1,
+                SyntaxKind::STRING_LITERAL2,
+                SyntaxKind::STRING_LITERAL_LONG1,
+                SyntaxKind::STRING_LITERAL_LONG2,
+                SyntaxKind::PNAME_LN,
+                SyntaxKind::BLANK_NODE_LABEL,
+                SyntaxKind::ANON,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::TriplesSameSubject);
 }
+const FIRST_GroupGraphPatternSub: TokenSet = TokenSet::new(&[
+    SyntaxKind::IRIREF,
+    SyntaxKind::PNAME_NS,
+    SyntaxKind::LParen,
+    SyntaxKind::LCurly,
+    SyntaxKind::INTEGER,
+    SyntaxKind::VALUES,
+    SyntaxKind::GRAPH,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::NIL,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::LBrack,
+    SyntaxKind::VAR1,
+    SyntaxKind::VAR2,
+    SyntaxKind::DECIMAL,
+    SyntaxKind::DOUBLE,
+    SyntaxKind::INTEGER_POSITIVE,
+    SyntaxKind::DECIMAL_POSITIVE,
+    SyntaxKind::DOUBLE_POSITIVE,
+    SyntaxKind::INTEGER_NEGATIVE,
+    SyntaxKind::DECIMAL_NEGATIVE,
+    SyntaxKind::DOUBLE_NEGATIVE,
+    SyntaxKind::True,
+    SyntaxKind::False,
+    SyntaxKind::STRING_LITERAL1,
+    SyntaxKind::STRING_LITERAL2,
+    SyntaxKind::STRING_LITERAL_LONG1,
+    SyntaxKind::STRING_LITERAL_LONG2,
+    SyntaxKind::PNAME_LN,
+    SyntaxKind::BLANK_NODE_LABEL,
+    SyntaxKind::ANON,
+]);
+const FIRST_GroupGraphPatternSub_0: TokenSet = TokenSet::new(&[SyntaxKind::Dot]);
+const FIRST_GroupGraphPatternSub_1: TokenSet = TokenSet::new(&[
+    SyntaxKind::LCurly,
+    SyntaxKind::VALUES,
+    SyntaxKind::GRAPH,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+]);
 /// [64] GroupGraphPatternSub -> TriplesBlock? (GraphPatternNotTriples '.'? TriplesBlock?)*
 pub(super) fn parse_GroupGraphPatternSub(p: &mut Parser) {
-    if !p.at_any(&[
-        SyntaxKind::IRIREF,
-        SyntaxKind::PNAME_NS,
-        SyntaxKind::LParen,
-        SyntaxKind::LCurly,
-        SyntaxKind::INTEGER,
-        SyntaxKind::VALUES,
-        SyntaxKind::GRAPH,
-        SyntaxKind::OPTIONAL,
-        SyntaxKind::SERVICE,
-        SyntaxKind::BIND,
-        SyntaxKind::NIL,
-        SyntaxKind::MINUS,
-        SyntaxKind::FILTER,
-        SyntaxKind::LBrack,
-        SyntaxKind::VAR1,
-        SyntaxKind::VAR2,
-        SyntaxKind::DECIMAL,
-        SyntaxKind::DOUBLE,
-        SyntaxKind::INTEGER_POSITIVE,
-        SyntaxKind::DECIMAL_POSITIVE,
-        SyntaxKind::DOUBLE_POSITIVE,
-        SyntaxKind::INTEGER_NEGATIVE,
-        SyntaxKind::DECIMAL_NEGATIVE,
-        SyntaxKind::DOUBLE_NEGATIVE,
-        SyntaxKind::True,
-        SyntaxKind::False,
-        SyntaxKind::STRING_LITERAL1,
-        SyntaxKind::STRING_LITERAL2,
-        SyntaxKind::STRING_LITERAL_LONG1,
-        SyntaxKind::STRING_LITERAL_LONG2,
-        SyntaxKind::PNAME_LN,
-        SyntaxKind::BLANK_NODE_LABEL,
-        SyntaxKind::ANON,
-    ]) {
+    if !p.at_any(FIRST_GroupGraphPatternSub) {
         return;
     }
     let marker = p.open();
-    if p.at_any(&[
-        SyntaxKind::IRIREF,
-        SyntaxKind::PNAME_NS,
-        SyntaxKind::LParen,
-        SyntaxKind::INTEGER,
-        SyntaxKind::NIL,
-        SyntaxKind::LBrack,
-        SyntaxKind::VAR1,
-        SyntaxKind::VAR2,
-        SyntaxKind::DECIMAL,
-        SyntaxKind::DOUBLE,
-        SyntaxKind::INTEGER_POSITIVE,
-        SyntaxKind::DECIMAL_POSITIVE,
-        SyntaxKind::DOUBLE_POSITIVE,
-        SyntaxKind::INTEGER_NEGATIVE,
-        SyntaxKind::DECIMAL_NEGATIVE,
-        SyntaxKind::DOUBLE_NEGATIVE,
-        SyntaxKind::True,
-        SyntaxKind::False,
-        SyntaxKind::STRING_LITERAL1,
-        SyntaxKind::STRING_LITERAL2,
-        SyntaxKind::STRING_LITERAL_LONG1,
-        SyntaxKind::STRING_LITERAL_LONG2,
-        SyntaxKind::PNAME_LN,
-        SyntaxKind::BLANK_NODE_LABEL,
-        SyntaxKind::ANON,
-    ]) {
+    if p.at_any(FIRST_TriplesBlock) {
         parse_TriplesBlock(p);
     }
-    while [
-        SyntaxKind::LCurly,
-        SyntaxKind::VALUES,
-        SyntaxKind::GRAPH,
-        SyntaxKind::OPTIONAL,
-        SyntaxKind::SERVICE,
-        SyntaxKind::BIND,
-        SyntaxKind::MINUS,
-        SyntaxKind::FILTER,
-    ]
-    .contains(&p.nth(0))
-    {
+    while p.at_any(FIRST_GroupGraphPatternSub_1) {
         parse_GraphPatternNotTriples(p);
-        if p.at_any(&[SyntaxKind::Dot]) {
+        if p.at_any(FIRST_GroupGraphPatternSub_0) {
             p.expect(SyntaxKind::Dot);
         }
-        if p.at_any(&[
-            SyntaxKind::IRIREF,
-            SyntaxKind::PNAME_NS,
-            SyntaxKind::LParen,
-            SyntaxKind::INTEGER,
-            SyntaxKind::NIL,
-            SyntaxKind::LBrack,
-            SyntaxKind::VAR1,
-            SyntaxKind::VAR2,
-            SyntaxKind::DECIMAL,
-            SyntaxKind::DOUBLE,
-            SyntaxKind::INTEGER_POSITIVE,
-            SyntaxKind::DECIMAL_POSITIVE,
-            SyntaxKind::DOUBLE_POSITIVE,
-            SyntaxKind::INTEGER_NEGATIVE,
-            SyntaxKind::DECIMAL_NEGATIVE,
-            SyntaxKind::DOUBLE_NEGATIVE,
-            SyntaxKind::True,
-            SyntaxKind::False,
-            SyntaxKind::STRING_LITERAL1,
-            SyntaxKind::STRING_LITERAL2,
-            SyntaxKind::STRING_LITERAL_LONG1,
-            SyntaxKind::STRING_LITERAL_LONG2,
-            SyntaxKind::PNAME_LN,
-            SyntaxKind::BLANK_NODE_LABEL,
-            SyntaxKind::ANON,
-        ]) {
+        if p.at_any(FIRST_TriplesBlock) {
             parse_TriplesBlock(p);
         }
     }
     p.close(marker, SyntaxKind::GroupGraphPatternSub);
 }
+const FIRST_TriplesBlock: TokenSet = TokenSet::new(&[
+    SyntaxKind::IRIREF,
+    SyntaxKind::PNAME_NS,
+    SyntaxKind::LParen,
+    SyntaxKind::INTEGER,
+    SyntaxKind::NIL,
+    SyntaxKind::LBrack,
+    SyntaxKind::VAR1,
+    SyntaxKind::VAR2,
+    SyntaxKind::DECIMAL,
+    SyntaxKind::DOUBLE,
+    SyntaxKind::INTEGER_POSITIVE,
+    SyntaxKind::DECIMAL_POSITIVE,
+    SyntaxKind::DOUBLE_POSITIVE,
+    SyntaxKind::INTEGER_NEGATIVE,
+    SyntaxKind::DECIMAL_NEGATIVE,
+    SyntaxKind::DOUBLE_NEGATIVE,
+    SyntaxKind::True,
+    SyntaxKind::False,
+    SyntaxKind::STRING_LITERAL1,
+    SyntaxKind::STRING_LITERAL2,
+    SyntaxKind::STRING_LITERAL_LONG1,
+    SyntaxKind::STRING_LITERAL_LONG2,
+    SyntaxKind::PNAME_LN,
+    SyntaxKind::BLANK_NODE_LABEL,
+    SyntaxKind::ANON,
+]);
+const FIRST_TriplesBlock_0: TokenSet = TokenSet::new(&[SyntaxKind::Dot]);
+const RECOVERY_TriplesBlock: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+]);
 /// [65] TriplesBlock -> TriplesSameSubjectPath ('.' TriplesBlock?)?
 pub(super) fn parse_TriplesBlock(p: &mut Parser) {
-    let marker = p.open();
-    parse_TriplesSameSubjectPath(p);
-    if p.at_any(&[SyntaxKind::Dot]) {
-        p.expect(SyntaxKind::Dot);
-        if p.at_any(&[
-            SyntaxKind::IRIREF,
-            SyntaxKind::PNAME_NS,
-            SyntaxKind::LParen,
-            SyntaxKind::INTEGER,
-            SyntaxKind::NIL,
-            SyntaxKind::LBrack,
-            SyntaxKind::VAR1,
-            SyntaxKind::VAR2,
-            SyntaxKind::DECIMAL,
-            SyntaxKind::DOUBLE,
-            SyntaxKind::INTEGER_POSITIVE,
-            SyntaxKind::DECIMAL_POSITIVE,
-            SyntaxKind::DOUBLE_POSITIVE,
-            SyntaxKind::INTEGER_NEGATIVE,
-            SyntaxKind::DECIMAL_NEGATIVE,
-            SyntaxKind::DOUBLE_NEGATIVE,
-            SyntaxKind::True,
-            SyntaxKind::False,
-            SyntaxKind::STRING_LITERAL1,
-            SyntaxKind::STRING_LITERAL2,
-            SyntaxKind::STRING_LITERAL_LONG1,
-            SyntaxKind::STRING_LITERAL_LONG2,
-            SyntaxKind::PNAME_LN,
-            SyntaxKind::BLANK_NODE_LABEL,
-            SyntaxKind::ANON,
-        ]) {
-            parse_TriplesBlock(p);
+    let marker = p.open_rule(RECOVERY_TriplesBlock);
+    loop {
+        parse_TriplesSameSubjectPath(p);
+        if p.at_any(FIRST_TriplesBlock_0) {
+            p.expect(SyntaxKind::Dot);
+            if p.at_any(FIRST_TriplesBlock) {
+                continue;
+            }
         }
+        break;
     }
     p.close(marker, SyntaxKind::TriplesBlock);
 }
+const RECOVERY_GraphPatternNotTriples: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+]);
 /// [66] GraphPatternNotTriples -> GroupOrUnionGraphPattern | OptionalGraphPattern | MinusGraphPattern | GraphGraphPattern | ServiceGraphPattern | Filter | Bind | InlineData
 pub(super) fn parse_GraphPatternNotTriples(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_GraphPatternNotTriples);
     match p.nth(0) {
         SyntaxKind::LCurly => {
             parse_GroupOrUnionGraphPattern(p);
@@ -2353,20 +2990,33 @@ pub(super) fn parse_GraphPatternNotTriples(p: &mut Parser) {
         SyntaxKind::VALUES => {
             parse_InlineData(p);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::LCurly,
+                SyntaxKind::VALUES,
+                SyntaxKind::GRAPH,
+                SyntaxKind::OPTIONAL,
+                SyntaxKind::SERVICE,
+                SyntaxKind::BIND,
+                SyntaxKind::MINUS,
+                SyntaxKind::FILTER,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::GraphPatternNotTriples);
 }
+const RECOVERY_TriplesSameSubjectPath: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+]);
 /// [67] TriplesSameSubjectPath -> VarOrTerm PropertyListPathNotEmpty | TriplesNodePath PropertyListPath
 pub(super) fn parse_TriplesSameSubjectPath(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_TriplesSameSubjectPath);
     match p.nth(0) {
         SyntaxKind::IRIREF
         | SyntaxKind::PNAME_NS
@@ -2398,70 +3048,156 @@ pub(super) fn parse_TriplesSameSubjectPath(p: &mut Parser) {
             parse_TriplesNodePath(p);
             parse_PropertyListPath(p);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::IRIREF,
+                SyntaxKind::PNAME_NS,
+                SyntaxKind::LParen,
+                SyntaxKind::INTEGER,
+                SyntaxKind::NIL,
+                SyntaxKind::LBrack,
+                SyntaxKind::VAR1,
+                SyntaxKind::VAR2,
+                SyntaxKind::DECIMAL,
+                SyntaxKind::DOUBLE,
+                SyntaxKind::INTEGER_POSITIVE,
+                SyntaxKind::DECIMAL_POSITIVE,
+                SyntaxKind::DOUBLE_POSITIVE,
+                SyntaxKind::INTEGER_NEGATIVE,
+                SyntaxKind::DECIMAL_NEGATIVE,
+                SyntaxKind::DOUBLE_NEGATIVE,
+                SyntaxKind::True,
+                SyntaxKind::False,
+                SyntaxKind::STRING_LITERAL1,
+                SyntaxKind::STRING_LITERAL2,
+                SyntaxKind::STRING_LITERAL_LONG1,
+                SyntaxKind::STRING_LITERAL_LONG2,
+                SyntaxKind::PNAME_LN,
+                SyntaxKind::BLANK_NODE_LABEL,
+                SyntaxKind::ANON,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::TriplesSameSubjectPath);
 }
+const FIRST_GroupOrUnionGraphPattern_0: TokenSet = TokenSet::new(&[SyntaxKind::UNION]);
+const RECOVERY_GroupOrUnionGraphPattern: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+]);
 /// [68] GroupOrUnionGraphPattern -> GroupGraphPattern ('UNION' GroupGraphPattern)*
 pub(super) fn parse_GroupOrUnionGraphPattern(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_GroupOrUnionGraphPattern);
     parse_GroupGraphPattern(p);
-    while [SyntaxKind::UNION].contains(&p.nth(0)) {
+    while p.at_any(FIRST_GroupOrUnionGraphPattern_0) {
         p.expect(SyntaxKind::UNION);
         parse_GroupGraphPattern(p);
     }
     p.close(marker, SyntaxKind::GroupOrUnionGraphPattern);
 }
+const RECOVERY_OptionalGraphPattern: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+]);
 /// [69] OptionalGraphPattern -> 'OPTIONAL' GroupGraphPattern
 pub(super) fn parse_OptionalGraphPattern(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_OptionalGraphPattern);
     p.expect(SyntaxKind::OPTIONAL);
     parse_GroupGraphPattern(p);
     p.close(marker, SyntaxKind::OptionalGraphPattern);
 }
+const RECOVERY_MinusGraphPattern: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+]);
 /// [70] MinusGraphPattern -> 'MINUS' GroupGraphPattern
 pub(super) fn parse_MinusGraphPattern(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_MinusGraphPattern);
     p.expect(SyntaxKind::MINUS);
     parse_GroupGraphPattern(p);
     p.close(marker, SyntaxKind::MinusGraphPattern);
 }
+const RECOVERY_GraphGraphPattern: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+]);
 /// [71] GraphGraphPattern -> 'GRAPH' VarOrIri GroupGraphPattern
 pub(super) fn parse_GraphGraphPattern(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_GraphGraphPattern);
     p.expect(SyntaxKind::GRAPH);
     parse_VarOrIri(p);
     parse_GroupGraphPattern(p);
     p.close(marker, SyntaxKind::GraphGraphPattern);
 }
+const FIRST_ServiceGraphPattern_0: TokenSet = TokenSet::new(&[SyntaxKind::SILENT]);
+const RECOVERY_ServiceGraphPattern: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+]);
 /// [72] ServiceGraphPattern -> 'SERVICE' 'SILENT'? VarOrIri GroupGraphPattern
 pub(super) fn parse_ServiceGraphPattern(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_ServiceGraphPattern);
     p.expect(SyntaxKind::SERVICE);
-    if p.at_any(&[SyntaxKind::SILENT]) {
+    if p.at_any(FIRST_ServiceGraphPattern_0) {
         p.expect(SyntaxKind::SILENT);
     }
     parse_VarOrIri(p);
     parse_GroupGraphPattern(p);
     p.close(marker, SyntaxKind::ServiceGraphPattern);
 }
+const RECOVERY_Filter: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+]);
 /// [73] Filter -> 'FILTER' Constraint
 pub(super) fn parse_Filter(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_Filter);
     p.expect(SyntaxKind::FILTER);
     parse_Constraint(p);
     p.close(marker, SyntaxKind::Filter);
 }
+const RECOVERY_Bind: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+]);
 /// [74] Bind -> 'BIND' '(' Expression 'AS' Var ')'
 pub(super) fn parse_Bind(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_Bind);
     p.expect(SyntaxKind::BIND);
     p.expect(SyntaxKind::LParen);
     parse_Expression(p);
@@ -2470,97 +3206,102 @@ pub(super) fn parse_Bind(p: &mut Parser) {
     p.expect(SyntaxKind::RParen);
     p.close(marker, SyntaxKind::Bind);
 }
+const RECOVERY_InlineData: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+]);
 /// [75] InlineData -> 'VALUES' DataBlock
 pub(super) fn parse_InlineData(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_InlineData);
     p.expect(SyntaxKind::VALUES);
     parse_DataBlock(p);
     p.close(marker, SyntaxKind::InlineData);
 }
+const FIRST_InlineDataOneVar_0: TokenSet = TokenSet::new(&[
+    SyntaxKind::IRIREF,
+    SyntaxKind::PNAME_NS,
+    SyntaxKind::INTEGER,
+    SyntaxKind::UNDEF,
+    SyntaxKind::DECIMAL,
+    SyntaxKind::DOUBLE,
+    SyntaxKind::INTEGER_POSITIVE,
+    SyntaxKind::DECIMAL_POSITIVE,
+    SyntaxKind::DOUBLE_POSITIVE,
+    SyntaxKind::INTEGER_NEGATIVE,
+    SyntaxKind::DECIMAL_NEGATIVE,
+    SyntaxKind::DOUBLE_NEGATIVE,
+    SyntaxKind::True,
+    SyntaxKind::False,
+    SyntaxKind::STRING_LITERAL1,
+    SyntaxKind::STRING_LITERAL2,
+    SyntaxKind::STRING_LITERAL_LONG1,
+    SyntaxKind::STRING_LITERAL_LONG2,
+    SyntaxKind::PNAME_LN,
+]);
+const RECOVERY_InlineDataOneVar: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+]);
 /// [76] InlineDataOneVar -> Var '{' DataBlockValue* '}'
 pub(super) fn parse_InlineDataOneVar(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_InlineDataOneVar);
     parse_Var(p);
     p.expect(SyntaxKind::LCurly);
-    while [
-        SyntaxKind::IRIREF,
-        SyntaxKind::PNAME_NS,
-        SyntaxKind::INTEGER,
-        SyntaxKind::UNDEF,
-        SyntaxKind::DECIMAL,
-        SyntaxKind::DOUBLE,
-        SyntaxKind::INTEGER_POSITIVE,
-        SyntaxKind::DECIMAL_POSITIVE,
-        SyntaxKind::DOUBLE_POSITIVE,
-        SyntaxKind::INTEGER_NEGATIVE,
-        SyntaxKind::DECIMAL_NEGATIVE,
-        SyntaxKind::DOUBLE_NEGATIVE,
-        SyntaxKind::True,
-        SyntaxKind::False,
-        SyntaxKind::STRING_LITERAL1,
-        SyntaxKind::STRING_LITERAL2,
-        SyntaxKind::STRING_LITERAL_LONG1,
-        SyntaxKind::STRING_LITERAL_LONG2,
-        SyntaxKind::PNAME_LN,
-    ]
-    .contains(&p.nth(0))
-    {
+    while p.at_any(FIRST_DataBlockValue) {
         parse_DataBlockValue(p);
     }
+    while p.recover_in_braces(FIRST_InlineDataOneVar_0) {
+        while p.at_any(FIRST_DataBlockValue) {
+            parse_DataBlockValue(p);
+        }
+    }
     p.expect(SyntaxKind::RCurly);
     p.close(marker, SyntaxKind::InlineDataOneVar);
 }
+const FIRST_InlineDataFull: TokenSet = TokenSet::new(&[SyntaxKind::LParen, SyntaxKind::NIL]);
+const RECOVERY_InlineDataFull: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+]);
 /// [77] InlineDataFull -> ('NIL' | '(' Var* ')') '{' ('(' DataBlockValue* ')' | 'NIL')* '}'
 pub(super) fn parse_InlineDataFull(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_InlineDataFull);
     match p.nth(0) {
         SyntaxKind::NIL => {
             p.expect(SyntaxKind::NIL);
         }
         SyntaxKind::LParen => {
             p.expect(SyntaxKind::LParen);
-            while [SyntaxKind::VAR1, SyntaxKind::VAR2].contains(&p.nth(0)) {
+            while p.at_any(FIRST_Var) {
                 parse_Var(p);
             }
             p.expect(SyntaxKind::RParen);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[SyntaxKind::LParen, SyntaxKind::NIL]);
         }
     };
     p.expect(SyntaxKind::LCurly);
-    while [SyntaxKind::LParen, SyntaxKind::NIL].contains(&p.nth(0)) {
+    while p.at_any(FIRST_InlineDataFull) {
         match p.nth(0) {
             SyntaxKind::LParen => {
                 p.expect(SyntaxKind::LParen);
-                while [
-                    SyntaxKind::IRIREF,
-                    SyntaxKind::PNAME_NS,
-                    SyntaxKind::INTEGER,
-                    SyntaxKind::UNDEF,
-                    SyntaxKind::DECIMAL,
-                    SyntaxKind::DOUBLE,
-                    SyntaxKind::INTEGER_POSITIVE,
-                    SyntaxKind::DECIMAL_POSITIVE,
-                    SyntaxKind::DOUBLE_POSITIVE,
-                    SyntaxKind::INTEGER_NEGATIVE,
-                    SyntaxKind::DECIMAL_NEGATIVE,
-                    SyntaxKind::DOUBLE_NEGATIVE,
-                    SyntaxKind::True,
-                    SyntaxKind::False,
-                    SyntaxKind::STRING_LITERAL1,
-                    SyntaxKind::STRING_LITERAL2,
-                    SyntaxKind::STRING_LITERAL_LONG1,
-                    SyntaxKind::STRING_LITERAL_LONG2,
-                    SyntaxKind::PNAME_LN,
-                ]
-                .contains(&p.nth(0))
-                {
+                while p.at_any(FIRST_DataBlockValue) {
                     parse_DataBlockValue(p);
                 }
                 p.expect(SyntaxKind::RParen);
@@ -2568,19 +3309,54 @@ pub(super) fn parse_InlineDataFull(p: &mut Parser) {
             SyntaxKind::NIL => {
                 p.expect(SyntaxKind::NIL);
             }
-            SyntaxKind::Eof => {
-                eprintln!("Unexpected Eof");
-                p.close(marker, SyntaxKind::Error);
-                return;
-            }
             _ => {
-                p.advance_with_error("Expected ....");
+                p.err_recover(&[SyntaxKind::LParen, SyntaxKind::NIL]);
             }
         };
     }
+    while p.recover_in_braces(FIRST_InlineDataFull) {
+        while p.at_any(FIRST_InlineDataFull) {
+            match p.nth(0) {
+                SyntaxKind::LParen => {
+                    p.expect(SyntaxKind::LParen);
+                    while p.at_any(FIRST_DataBlockValue) {
+                        parse_DataBlockValue(p);
+                    }
+                    p.expect(SyntaxKind::RParen);
+                }
+                SyntaxKind::NIL => {
+                    p.expect(SyntaxKind::NIL);
+                }
+                _ => {
+                    p.err_recover(&[SyntaxKind::LParen, SyntaxKind::NIL]);
+                }
+            };
+        }
+    }
     p.expect(SyntaxKind::RCurly);
     p.close(marker, SyntaxKind::InlineDataFull);
 }
+const FIRST_DataBlockValue: TokenSet = TokenSet::new(&[
+    SyntaxKind::IRIREF,
+    SyntaxKind::PNAME_NS,
+    SyntaxKind::INTEGER,
+    SyntaxKind::UNDEF,
+    SyntaxKind::DECIMAL,
+    SyntaxKind::DOUBLE,
+    SyntaxKind::INTEGER_POSITIVE,
+    SyntaxKind::DECIMAL_POSITIVE,
+    SyntaxKind::DOUBLE_POSITIVE,
+    SyntaxKind::INTEGER_NEGATIVE,
+    SyntaxKind::DECIMAL_NEGATIVE,
+    SyntaxKind::DOUBLE_NEGATIVE,
+    SyntaxKind::True,
+    SyntaxKind::False,
+    SyntaxKind::STRING_LITERAL1,
+    SyntaxKind::STRING_LITERAL2,
+    SyntaxKind::STRING_LITERAL_LONG1,
+    SyntaxKind::STRING_LITERAL_LONG2,
+    SyntaxKind::PNAME_LN,
+]);
 /// [78] DataBlockValue -> iri | RDFLiteral | NumericLiteral | BooleanLiteral | 'UNDEF'
 pub(super) fn parse_DataBlockValue(p: &mut Parser) {
     let marker = p.open();
@@ -2611,22 +3387,50 @@ pub(super) fn parse_DataBlockValue(p: &mut Parser) {
         SyntaxKind::UNDEF => {
             p.expect(SyntaxKind::UNDEF);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::IRIREF,
+                SyntaxKind::PNAME_NS,
+                SyntaxKind::INTEGER,
+                SyntaxKind::UNDEF,
+                SyntaxKind::DECIMAL,
+                SyntaxKind::DOUBLE,
+                SyntaxKind::INTEGER_POSITIVE,
+                SyntaxKind::DECIMAL_POSITIVE,
+                SyntaxKind::DOUBLE_POSITIVE,
+                SyntaxKind::INTEGER_NEGATIVE,
+                SyntaxKind::DECIMAL_NEGATIVE,
+                SyntaxKind::DOUBLE_NEGATIVE,
+                SyntaxKind::True,
+                SyntaxKind::False,
+                SyntaxKind::STRING_LITERAL1,
+                SyntaxKind::STRING_LITERAL2,
+                SyntaxKind::STRING_LITERAL_LONG1,
+                SyntaxKind::STRING_LITERAL_LONG2,
+                SyntaxKind::PNAME_LN,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::DataBlockValue);
 }
+const FIRST_RDFLiteral_0: TokenSet =
+    TokenSet::new(&[SyntaxKind::LANGTAG, SyntaxKind::DoubleZirkumflex]);
+const RECOVERY_RDFLiteral: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [79] RDFLiteral -> String ('LANGTAG' | '^^' iri)?
 pub(super) fn parse_RDFLiteral(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_RDFLiteral);
     parse_String(p);
-    if p.at_any(&[SyntaxKind::LANGTAG, SyntaxKind::DoubleZirkumflex]) {
+    if p.at_any(FIRST_RDFLiteral_0) {
         match p.nth(0) {
             SyntaxKind::LANGTAG => {
                 p.expect(SyntaxKind::LANGTAG);
@@ -2635,21 +3439,27 @@ pub(super) fn parse_RDFLiteral(p: &mut Parser) {
                 p.expect(SyntaxKind::DoubleZirkumflex);
                 parse_iri(p);
             }
-            SyntaxKind::Eof => {
-                eprintln!("Unexpected Eof");
-                p.close(marker, SyntaxKind::Error);
-                return;
-            }
             _ => {
-                p.advance_with_error("Expected ....");
+                p.err_recover(&[SyntaxKind::LANGTAG, SyntaxKind::DoubleZirkumflex]);
             }
         };
     }
     p.close(marker, SyntaxKind::RDFLiteral);
 }
+const RECOVERY_NumericLiteral: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [80] NumericLiteral -> NumericLiteralUnsigned | NumericLiteralPositive | NumericLiteralNegative
 pub(super) fn parse_NumericLiteral(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_NumericLiteral);
     match p.nth(0) {
         SyntaxKind::INTEGER | SyntaxKind::DECIMAL | SyntaxKind::DOUBLE => {
             parse_NumericLiteralUnsigned(p);
@@ -2664,20 +3474,36 @@ pub(super) fn parse_NumericLiteral(p: &mut Parser) {
         | SyntaxKind::DOUBLE_NEGATIVE => {
             parse_NumericLiteralNegative(p);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::INTEGER,
+                SyntaxKind::DECIMAL,
+                SyntaxKind::DOUBLE,
+                SyntaxKind::INTEGER_POSITIVE,
+                SyntaxKind::DECIMAL_POSITIVE,
+                SyntaxKind::DOUBLE_POSITIVE,
+                SyntaxKind::INTEGER_NEGATIVE,
+                SyntaxKind::DECIMAL_NEGATIVE,
+                SyntaxKind::DOUBLE_NEGATIVE,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::NumericLiteral);
 }
+const RECOVERY_BooleanLiteral: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [81] BooleanLiteral -> 'true' | 'false'
 pub(super) fn parse_BooleanLiteral(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_BooleanLiteral);
     match p.nth(0) {
         SyntaxKind::True => {
             p.expect(SyntaxKind::True);
@@ -2685,50 +3511,74 @@ pub(super) fn parse_BooleanLiteral(p: &mut Parser) {
         SyntaxKind::False => {
             p.expect(SyntaxKind::False);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[SyntaxKind::True, SyntaxKind::False]);
         }
     };
     p.close(marker, SyntaxKind::BooleanLiteral);
 }
+const FIRST_ArgList: TokenSet = TokenSet::new(&[SyntaxKind::LParen, SyntaxKind::NIL]);
+const FIRST_ArgList_0: TokenSet = TokenSet::new(&[SyntaxKind::DISTINCT]);
+const FIRST_ArgList_1: TokenSet = TokenSet::new(&[SyntaxKind::Comma]);
+const RECOVERY_ArgList: TokenSet = TokenSet::new(&[
+    SyntaxKind::HAVING,
+    SyntaxKind::ORDER,
+    SyntaxKind::LIMIT,
+    SyntaxKind::OFFSET,
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [82] ArgList -> 'NIL' | '(' 'DISTINCT'? Expression (',' Expression)* ')'
 pub(super) fn parse_ArgList(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_ArgList);
     match p.nth(0) {
         SyntaxKind::NIL => {
             p.expect(SyntaxKind::NIL);
         }
         SyntaxKind::LParen => {
             p.expect(SyntaxKind::LParen);
-            if p.at_any(&[SyntaxKind::DISTINCT]) {
+            if p.at_any(FIRST_ArgList_0) {
                 p.expect(SyntaxKind::DISTINCT);
             }
             parse_Expression(p);
-            while [SyntaxKind::Colon].contains(&p.nth(0)) {
-                p.expect(SyntaxKind::Colon);
+            while p.at_any(FIRST_ArgList_1) {
+                p.expect(SyntaxKind::Comma);
                 parse_Expression(p);
             }
             p.expect(SyntaxKind::RParen);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[SyntaxKind::LParen, SyntaxKind::NIL]);
         }
     };
     p.close(marker, SyntaxKind::ArgList);
 }
+const FIRST_ExpressionList_0: TokenSet = TokenSet::new(&[SyntaxKind::Comma]);
+const RECOVERY_ExpressionList: TokenSet = TokenSet::new(&[
+    SyntaxKind::HAVING,
+    SyntaxKind::ORDER,
+    SyntaxKind::LIMIT,
+    SyntaxKind::OFFSET,
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [83] ExpressionList -> 'NIL' | '(' Expression (',' Expression)* ')'
 pub(super) fn parse_ExpressionList(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_ExpressionList);
     match p.nth(0) {
         SyntaxKind::NIL => {
             p.expect(SyntaxKind::NIL);
@@ -2736,64 +3586,75 @@ pub(super) fn parse_ExpressionList(p: &mut Parser) {
         SyntaxKind::LParen => {
             p.expect(SyntaxKind::LParen);
             parse_Expression(p);
-            while [SyntaxKind::Colon].contains(&p.nth(0)) {
-                p.expect(SyntaxKind::Colon);
+            while p.at_any(FIRST_ExpressionList_0) {
+                p.expect(SyntaxKind::Comma);
                 parse_Expression(p);
             }
             p.expect(SyntaxKind::RParen);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[SyntaxKind::LParen, SyntaxKind::NIL]);
         }
     };
     p.close(marker, SyntaxKind::ExpressionList);
 }
+const FIRST_ConstructTriples: TokenSet = TokenSet::new(&[
+    SyntaxKind::IRIREF,
+    SyntaxKind::PNAME_NS,
+    SyntaxKind::LParen,
+    SyntaxKind::INTEGER,
+    SyntaxKind::NIL,
+    SyntaxKind::LBrack,
+    SyntaxKind::VAR1,
+    SyntaxKind::VAR2,
+    SyntaxKind::DECIMAL,
+    SyntaxKind::DOUBLE,
+    SyntaxKind::INTEGER_POSITIVE,
+    SyntaxKind::DECIMAL_POSITIVE,
+    SyntaxKind::DOUBLE_POSITIVE,
+    SyntaxKind::INTEGER_NEGATIVE,
+    SyntaxKind::DECIMAL_NEGATIVE,
+    SyntaxKind::DOUBLE_NEGATIVE,
+    SyntaxKind::True,
+    SyntaxKind::False,
+    SyntaxKind::STRING_LITERAL1,
+    SyntaxKind::STRING_LITERAL2,
+    SyntaxKind::STRING_LITERAL_LONG1,
+    SyntaxKind::STRING_LITERAL_LONG2,
+    SyntaxKind::PNAME_LN,
+    SyntaxKind::BLANK_NODE_LABEL,
+    SyntaxKind::ANON,
+]);
+const FIRST_ConstructTriples_0: TokenSet = TokenSet::new(&[SyntaxKind::Dot]);
 /// [84] ConstructTriples -> TriplesSameSubject ('.' ConstructTriples?)?
 pub(super) fn parse_ConstructTriples(p: &mut Parser) {
     let marker = p.open();
-    parse_TriplesSameSubject(p);
-    if p.at_any(&[SyntaxKind::Dot]) {
-        p.expect(SyntaxKind::Dot);
-        if p.at_any(&[
-            SyntaxKind::IRIREF,
-            SyntaxKind::PNAME_NS,
-            SyntaxKind::LParen,
-            SyntaxKind::INTEGER,
-            SyntaxKind::NIL,
-            SyntaxKind::LBrack,
-            SyntaxKind::VAR1,
-            SyntaxKind::VAR2,
-            SyntaxKind::DECIMAL,
-            SyntaxKind::DOUBLE,
-            SyntaxKind::INTEGER_POSITIVE,
-            SyntaxKind::DECIMAL_POSITIVE,
-            SyntaxKind::DOUBLE_POSITIVE,
-            SyntaxKind::INTEGER_NEGATIVE,
-            SyntaxKind::DECIMAL_NEGATIVE,
-            SyntaxKind::DOUBLE_NEGATIVE,
-            SyntaxKind::True,
-            SyntaxKind::False,
-            SyntaxKind::STRING_LITERAL1,
-            SyntaxKind::STRING_LITERAL2,
-            SyntaxKind::STRING_LITERAL_LONG1,
-            SyntaxKind::STRING_LITERAL_LONG2,
-            SyntaxKind::PNAME_LN,
-            SyntaxKind::BLANK_NODE_LABEL,
-            SyntaxKind::ANON,
-        ]) {
-            parse_ConstructTriples(p);
+    loop {
+        parse_TriplesSameSubject(p);
+        if p.at_any(FIRST_ConstructTriples_0) {
+            p.expect(SyntaxKind::Dot);
+            if p.at_any(FIRST_ConstructTriples) {
+                continue;
+            }
         }
+        break;
     }
     p.close(marker, SyntaxKind::ConstructTriples);
 }
+const RECOVERY_VarOrTerm: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [85] VarOrTerm -> Var | GraphTerm
 pub(super) fn parse_VarOrTerm(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_VarOrTerm);
     match p.nth(0) {
         SyntaxKind::VAR1 | SyntaxKind::VAR2 => {
             parse_Var(p);
@@ -2821,41 +3682,74 @@ pub(super) fn parse_VarOrTerm(p: &mut Parser) {
         | SyntaxKind::ANON => {
             parse_GraphTerm(p);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::IRIREF,
+                SyntaxKind::PNAME_NS,
+                SyntaxKind::INTEGER,
+                SyntaxKind::NIL,
+                SyntaxKind::VAR1,
+                SyntaxKind::VAR2,
+                SyntaxKind::DECIMAL,
+                SyntaxKind::DOUBLE,
+                SyntaxKind::INTEGER_POSITIVE,
+                SyntaxKind::DECIMAL_POSITIVE,
+                SyntaxKind::DOUBLE_POSITIVE,
+                SyntaxKind::INTEGER_NEGATIVE,
+                SyntaxKind::DECIMAL_NEGATIVE,
+                SyntaxKind::DOUBLE_NEGATIVE,
+                SyntaxKind::True,
+                SyntaxKind::False,
+                SyntaxKind::STRING_LITERAL1,
+                SyntaxKind::STRING_LITERAL2,
+                SyntaxKind::STRING_LITERAL_LONG1,
+                SyntaxKind::STRING_LITERAL_LONG2,
+                SyntaxKind::PNAME_LN,
+                SyntaxKind::BLANK_NODE_LABEL,
+                SyntaxKind::ANON,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::VarOrTerm);
 }
+const FIRST_PropertyListNotEmpty: TokenSet = TokenSet::new(&[
+    SyntaxKind::IRIREF,
+    SyntaxKind::PNAME_NS,
+    SyntaxKind::a,
+    SyntaxKind::VAR1,
+    SyntaxKind::VAR2,
+    SyntaxKind::PNAME_LN,
+]);
+const FIRST_PropertyListNotEmpty_0: TokenSet = TokenSet::new(&[SyntaxKind::Semicolon]);
+const RECOVERY_PropertyListNotEmpty: TokenSet = TokenSet::new(&[SyntaxKind::Dot]);
 /// [86] PropertyListNotEmpty -> Verb ObjectList (';' (Verb ObjectList)?)*
 pub(super) fn parse_PropertyListNotEmpty(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_PropertyListNotEmpty);
     parse_Verb(p);
     parse_ObjectList(p);
-    while [SyntaxKind::Semicolon].contains(&p.nth(0)) {
+    while p.at_any(FIRST_PropertyListNotEmpty_0) {
         p.expect(SyntaxKind::Semicolon);
-        if p.at_any(&[
-            SyntaxKind::IRIREF,
-            SyntaxKind::PNAME_NS,
-            SyntaxKind::a,
-            SyntaxKind::VAR1,
-            SyntaxKind::VAR2,
-            SyntaxKind::PNAME_LN,
-        ]) {
+        if p.at_any(FIRST_PropertyListNotEmpty) {
             parse_Verb(p);
             parse_ObjectList(p);
         }
     }
     p.close(marker, SyntaxKind::PropertyListNotEmpty);
 }
+const RECOVERY_TriplesNode: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [87] TriplesNode -> Collection | BlankNodePropertyList
 pub(super) fn parse_TriplesNode(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_TriplesNode);
     match p.nth(0) {
         SyntaxKind::LParen => {
             parse_Collection(p);
@@ -2863,38 +3757,28 @@ pub(super) fn parse_TriplesNode(p: &mut Parser) {
         SyntaxKind::LBrack => {
             parse_BlankNodePropertyList(p);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[SyntaxKind::LParen, SyntaxKind::LBrack]);
         }
     };
     p.close(marker, SyntaxKind::TriplesNode);
 }
+const FIRST_PropertyList: TokenSet = TokenSet::new(&[
+    SyntaxKind::IRIREF,
+    SyntaxKind::PNAME_NS,
+    SyntaxKind::a,
+    SyntaxKind::VAR1,
+    SyntaxKind::VAR2,
+    SyntaxKind::PNAME_LN,
+]);
+const RECOVERY_PropertyList: TokenSet = TokenSet::new(&[SyntaxKind::Dot]);
 /// [88] PropertyList -> PropertyListNotEmpty?
 pub(super) fn parse_PropertyList(p: &mut Parser) {
-    if !p.at_any(&[
-        SyntaxKind::IRIREF,
-        SyntaxKind::PNAME_NS,
-        SyntaxKind::a,
-        SyntaxKind::VAR1,
-        SyntaxKind::VAR2,
-        SyntaxKind::PNAME_LN,
-    ]) {
+    if !p.at_any(FIRST_PropertyList) {
         return;
     }
-    let marker = p.open();
-    if p.at_any(&[
-        SyntaxKind::IRIREF,
-        SyntaxKind::PNAME_NS,
-        SyntaxKind::a,
-        SyntaxKind::VAR1,
-        SyntaxKind::VAR2,
-        SyntaxKind::PNAME_LN,
-    ]) {
+    let marker = p.open_rule(RECOVERY_PropertyList);
+    if p.at_any(FIRST_PropertyListNotEmpty) {
         parse_PropertyListNotEmpty(p);
     }
     p.close(marker, SyntaxKind::PropertyList);
@@ -2913,36 +3797,98 @@ pub(super) fn parse_Verb(p: &mut Parser) {
         SyntaxKind::a => {
             p.expect(SyntaxKind::a);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::IRIREF,
+                SyntaxKind::PNAME_NS,
+                SyntaxKind::a,
+                SyntaxKind::VAR1,
+                SyntaxKind::VAR2,
+                SyntaxKind::PNAME_LN,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::Verb);
 }
+const FIRST_ObjectList_0: TokenSet = TokenSet::new(&[SyntaxKind::Comma]);
+const RECOVERY_ObjectList: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+]);
 /// [90] ObjectList -> Object (',' Object)*
 pub(super) fn parse_ObjectList(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_ObjectList);
     parse_Object(p);
-    while [SyntaxKind::Colon].contains(&p.nth(0)) {
-        p.expect(SyntaxKind::Colon);
+    while p.at_any(FIRST_ObjectList_0) {
+        p.expect(SyntaxKind::Comma);
         parse_Object(p);
     }
     p.close(marker, SyntaxKind::ObjectList);
 }
+const RECOVERY_Object: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [91] Object -> GraphNode
 pub(super) fn parse_Object(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_Object);
     parse_GraphNode(p);
     p.close(marker, SyntaxKind::Object);
 }
+const FIRST_GraphNode: TokenSet = TokenSet::new(&[
+    SyntaxKind::IRIREF,
+    SyntaxKind::PNAME_NS,
+    SyntaxKind::LParen,
+    SyntaxKind::INTEGER,
+    SyntaxKind::NIL,
+    SyntaxKind::LBrack,
+    SyntaxKind::VAR1,
+    SyntaxKind::VAR2,
+    SyntaxKind::DECIMAL,
+    SyntaxKind::DOUBLE,
+    SyntaxKind::INTEGER_POSITIVE,
+    SyntaxKind::DECIMAL_POSITIVE,
+    SyntaxKind::DOUBLE_POSITIVE,
+    SyntaxKind::INTEGER_NEGATIVE,
+    SyntaxKind::DECIMAL_NEGATIVE,
+    SyntaxKind::DOUBLE_NEGATIVE,
+    SyntaxKind::True,
+    SyntaxKind::False,
+    SyntaxKind::STRING_LITERAL1,
+    SyntaxKind::STRING_LITERAL2,
+    SyntaxKind::STRING_LITERAL_LONG1,
+    SyntaxKind::STRING_LITERAL_LONG2,
+    SyntaxKind::PNAME_LN,
+    SyntaxKind::BLANK_NODE_LABEL,
+    SyntaxKind::ANON,
+]);
+const RECOVERY_GraphNode: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [92] GraphNode -> VarOrTerm | TriplesNode
 pub(super) fn parse_GraphNode(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_GraphNode);
     match p.nth(0) {
         SyntaxKind::IRIREF
         | SyntaxKind::PNAME_NS
@@ -2972,20 +3918,62 @@ pub(super) fn parse_GraphNode(p: &mut Parser) {
         SyntaxKind::LParen | SyntaxKind::LBrack => {
             parse_TriplesNode(p);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::IRIREF,
+                SyntaxKind::PNAME_NS,
+                SyntaxKind::LParen,
+                SyntaxKind::INTEGER,
+                SyntaxKind::NIL,
+                SyntaxKind::LBrack,
+                SyntaxKind::VAR1,
+                SyntaxKind::VAR2,
+                SyntaxKind::DECIMAL,
+                SyntaxKind::DOUBLE,
+                SyntaxKind::INTEGER_POSITIVE,
+                SyntaxKind::DECIMAL_POSITIVE,
+                SyntaxKind::DOUBLE_POSITIVE,
+                SyntaxKind::INTEGER_NEGATIVE,
+                SyntaxKind::DECIMAL_NEGATIVE,
+                SyntaxKind::DOUBLE_NEGATIVE,
+                SyntaxKind::True,
+                SyntaxKind::False,
+                SyntaxKind::STRING_LITERAL1,
+                SyntaxKind::STRING_LITERAL2,
+                SyntaxKind::STRING_LITERAL_LONG1,
+                SyntaxKind::STRING_LITERAL_LONG2,
+                SyntaxKind::PNAME_LN,
+                SyntaxKind::BLANK_NODE_LABEL,
+                SyntaxKind::ANON,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::GraphNode);
 }
+const FIRST_PropertyListPathNotEmpty: TokenSet = TokenSet::new(&[
+    SyntaxKind::IRIREF,
+    SyntaxKind::PNAME_NS,
+    SyntaxKind::LParen,
+    SyntaxKind::a,
+    SyntaxKind::Zirkumflex,
+    SyntaxKind::ExclamationMark,
+    SyntaxKind::VAR1,
+    SyntaxKind::VAR2,
+    SyntaxKind::PNAME_LN,
+]);
+const FIRST_PropertyListPathNotEmpty_0: TokenSet = TokenSet::new(&[SyntaxKind::Semicolon]);
+const RECOVERY_PropertyListPathNotEmpty: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+]);
 /// [93] PropertyListPathNotEmpty -> (VerbPath | VerbSimple) ObjectListPath (';' ((VerbPath | VerbSimple) ObjectList)?)*
 pub(super) fn parse_PropertyListPathNotEmpty(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_PropertyListPathNotEmpty);
     match p.nth(0) {
         SyntaxKind::IRIREF
         | SyntaxKind::PNAME_NS
@@ -2999,29 +3987,24 @@ pub(super) fn parse_PropertyListPathNotEmpty(p: &mut Parser) {
         SyntaxKind::VAR1 | SyntaxKind::VAR2 => {
             parse_VerbSimple(p);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::IRIREF,
+                SyntaxKind::PNAME_NS,
+                SyntaxKind::LParen,
+                SyntaxKind::a,
+                SyntaxKind::Zirkumflex,
+                SyntaxKind::ExclamationMark,
+                SyntaxKind::VAR1,
+                SyntaxKind::VAR2,
+                SyntaxKind::PNAME_LN,
+            ]);
         }
     };
     parse_ObjectListPath(p);
-    while [SyntaxKind::Semicolon].contains(&p.nth(0)) {
+    while p.at_any(FIRST_PropertyListPathNotEmpty_0) {
         p.expect(SyntaxKind::Semicolon);
-        if p.at_any(&[
-            SyntaxKind::IRIREF,
-            SyntaxKind::PNAME_NS,
-            SyntaxKind::LParen,
-            SyntaxKind::a,
-            SyntaxKind::Zirkumflex,
-            SyntaxKind::ExclamationMark,
-            SyntaxKind::VAR1,
-            SyntaxKind::VAR2,
-            SyntaxKind::PNAME_LN,
-        ]) {
+        if p.at_any(FIRST_PropertyListPathNotEmpty) {
             match p.nth(0) {
                 SyntaxKind::IRIREF
                 | SyntaxKind::PNAME_NS
@@ -3035,13 +4018,18 @@ pub(super) fn parse_PropertyListPathNotEmpty(p: &mut Parser) {
                 SyntaxKind::VAR1 | SyntaxKind::VAR2 => {
                     parse_VerbSimple(p);
                 }
-                SyntaxKind::Eof => {
-                    eprintln!("Unexpected Eof");
-                    p.close(marker, SyntaxKind::Error);
-                    return;
-                }
                 _ => {
-                    p.advance_with_error("Expected ....");
+                    p.err_recover(&[
+                        SyntaxKind::IRIREF,
+                        SyntaxKind::PNAME_NS,
+                        SyntaxKind::LParen,
+                        SyntaxKind::a,
+                        SyntaxKind::Zirkumflex,
+                        SyntaxKind::ExclamationMark,
+                        SyntaxKind::VAR1,
+                        SyntaxKind::VAR2,
+                        SyntaxKind::PNAME_LN,
+                    ]);
                 }
             };
             parse_ObjectList(p);
@@ -3049,9 +4037,20 @@ pub(super) fn parse_PropertyListPathNotEmpty(p: &mut Parser) {
     }
     p.close(marker, SyntaxKind::PropertyListPathNotEmpty);
 }
+const RECOVERY_TriplesNodePath: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [94] TriplesNodePath -> CollectionPath | BlankNodePropertyListPath
 pub(super) fn parse_TriplesNodePath(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_TriplesNodePath);
     match p.nth(0) {
         SyntaxKind::LParen => {
             parse_CollectionPath(p);
@@ -3059,44 +4058,39 @@ pub(super) fn parse_TriplesNodePath(p: &mut Parser) {
         SyntaxKind::LBrack => {
             parse_BlankNodePropertyListPath(p);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[SyntaxKind::LParen, SyntaxKind::LBrack]);
         }
     };
     p.close(marker, SyntaxKind::TriplesNodePath);
 }
+const FIRST_PropertyListPath: TokenSet = TokenSet::new(&[
+    SyntaxKind::IRIREF,
+    SyntaxKind::PNAME_NS,
+    SyntaxKind::LParen,
+    SyntaxKind::a,
+    SyntaxKind::Zirkumflex,
+    SyntaxKind::ExclamationMark,
+    SyntaxKind::VAR1,
+    SyntaxKind::VAR2,
+    SyntaxKind::PNAME_LN,
+]);
+const RECOVERY_PropertyListPath: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+]);
 /// [95] PropertyListPath -> PropertyListPathNotEmpty?
 pub(super) fn parse_PropertyListPath(p: &mut Parser) {
-    if !p.at_any(&[
-        SyntaxKind::IRIREF,
-        SyntaxKind::PNAME_NS,
-        SyntaxKind::LParen,
-        SyntaxKind::a,
-        SyntaxKind::Zirkumflex,
-        SyntaxKind::ExclamationMark,
-        SyntaxKind::VAR1,
-        SyntaxKind::VAR2,
-        SyntaxKind::PNAME_LN,
-    ]) {
+    if !p.at_any(FIRST_PropertyListPath) {
         return;
     }
-    let marker = p.open();
-    if p.at_any(&[
-        SyntaxKind::IRIREF,
-        SyntaxKind::PNAME_NS,
-        SyntaxKind::LParen,
-        SyntaxKind::a,
-        SyntaxKind::Zirkumflex,
-        SyntaxKind::ExclamationMark,
-        SyntaxKind::VAR1,
-        SyntaxKind::VAR2,
-        SyntaxKind::PNAME_LN,
-    ]) {
+    let marker = p.open_rule(RECOVERY_PropertyListPath);
+    if p.at_any(FIRST_PropertyListPathNotEmpty) {
         parse_PropertyListPathNotEmpty(p);
     }
     p.close(marker, SyntaxKind::PropertyListPath);
@@ -3113,12 +4107,23 @@ pub(super) fn parse_VerbSimple(p: &mut Parser) {
     parse_Var(p);
     p.close(marker, SyntaxKind::VerbSimple);
 }
+const FIRST_ObjectListPath_0: TokenSet = TokenSet::new(&[SyntaxKind::Comma]);
+const RECOVERY_ObjectListPath: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+]);
 /// [98] ObjectListPath -> ObjectPath (',' ObjectPath)*
 pub(super) fn parse_ObjectListPath(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_ObjectListPath);
     parse_ObjectPath(p);
-    while [SyntaxKind::Colon].contains(&p.nth(0)) {
-        p.expect(SyntaxKind::Colon);
+    while p.at_any(FIRST_ObjectListPath_0) {
+        p.expect(SyntaxKind::Comma);
         parse_ObjectPath(p);
     }
     p.close(marker, SyntaxKind::ObjectListPath);
@@ -3129,15 +4134,64 @@ pub(super) fn parse_Path(p: &mut Parser) {
     parse_PathAlternative(p);
     p.close(marker, SyntaxKind::Path);
 }
+const RECOVERY_ObjectPath: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [100] ObjectPath -> GraphNodePath
 pub(super) fn parse_ObjectPath(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_ObjectPath);
     parse_GraphNodePath(p);
     p.close(marker, SyntaxKind::ObjectPath);
 }
+const FIRST_GraphNodePath: TokenSet = TokenSet::new(&[
+    SyntaxKind::IRIREF,
+    SyntaxKind::PNAME_NS,
+    SyntaxKind::LParen,
+    SyntaxKind::INTEGER,
+    SyntaxKind::NIL,
+    SyntaxKind::LBrack,
+    SyntaxKind::VAR1,
+    SyntaxKind::VAR2,
+    SyntaxKind::DECIMAL,
+    SyntaxKind::DOUBLE,
+    SyntaxKind::INTEGER_POSITIVE,
+    SyntaxKind::DECIMAL_POSITIVE,
+    SyntaxKind::DOUBLE_POSITIVE,
+    SyntaxKind::INTEGER_NEGATIVE,
+    SyntaxKind::DECIMAL_NEGATIVE,
+    SyntaxKind::DOUBLE_NEGATIVE,
+    SyntaxKind::True,
+    SyntaxKind::False,
+    SyntaxKind::STRING_LITERAL1,
+    SyntaxKind::STRING_LITERAL2,
+    SyntaxKind::STRING_LITERAL_LONG1,
+    SyntaxKind::STRING_LITERAL_LONG2,
+    SyntaxKind::PNAME_LN,
+    SyntaxKind::BLANK_NODE_LABEL,
+    SyntaxKind::ANON,
+]);
+const RECOVERY_GraphNodePath: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [101] GraphNodePath -> VarOrTerm | TriplesNodePath
 pub(super) fn parse_GraphNodePath(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_GraphNodePath);
     match p.nth(0) {
         SyntaxKind::IRIREF
         | SyntaxKind::PNAME_NS
@@ -3167,32 +4221,55 @@ pub(super) fn parse_GraphNodePath(p: &mut Parser) {
         SyntaxKind::LParen | SyntaxKind::LBrack => {
             parse_TriplesNodePath(p);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::IRIREF,
+                SyntaxKind::PNAME_NS,
+                SyntaxKind::LParen,
+                SyntaxKind::INTEGER,
+                SyntaxKind::NIL,
+                SyntaxKind::LBrack,
+                SyntaxKind::VAR1,
+                SyntaxKind::VAR2,
+                SyntaxKind::DECIMAL,
+                SyntaxKind::DOUBLE,
+                SyntaxKind::INTEGER_POSITIVE,
+                SyntaxKind::DECIMAL_POSITIVE,
+                SyntaxKind::DOUBLE_POSITIVE,
+                SyntaxKind::INTEGER_NEGATIVE,
+                SyntaxKind::DECIMAL_NEGATIVE,
+                SyntaxKind::DOUBLE_NEGATIVE,
+                SyntaxKind::True,
+                SyntaxKind::False,
+                SyntaxKind::STRING_LITERAL1,
+                SyntaxKind::STRING_LITERAL2,
+                SyntaxKind::STRING_LITERAL_LONG1,
+                SyntaxKind::STRING_LITERAL_LONG2,
+                SyntaxKind::PNAME_LN,
+                SyntaxKind::BLANK_NODE_LABEL,
+                SyntaxKind::ANON,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::GraphNodePath);
 }
+const FIRST_PathAlternative_0: TokenSet = TokenSet::new(&[SyntaxKind::Pipe]);
 /// [102] PathAlternative -> PathSequence ('|' PathSequence)*
 pub(super) fn parse_PathAlternative(p: &mut Parser) {
     let marker = p.open();
     parse_PathSequence(p);
-    while [SyntaxKind::Pipe].contains(&p.nth(0)) {
+    while p.at_any(FIRST_PathAlternative_0) {
         p.expect(SyntaxKind::Pipe);
         parse_PathSequence(p);
     }
     p.close(marker, SyntaxKind::PathAlternative);
 }
+const FIRST_PathSequence_0: TokenSet = TokenSet::new(&[SyntaxKind::Slash]);
 /// [103] PathSequence -> PathEltOrInverse ('/' PathEltOrInverse)*
 pub(super) fn parse_PathSequence(p: &mut Parser) {
     let marker = p.open();
     parse_PathEltOrInverse(p);
-    while [SyntaxKind::Slash].contains(&p.nth(0)) {
+    while p.at_any(FIRST_PathSequence_0) {
         p.expect(SyntaxKind::Slash);
         parse_PathEltOrInverse(p);
     }
@@ -3214,13 +4291,16 @@ pub(super) fn parse_PathEltOrInverse(p: &mut Parser) {
             p.expect(SyntaxKind::Zirkumflex);
             parse_PathElt(p);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::IRIREF,
+                SyntaxKind::PNAME_NS,
+                SyntaxKind::LParen,
+                SyntaxKind::a,
+                SyntaxKind::Zirkumflex,
+                SyntaxKind::ExclamationMark,
+                SyntaxKind::PNAME_LN,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::PathEltOrInverse);
@@ -3229,7 +4309,7 @@ pub(super) fn parse_PathEltOrInverse(p: &mut Parser) {
 pub(super) fn parse_PathElt(p: &mut Parser) {
     let marker = p.open();
     parse_PathPrimary(p);
-    if p.at_any(&[SyntaxKind::Star, SyntaxKind::QuestionMark, SyntaxKind::Plus]) {
+    if p.at_any(FIRST_PathMod) {
         parse_PathMod(p);
     }
     p.close(marker, SyntaxKind::PathElt);
@@ -3253,17 +4333,21 @@ pub(super) fn parse_PathPrimary(p: &mut Parser) {
             parse_Path(p);
             p.expect(SyntaxKind::RParen);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::IRIREF,
+                SyntaxKind::PNAME_NS,
+                SyntaxKind::LParen,
+                SyntaxKind::a,
+                SyntaxKind::ExclamationMark,
+                SyntaxKind::PNAME_LN,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::PathPrimary);
 }
+const FIRST_PathMod: TokenSet =
+    TokenSet::new(&[SyntaxKind::Star, SyntaxKind::QuestionMark, SyntaxKind::Plus]);
 /// [107] PathMod -> '?' | '*' | '+'
 pub(super) fn parse_PathMod(p: &mut Parser) {
     let marker = p.open();
@@ -3277,17 +4361,20 @@ pub(super) fn parse_PathMod(p: &mut Parser) {
         SyntaxKind::Plus => {
             p.expect(SyntaxKind::Plus);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[SyntaxKind::Star, SyntaxKind::QuestionMark, SyntaxKind::Plus]);
         }
     };
     p.close(marker, SyntaxKind::PathMod);
 }
+const FIRST_PathNegatedPropertySet_0: TokenSet = TokenSet::new(&[SyntaxKind::Pipe]);
+const FIRST_PathNegatedPropertySet_1: TokenSet = TokenSet::new(&[
+    SyntaxKind::IRIREF,
+    SyntaxKind::PNAME_NS,
+    SyntaxKind::a,
+    SyntaxKind::Zirkumflex,
+    SyntaxKind::PNAME_LN,
+]);
 /// [108] PathNegatedPropertySet -> PathOneInPropertySet | '(' (PathOneInPropertySet ('|' PathOneInPropertySet)*)? ')'
 pub(super) fn parse_PathNegatedPropertySet(p: &mut Parser) {
     let marker = p.open();
@@ -3301,28 +4388,24 @@ pub(super) fn parse_PathNegatedPropertySet(p: &mut Parser) {
         }
         SyntaxKind::LParen => {
             p.expect(SyntaxKind::LParen);
-            if p.at_any(&[
-                SyntaxKind::IRIREF,
-                SyntaxKind::PNAME_NS,
-                SyntaxKind::a,
-                SyntaxKind::Zirkumflex,
-                SyntaxKind::PNAME_LN,
-            ]) {
+            if p.at_any(FIRST_PathNegatedPropertySet_1) {
                 parse_PathOneInPropertySet(p);
-                while [SyntaxKind::Pipe].contains(&p.nth(0)) {
+                while p.at_any(FIRST_PathNegatedPropertySet_0) {
                     p.expect(SyntaxKind::Pipe);
                     parse_PathOneInPropertySet(p);
                 }
             }
             p.expect(SyntaxKind::RParen);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::IRIREF,
+                SyntaxKind::PNAME_NS,
+                SyntaxKind::LParen,
+                SyntaxKind::a,
+                SyntaxKind::Zirkumflex,
+                SyntaxKind::PNAME_LN,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::PathNegatedPropertySet);
@@ -3346,28 +4429,28 @@ pub(super) fn parse_PathOneInPropertySet(p: &mut Parser) {
                 SyntaxKind::a => {
                     p.expect(SyntaxKind::a);
                 }
-                SyntaxKind::Eof => {
-                    eprintln!("Unexpected Eof");
-                    p.close(marker, SyntaxKind::Error);
-                    return;
-                }
                 _ => {
-                    p.advance_with_error("Expected ....");
+                    p.err_recover(&[
+                        SyntaxKind::IRIREF,
+                        SyntaxKind::PNAME_NS,
+                        SyntaxKind::a,
+                        SyntaxKind::PNAME_LN,
+                    ]);
                 }
             };
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::IRIREF,
+                SyntaxKind::PNAME_NS,
+                SyntaxKind::a,
+                SyntaxKind::Zirkumflex,
+                SyntaxKind::PNAME_LN,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::PathOneInPropertySet);
 }
-
 #[allow(dead_code)]
 /// [110] Integer -> 'INTEGER'
 pub(super) fn parse_Integer(p: &mut Parser) {
@@ -3375,103 +4458,102 @@ pub(super) fn parse_Integer(p: &mut Parser) {
     p.expect(SyntaxKind::INTEGER);
     p.close(marker, SyntaxKind::Integer);
 }
+const RECOVERY_Collection: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [111] Collection -> '(' GraphNode GraphNode* ')'
 pub(super) fn parse_Collection(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_Collection);
     p.expect(SyntaxKind::LParen);
     parse_GraphNode(p);
-    while [
-        SyntaxKind::IRIREF,
-        SyntaxKind::PNAME_NS,
-        SyntaxKind::LParen,
-        SyntaxKind::INTEGER,
-        SyntaxKind::NIL,
-        SyntaxKind::LBrack,
-        SyntaxKind::VAR1,
-        SyntaxKind::VAR2,
-        SyntaxKind::DECIMAL,
-        SyntaxKind::DOUBLE,
-        SyntaxKind::INTEGER_POSITIVE,
-        SyntaxKind::DECIMAL_POSITIVE,
-        SyntaxKind::DOUBLE_POSITIVE,
-        SyntaxKind::INTEGER_NEGATIVE,
-        SyntaxKind::DECIMAL_NEGATIVE,
-        SyntaxKind::DOUBLE_NEGATIVE,
-        SyntaxKind::True,
-        SyntaxKind::False,
-        SyntaxKind::STRING_LITERAL1,
-        SyntaxKind::STRING_LITERAL2,
-        SyntaxKind::STRING_LITERAL_LONG1,
-        SyntaxKind::STRING_LITERAL_LONG2,
-        SyntaxKind::PNAME_LN,
-        SyntaxKind::BLANK_NODE_LABEL,
-        SyntaxKind::ANON,
-    ]
-    .contains(&p.nth(0))
-    {
+    while p.at_any(FIRST_GraphNode) {
         parse_GraphNode(p);
     }
     p.expect(SyntaxKind::RParen);
     p.close(marker, SyntaxKind::Collection);
 }
+const RECOVERY_BlankNodePropertyList: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [112] BlankNodePropertyList -> '[' PropertyListNotEmpty ']'
 pub(super) fn parse_BlankNodePropertyList(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_BlankNodePropertyList);
     p.expect(SyntaxKind::LBrack);
     parse_PropertyListNotEmpty(p);
     p.expect(SyntaxKind::RBrack);
     p.close(marker, SyntaxKind::BlankNodePropertyList);
 }
+const RECOVERY_CollectionPath: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [113] CollectionPath -> '(' GraphNodePath GraphNodePath* ')'
 pub(super) fn parse_CollectionPath(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_CollectionPath);
     p.expect(SyntaxKind::LParen);
     parse_GraphNodePath(p);
-    while [
-        SyntaxKind::IRIREF,
-        SyntaxKind::PNAME_NS,
-        SyntaxKind::LParen,
-        SyntaxKind::INTEGER,
-        SyntaxKind::NIL,
-        SyntaxKind::LBrack,
-        SyntaxKind::VAR1,
-        SyntaxKind::VAR2,
-        SyntaxKind::DECIMAL,
-        SyntaxKind::DOUBLE,
-        SyntaxKind::INTEGER_POSITIVE,
-        SyntaxKind::DECIMAL_POSITIVE,
-        SyntaxKind::DOUBLE_POSITIVE,
-        SyntaxKind::INTEGER_NEGATIVE,
-        SyntaxKind::DECIMAL_NEGATIVE,
-        SyntaxKind::DOUBLE_NEGATIVE,
-        SyntaxKind::True,
-        SyntaxKind::False,
-        SyntaxKind::STRING_LITERAL1,
-        SyntaxKind::STRING_LITERAL2,
-        SyntaxKind::STRING_LITERAL_LONG1,
-        SyntaxKind::STRING_LITERAL_LONG2,
-        SyntaxKind::PNAME_LN,
-        SyntaxKind::BLANK_NODE_LABEL,
-        SyntaxKind::ANON,
-    ]
-    .contains(&p.nth(0))
-    {
+    while p.at_any(FIRST_GraphNodePath) {
         parse_GraphNodePath(p);
     }
     p.expect(SyntaxKind::RParen);
     p.close(marker, SyntaxKind::CollectionPath);
 }
+const RECOVERY_BlankNodePropertyListPath: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [114] BlankNodePropertyListPath -> '[' PropertyListPathNotEmpty ']'
 pub(super) fn parse_BlankNodePropertyListPath(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_BlankNodePropertyListPath);
     p.expect(SyntaxKind::LBrack);
     parse_PropertyListPathNotEmpty(p);
     p.expect(SyntaxKind::RBrack);
     p.close(marker, SyntaxKind::BlankNodePropertyListPath);
 }
+const RECOVERY_GraphTerm: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [115] GraphTerm -> iri | RDFLiteral | NumericLiteral | BooleanLiteral | BlankNode | 'NIL'
 pub(super) fn parse_GraphTerm(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_GraphTerm);
     match p.nth(0) {
         SyntaxKind::IRIREF | SyntaxKind::PNAME_NS | SyntaxKind::PNAME_LN => {
             parse_iri(p);
@@ -3502,20 +4584,48 @@ pub(super) fn parse_GraphTerm(p: &mut Parser) {
         SyntaxKind::NIL => {
             p.expect(SyntaxKind::NIL);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::IRIREF,
+                SyntaxKind::PNAME_NS,
+                SyntaxKind::INTEGER,
+                SyntaxKind::NIL,
+                SyntaxKind::DECIMAL,
+                SyntaxKind::DOUBLE,
+                SyntaxKind::INTEGER_POSITIVE,
+                SyntaxKind::DECIMAL_POSITIVE,
+                SyntaxKind::DOUBLE_POSITIVE,
+                SyntaxKind::INTEGER_NEGATIVE,
+                SyntaxKind::DECIMAL_NEGATIVE,
+                SyntaxKind::DOUBLE_NEGATIVE,
+                SyntaxKind::True,
+                SyntaxKind::False,
+                SyntaxKind::STRING_LITERAL1,
+                SyntaxKind::STRING_LITERAL2,
+                SyntaxKind::STRING_LITERAL_LONG1,
+                SyntaxKind::STRING_LITERAL_LONG2,
+                SyntaxKind::PNAME_LN,
+                SyntaxKind::BLANK_NODE_LABEL,
+                SyntaxKind::ANON,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::GraphTerm);
 }
+const RECOVERY_BlankNode: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [116] BlankNode -> 'BLANK_NODE_LABEL' | 'ANON'
 pub(super) fn parse_BlankNode(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_BlankNode);
     match p.nth(0) {
         SyntaxKind::BLANK_NODE_LABEL => {
             p.expect(SyntaxKind::BLANK_NODE_LABEL);
@@ -3523,57 +4633,62 @@ pub(super) fn parse_BlankNode(p: &mut Parser) {
         SyntaxKind::ANON => {
             p.expect(SyntaxKind::ANON);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[SyntaxKind::BLANK_NODE_LABEL, SyntaxKind::ANON]);
         }
     };
     p.close(marker, SyntaxKind::BlankNode);
 }
+const FIRST_ConditionalOrExpression_0: TokenSet = TokenSet::new(&[SyntaxKind::DoublePipe]);
+const RECOVERY_ConditionalOrExpression: TokenSet =
+    TokenSet::new(&[SyntaxKind::Semicolon, SyntaxKind::Comma]);
 /// [117] ConditionalOrExpression -> ConditionalAndExpression ('||' ConditionalAndExpression)*
 pub(super) fn parse_ConditionalOrExpression(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_ConditionalOrExpression);
     parse_ConditionalAndExpression(p);
-    while [SyntaxKind::DoublePipe].contains(&p.nth(0)) {
+    while p.at_any(FIRST_ConditionalOrExpression_0) {
         p.expect(SyntaxKind::DoublePipe);
         parse_ConditionalAndExpression(p);
     }
     p.close(marker, SyntaxKind::ConditionalOrExpression);
 }
+const FIRST_ConditionalAndExpression_0: TokenSet = TokenSet::new(&[SyntaxKind::DoubleAnd]);
+const RECOVERY_ConditionalAndExpression: TokenSet =
+    TokenSet::new(&[SyntaxKind::Semicolon, SyntaxKind::Comma]);
 /// [118] ConditionalAndExpression -> ValueLogical ('&&' ValueLogical)*
 pub(super) fn parse_ConditionalAndExpression(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_ConditionalAndExpression);
     parse_ValueLogical(p);
-    while [SyntaxKind::DoubleAnd].contains(&p.nth(0)) {
+    while p.at_any(FIRST_ConditionalAndExpression_0) {
         p.expect(SyntaxKind::DoubleAnd);
         parse_ValueLogical(p);
     }
     p.close(marker, SyntaxKind::ConditionalAndExpression);
 }
+const RECOVERY_ValueLogical: TokenSet = TokenSet::new(&[SyntaxKind::Semicolon, SyntaxKind::Comma]);
 /// [119] ValueLogical -> RelationalExpression
 pub(super) fn parse_ValueLogical(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_ValueLogical);
     parse_RelationalExpression(p);
     p.close(marker, SyntaxKind::ValueLogical);
 }
+const FIRST_RelationalExpression_0: TokenSet = TokenSet::new(&[
+    SyntaxKind::Equals,
+    SyntaxKind::ExclamationMarkEquals,
+    SyntaxKind::Less,
+    SyntaxKind::More,
+    SyntaxKind::LessEquals,
+    SyntaxKind::MoreEquals,
+    SyntaxKind::IN,
+    SyntaxKind::NOT,
+]);
+const RECOVERY_RelationalExpression: TokenSet =
+    TokenSet::new(&[SyntaxKind::Semicolon, SyntaxKind::Comma]);
 /// [120] RelationalExpression -> NumericExpression ('=' NumericExpression | '!=' NumericExpression | '<' NumericExpression | '>' NumericExpression | '<=' NumericExpression | '>=' NumericExpression | 'IN' ExpressionList | 'NOT' 'IN' ExpressionList)?
 pub(super) fn parse_RelationalExpression(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_RelationalExpression);
     parse_NumericExpression(p);
-    if p.at_any(&[
-        SyntaxKind::Equals,
-        SyntaxKind::ExclamationMarkEquals,
-        SyntaxKind::Less,
-        SyntaxKind::More,
-        SyntaxKind::LessEquals,
-        SyntaxKind::MoreEquals,
-        SyntaxKind::IN,
-        SyntaxKind::NOT,
-    ]) {
+    if p.at_any(FIRST_RelationalExpression_0) {
         match p.nth(0) {
             SyntaxKind::Equals => {
                 p.expect(SyntaxKind::Equals);
@@ -3608,40 +4723,48 @@ pub(super) fn parse_RelationalExpression(p: &mut Parser) {
                 p.expect(SyntaxKind::IN);
                 parse_ExpressionList(p);
             }
-            SyntaxKind::Eof => {
-                eprintln!("Unexpected Eof");
-                p.close(marker, SyntaxKind::Error);
-                return;
-            }
             _ => {
-                p.advance_with_error("Expected ....");
+                p.err_recover(&[
+                    SyntaxKind::Equals,
+                    SyntaxKind::ExclamationMarkEquals,
+                    SyntaxKind::Less,
+                    SyntaxKind::More,
+                    SyntaxKind::LessEquals,
+                    SyntaxKind::MoreEquals,
+                    SyntaxKind::IN,
+                    SyntaxKind::NOT,
+                ]);
             }
         };
     }
     p.close(marker, SyntaxKind::RelationalExpression);
 }
+const RECOVERY_NumericExpression: TokenSet =
+    TokenSet::new(&[SyntaxKind::Semicolon, SyntaxKind::Comma]);
 /// [121] NumericExpression -> AdditiveExpression
 pub(super) fn parse_NumericExpression(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_NumericExpression);
     parse_AdditiveExpression(p);
     p.close(marker, SyntaxKind::NumericExpression);
 }
+const FIRST_AdditiveExpression_0: TokenSet = TokenSet::new(&[SyntaxKind::Star, SyntaxKind::Slash]);
+const FIRST_AdditiveExpression_1: TokenSet = TokenSet::new(&[
+    SyntaxKind::Plus,
+    SyntaxKind::Minus,
+    SyntaxKind::INTEGER_POSITIVE,
+    SyntaxKind::DECIMAL_POSITIVE,
+    SyntaxKind::DOUBLE_POSITIVE,
+    SyntaxKind::INTEGER_NEGATIVE,
+    SyntaxKind::DECIMAL_NEGATIVE,
+    SyntaxKind::DOUBLE_NEGATIVE,
+]);
+const RECOVERY_AdditiveExpression: TokenSet =
+    TokenSet::new(&[SyntaxKind::Semicolon, SyntaxKind::Comma]);
 /// [122] AdditiveExpression -> MultiplicativeExpression ('+' MultiplicativeExpression | '-' MultiplicativeExpression | (NumericLiteralPositive | NumericLiteralNegative) ('*' UnaryExpression | '/' UnaryExpression)*)*
 pub(super) fn parse_AdditiveExpression(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_AdditiveExpression);
     parse_MultiplicativeExpression(p);
-    while [
-        SyntaxKind::Plus,
-        SyntaxKind::Minus,
-        SyntaxKind::INTEGER_POSITIVE,
-        SyntaxKind::DECIMAL_POSITIVE,
-        SyntaxKind::DOUBLE_POSITIVE,
-        SyntaxKind::INTEGER_NEGATIVE,
-        SyntaxKind::DECIMAL_NEGATIVE,
-        SyntaxKind::DOUBLE_NEGATIVE,
-    ]
-    .contains(&p.nth(0))
-    {
+    while p.at_any(FIRST_AdditiveExpression_1) {
         match p.nth(0) {
             SyntaxKind::Plus => {
                 p.expect(SyntaxKind::Plus);
@@ -3668,16 +4791,18 @@ pub(super) fn parse_AdditiveExpression(p: &mut Parser) {
                     | SyntaxKind::DOUBLE_NEGATIVE => {
                         parse_NumericLiteralNegative(p);
                     }
-                    SyntaxKind::Eof => {
-                        eprintln!("Unexpected Eof");
-                        p.close(marker, SyntaxKind::Error);
-                        return;
-                    }
                     _ => {
-                        p.advance_with_error("Expected ....");
+                        p.err_recover(&[
+                            SyntaxKind::INTEGER_POSITIVE,
+                            SyntaxKind::DECIMAL_POSITIVE,
+                            SyntaxKind::DOUBLE_POSITIVE,
+                            SyntaxKind::INTEGER_NEGATIVE,
+                            SyntaxKind::DECIMAL_NEGATIVE,
+                            SyntaxKind::DOUBLE_NEGATIVE,
+                        ]);
                     }
                 };
-                while [SyntaxKind::Star, SyntaxKind::Slash].contains(&p.nth(0)) {
+                while p.at_any(FIRST_AdditiveExpression_0) {
                     match p.nth(0) {
                         SyntaxKind::Star => {
                             p.expect(SyntaxKind::Star);
@@ -3687,34 +4812,37 @@ pub(super) fn parse_AdditiveExpression(p: &mut Parser) {
                             p.expect(SyntaxKind::Slash);
                             parse_UnaryExpression(p);
                         }
-                        SyntaxKind::Eof => {
-                            eprintln!("Unexpected Eof");
-                            p.close(marker, SyntaxKind::Error);
-                            return;
-                        }
                         _ => {
-                            p.advance_with_error("Expected ....");
+                            p.err_recover(&[SyntaxKind::Star, SyntaxKind::Slash]);
                         }
                     };
                 }
             }
-            SyntaxKind::Eof => {
-                eprintln!("Unexpected Eof");
-                p.close(marker, SyntaxKind::Error);
-                return;
-            }
             _ => {
-                p.advance_with_error("Expected ....");
+                p.err_recover(&[
+                    SyntaxKind::Plus,
+                    SyntaxKind::Minus,
+                    SyntaxKind::INTEGER_POSITIVE,
+                    SyntaxKind::DECIMAL_POSITIVE,
+                    SyntaxKind::DOUBLE_POSITIVE,
+                    SyntaxKind::INTEGER_NEGATIVE,
+                    SyntaxKind::DECIMAL_NEGATIVE,
+                    SyntaxKind::DOUBLE_NEGATIVE,
+                ]);
             }
         };
     }
     p.close(marker, SyntaxKind::AdditiveExpression);
 }
+const FIRST_MultiplicativeExpression_0: TokenSet =
+    TokenSet::new(&[SyntaxKind::Star, SyntaxKind::Slash]);
+const RECOVERY_MultiplicativeExpression: TokenSet =
+    TokenSet::new(&[SyntaxKind::Semicolon, SyntaxKind::Comma]);
 /// [123] MultiplicativeExpression -> UnaryExpression ('*' UnaryExpression | '/' UnaryExpression)*
 pub(super) fn parse_MultiplicativeExpression(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_MultiplicativeExpression);
     parse_UnaryExpression(p);
-    while [SyntaxKind::Star, SyntaxKind::Slash].contains(&p.nth(0)) {
+    while p.at_any(FIRST_MultiplicativeExpression_0) {
         match p.nth(0) {
             SyntaxKind::Star => {
                 p.expect(SyntaxKind::Star);
@@ -3724,21 +4852,27 @@ pub(super) fn parse_MultiplicativeExpression(p: &mut Parser) {
                 p.expect(SyntaxKind::Slash);
                 parse_UnaryExpression(p);
             }
-            SyntaxKind::Eof => {
-                eprintln!("Unexpected Eof");
-                p.close(marker, SyntaxKind::Error);
-                return;
-            }
             _ => {
-                p.advance_with_error("Expected ....");
+                p.err_recover(&[SyntaxKind::Star, SyntaxKind::Slash]);
             }
         };
     }
     p.close(marker, SyntaxKind::MultiplicativeExpression);
 }
+const RECOVERY_NumericLiteralPositive: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [124] NumericLiteralPositive -> 'INTEGER_POSITIVE' | 'DECIMAL_POSITIVE' | 'DOUBLE_POSITIVE'
 pub(super) fn parse_NumericLiteralPositive(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_NumericLiteralPositive);
     match p.nth(0) {
         SyntaxKind::INTEGER_POSITIVE => {
             p.expect(SyntaxKind::INTEGER_POSITIVE);
@@ -3749,20 +4883,30 @@ pub(super) fn parse_NumericLiteralPositive(p: &mut Parser) {
         SyntaxKind::DOUBLE_POSITIVE => {
             p.expect(SyntaxKind::DOUBLE_POSITIVE);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::INTEGER_POSITIVE,
+                SyntaxKind::DECIMAL_POSITIVE,
+                SyntaxKind::DOUBLE_POSITIVE,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::NumericLiteralPositive);
 }
+const RECOVERY_NumericLiteralNegative: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [125] NumericLiteralNegative -> 'INTEGER_NEGATIVE' | 'DECIMAL_NEGATIVE' | 'DOUBLE_NEGATIVE'
 pub(super) fn parse_NumericLiteralNegative(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_NumericLiteralNegative);
     match p.nth(0) {
         SyntaxKind::INTEGER_NEGATIVE => {
             p.expect(SyntaxKind::INTEGER_NEGATIVE);
@@ -3773,20 +4917,108 @@ pub(super) fn parse_NumericLiteralNegative(p: &mut Parser) {
         SyntaxKind::DOUBLE_NEGATIVE => {
             p.expect(SyntaxKind::DOUBLE_NEGATIVE);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::INTEGER_NEGATIVE,
+                SyntaxKind::DECIMAL_NEGATIVE,
+                SyntaxKind::DOUBLE_NEGATIVE,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::NumericLiteralNegative);
 }
+pub(super) const FIRST_UnaryExpression: TokenSet = TokenSet::new(&[
+    SyntaxKind::IRIREF,
+    SyntaxKind::PNAME_NS,
+    SyntaxKind::LParen,
+    SyntaxKind::INTEGER,
+    SyntaxKind::Plus,
+    SyntaxKind::ExclamationMark,
+    SyntaxKind::VAR1,
+    SyntaxKind::VAR2,
+    SyntaxKind::NOT,
+    SyntaxKind::Minus,
+    SyntaxKind::STR,
+    SyntaxKind::LANG,
+    SyntaxKind::LANGMATCHES,
+    SyntaxKind::DATATYPE,
+    SyntaxKind::BOUND,
+    SyntaxKind::IRI,
+    SyntaxKind::URI,
+    SyntaxKind::BNODE,
+    SyntaxKind::RAND,
+    SyntaxKind::ABS,
+    SyntaxKind::CEIL,
+    SyntaxKind::FLOOR,
+    SyntaxKind::ROUND,
+    SyntaxKind::CONCAT,
+    SyntaxKind::STRLEN,
+    SyntaxKind::UCASE,
+    SyntaxKind::LCASE,
+    SyntaxKind::ENCODE_FOR_URI,
+    SyntaxKind::CONTAINS,
+    SyntaxKind::STRSTARTS,
+    SyntaxKind::STRENDS,
+    SyntaxKind::STRBEFORE,
+    SyntaxKind::STRAFTER,
+    SyntaxKind::YEAR,
+    SyntaxKind::MONTH,
+    SyntaxKind::DAY,
+    SyntaxKind::HOURS,
+    SyntaxKind::MINUTES,
+    SyntaxKind::SECONDS,
+    SyntaxKind::TIMEZONE,
+    SyntaxKind::TZ,
+    SyntaxKind::NOW,
+    SyntaxKind::UUID,
+    SyntaxKind::STRUUID,
+    SyntaxKind::MD5,
+    SyntaxKind::SHA1,
+    SyntaxKind::SHA256,
+    SyntaxKind::SHA384,
+    SyntaxKind::SHA512,
+    SyntaxKind::COALESCE,
+    SyntaxKind::IF,
+    SyntaxKind::STRLANG,
+    SyntaxKind::STRDT,
+    SyntaxKind::sameTerm,
+    SyntaxKind::isIRI,
+    SyntaxKind::isURI,
+    SyntaxKind::isBLANK,
+    SyntaxKind::isLITERAL,
+    SyntaxKind::isNUMERIC,
+    SyntaxKind::REGEX,
+    SyntaxKind::SUBSTR,
+    SyntaxKind::REPLACE,
+    SyntaxKind::EXISTS,
+    SyntaxKind::COUNT,
+    SyntaxKind::SUM,
+    SyntaxKind::MIN,
+    SyntaxKind::MAX,
+    SyntaxKind::AVG,
+    SyntaxKind::SAMPLE,
+    SyntaxKind::GROUP_CONCAT,
+    SyntaxKind::DECIMAL,
+    SyntaxKind::DOUBLE,
+    SyntaxKind::INTEGER_POSITIVE,
+    SyntaxKind::DECIMAL_POSITIVE,
+    SyntaxKind::DOUBLE_POSITIVE,
+    SyntaxKind::INTEGER_NEGATIVE,
+    SyntaxKind::DECIMAL_NEGATIVE,
+    SyntaxKind::DOUBLE_NEGATIVE,
+    SyntaxKind::True,
+    SyntaxKind::False,
+    SyntaxKind::STRING_LITERAL1,
+    SyntaxKind::STRING_LITERAL2,
+    SyntaxKind::STRING_LITERAL_LONG1,
+    SyntaxKind::STRING_LITERAL_LONG2,
+    SyntaxKind::PNAME_LN,
+]);
+const RECOVERY_UnaryExpression: TokenSet =
+    TokenSet::new(&[SyntaxKind::Semicolon, SyntaxKind::Comma]);
 /// [126] UnaryExpression -> '!' PrimaryExpression | '+' PrimaryExpression | '-' PrimaryExpression | PrimaryExpression
 pub(super) fn parse_UnaryExpression(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_UnaryExpression);
     match p.nth(0) {
         SyntaxKind::ExclamationMark => {
             p.expect(SyntaxKind::ExclamationMark);
@@ -3884,20 +5116,103 @@ pub(super) fn parse_UnaryExpression(p: &mut Parser) {
         | SyntaxKind::PNAME_LN => {
             parse_PrimaryExpression(p);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::IRIREF,
+                SyntaxKind::PNAME_NS,
+                SyntaxKind::LParen,
+                SyntaxKind::INTEGER,
+                SyntaxKind::Plus,
+                SyntaxKind::ExclamationMark,
+                SyntaxKind::VAR1,
+                SyntaxKind::VAR2,
+                SyntaxKind::NOT,
+                SyntaxKind::Minus,
+                SyntaxKind::STR,
+                SyntaxKind::LANG,
+                SyntaxKind::LANGMATCHES,
+                SyntaxKind::DATATYPE,
+                SyntaxKind::BOUND,
+                SyntaxKind::IRI,
+                SyntaxKind::URI,
+                SyntaxKind::BNODE,
+                SyntaxKind::RAND,
+                SyntaxKind::ABS,
+                SyntaxKind::CEIL,
+                SyntaxKind::FLOOR,
+                SyntaxKind::ROUND,
+                SyntaxKind::CONCAT,
+                SyntaxKind::STRLEN,
+                SyntaxKind::UCASE,
+                SyntaxKind::LCASE,
+                SyntaxKind::ENCODE_FOR_URI,
+                SyntaxKind::CONTAINS,
+                SyntaxKind::STRSTARTS,
+                SyntaxKind::STRENDS,
+                SyntaxKind::STRBEFORE,
+                SyntaxKind::STRAFTER,
+                SyntaxKind::YEAR,
+                SyntaxKind::MONTH,
+                SyntaxKind::DAY,
+                SyntaxKind::HOURS,
+                SyntaxKind::MINUTES,
+                SyntaxKind::SECONDS,
+                SyntaxKind::TIMEZONE,
+                SyntaxKind::TZ,
+                SyntaxKind::NOW,
+                SyntaxKind::UUID,
+                SyntaxKind::STRUUID,
+                SyntaxKind::MD5,
+                SyntaxKind::SHA1,
+                SyntaxKind::SHA256,
+                SyntaxKind::SHA384,
+                SyntaxKind::SHA512,
+                SyntaxKind::COALESCE,
+                SyntaxKind::IF,
+                SyntaxKind::STRLANG,
+                SyntaxKind::STRDT,
+                SyntaxKind::sameTerm,
+                SyntaxKind::isIRI,
+                SyntaxKind::isURI,
+                SyntaxKind::isBLANK,
+                SyntaxKind::isLITERAL,
+                SyntaxKind::isNUMERIC,
+                SyntaxKind::REGEX,
+                SyntaxKind::SUBSTR,
+                SyntaxKind::REPLACE,
+                SyntaxKind::EXISTS,
+                SyntaxKind::COUNT,
+                SyntaxKind::SUM,
+                SyntaxKind::MIN,
+                SyntaxKind::MAX,
+                SyntaxKind::AVG,
+                SyntaxKind::SAMPLE,
+                SyntaxKind::GROUP_CONCAT,
+                SyntaxKind::DECIMAL,
+                SyntaxKind::DOUBLE,
+                SyntaxKind::INTEGER_POSITIVE,
+                SyntaxKind::DECIMAL_POSITIVE,
+                SyntaxKind::DOUBLE_POSITIVE,
+                SyntaxKind::INTEGER_NEGATIVE,
+                SyntaxKind::DECIMAL_NEGATIVE,
+                SyntaxKind::DOUBLE_NEGATIVE,
+                SyntaxKind::True,
+                SyntaxKind::False,
+                SyntaxKind::STRING_LITERAL1,
+                SyntaxKind::STRING_LITERAL2,
+                SyntaxKind::STRING_LITERAL_LONG1,
+                SyntaxKind::STRING_LITERAL_LONG2,
+                SyntaxKind::PNAME_LN,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::UnaryExpression);
 }
+const RECOVERY_PrimaryExpression: TokenSet =
+    TokenSet::new(&[SyntaxKind::Semicolon, SyntaxKind::Comma]);
 /// [127] PrimaryExpression -> BrackettedExpression | BuiltInCall | iriOrFunction | RDFLiteral | NumericLiteral | BooleanLiteral | Var
 pub(super) fn parse_PrimaryExpression(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_PrimaryExpression);
     match p.nth(0) {
         SyntaxKind::LParen => {
             parse_BrackettedExpression(p);
@@ -3991,34 +5306,130 @@ pub(super) fn parse_PrimaryExpression(p: &mut Parser) {
         SyntaxKind::VAR1 | SyntaxKind::VAR2 => {
             parse_Var(p);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::IRIREF,
+                SyntaxKind::PNAME_NS,
+                SyntaxKind::LParen,
+                SyntaxKind::INTEGER,
+                SyntaxKind::VAR1,
+                SyntaxKind::VAR2,
+                SyntaxKind::NOT,
+                SyntaxKind::STR,
+                SyntaxKind::LANG,
+                SyntaxKind::LANGMATCHES,
+                SyntaxKind::DATATYPE,
+                SyntaxKind::BOUND,
+                SyntaxKind::IRI,
+                SyntaxKind::URI,
+                SyntaxKind::BNODE,
+                SyntaxKind::RAND,
+                SyntaxKind::ABS,
+                SyntaxKind::CEIL,
+                SyntaxKind::FLOOR,
+                SyntaxKind::ROUND,
+                SyntaxKind::CONCAT,
+                SyntaxKind::STRLEN,
+                SyntaxKind::UCASE,
+                SyntaxKind::LCASE,
+                SyntaxKind::ENCODE_FOR_URI,
+                SyntaxKind::CONTAINS,
+                SyntaxKind::STRSTARTS,
+                SyntaxKind::STRENDS,
+                SyntaxKind::STRBEFORE,
+                SyntaxKind::STRAFTER,
+                SyntaxKind::YEAR,
+                SyntaxKind::MONTH,
+                SyntaxKind::DAY,
+                SyntaxKind::HOURS,
+                SyntaxKind::MINUTES,
+                SyntaxKind::SECONDS,
+                SyntaxKind::TIMEZONE,
+                SyntaxKind::TZ,
+                SyntaxKind::NOW,
+                SyntaxKind::UUID,
+                SyntaxKind::STRUUID,
+                SyntaxKind::MD5,
+                SyntaxKind::SHA1,
+                SyntaxKind::SHA256,
+                SyntaxKind::SHA384,
+                SyntaxKind::SHA512,
+                SyntaxKind::COALESCE,
+                SyntaxKind::IF,
+                SyntaxKind::STRLANG,
+                SyntaxKind::STRDT,
+                SyntaxKind::sameTerm,
+                SyntaxKind::isIRI,
+                SyntaxKind::isURI,
+                SyntaxKind::isBLANK,
+                SyntaxKind::isLITERAL,
+                SyntaxKind::isNUMERIC,
+                SyntaxKind::REGEX,
+                SyntaxKind::SUBSTR,
+                SyntaxKind::REPLACE,
+                SyntaxKind::EXISTS,
+                SyntaxKind::COUNT,
+                SyntaxKind::SUM,
+                SyntaxKind::MIN,
+                SyntaxKind::MAX,
+                SyntaxKind::AVG,
+                SyntaxKind::SAMPLE,
+                SyntaxKind::GROUP_CONCAT,
+                SyntaxKind::DECIMAL,
+                SyntaxKind::DOUBLE,
+                SyntaxKind::INTEGER_POSITIVE,
+                SyntaxKind::DECIMAL_POSITIVE,
+                SyntaxKind::DOUBLE_POSITIVE,
+                SyntaxKind::INTEGER_NEGATIVE,
+                SyntaxKind::DECIMAL_NEGATIVE,
+                SyntaxKind::DOUBLE_NEGATIVE,
+                SyntaxKind::True,
+                SyntaxKind::False,
+                SyntaxKind::STRING_LITERAL1,
+                SyntaxKind::STRING_LITERAL2,
+                SyntaxKind::STRING_LITERAL_LONG1,
+                SyntaxKind::STRING_LITERAL_LONG2,
+                SyntaxKind::PNAME_LN,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::PrimaryExpression);
 }
+const RECOVERY_iriOrFunction: TokenSet = TokenSet::new(&[SyntaxKind::Semicolon, SyntaxKind::Comma]);
 /// [128] iriOrFunction -> iri ArgList?
 pub(super) fn parse_iriOrFunction(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_iriOrFunction);
     parse_iri(p);
-    if p.at_any(&[SyntaxKind::LParen, SyntaxKind::NIL]) {
+    if p.at_any(FIRST_ArgList) {
         parse_ArgList(p);
     }
     p.close(marker, SyntaxKind::iriOrFunction);
 }
+const FIRST_Aggregate_0: TokenSet = TokenSet::new(&[SyntaxKind::DISTINCT]);
+const FIRST_Aggregate_1: TokenSet = TokenSet::new(&[SyntaxKind::Semicolon]);
+const RECOVERY_Aggregate: TokenSet = TokenSet::new(&[
+    SyntaxKind::HAVING,
+    SyntaxKind::ORDER,
+    SyntaxKind::LIMIT,
+    SyntaxKind::OFFSET,
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [129] Aggregate -> 'COUNT' '(' 'DISTINCT'? ('*' | Expression) ')' | 'SUM' '(' 'DISTINCT'? Expression ')' | 'MIN' '(' 'DISTINCT'? Expression ')' | 'MAX' '(' 'DISTINCT'? Expression ')' | 'AVG' '(' 'DISTINCT'? Expression ')' | 'SAMPLE' '(' 'DISTINCT'? Expression ')' | 'GROUP_CONCAT' '(' 'DISTINCT'? Expression (';' 'SEPARATOR' '=' String)? ')'
 pub(super) fn parse_Aggregate(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_Aggregate);
     match p.nth(0) {
         SyntaxKind::COUNT => {
             p.expect(SyntaxKind::COUNT);
             p.expect(SyntaxKind::LParen);
-            if p.at_any(&[SyntaxKind::DISTINCT]) {
+            if p.at_any(FIRST_Aggregate_0) {
                 p.expect(SyntaxKind::DISTINCT);
             }
             match p.nth(0) {
@@ -4112,13 +5523,95 @@ pub(super) fn parse_Aggregate(p: &mut Parser) {
                 | SyntaxKind::PNAME_LN => {
                     parse_Expression(p);
                 }
-                SyntaxKind::Eof => {
-                    eprintln!("Unexpected Eof");
-                    p.close(marker, SyntaxKind::Error);
-                    return;
-                }
                 _ => {
-                    p.advance_with_error("Expected ....");
+                    p.err_recover(&[
+                        SyntaxKind::IRIREF,
+                        SyntaxKind::PNAME_NS,
+                        SyntaxKind::LParen,
+                        SyntaxKind::Star,
+                        SyntaxKind::INTEGER,
+                        SyntaxKind::Plus,
+                        SyntaxKind::ExclamationMark,
+                        SyntaxKind::VAR1,
+                        SyntaxKind::VAR2,
+                        SyntaxKind::NOT,
+                        SyntaxKind::Minus,
+                        SyntaxKind::STR,
+                        SyntaxKind::LANG,
+                        SyntaxKind::LANGMATCHES,
+                        SyntaxKind::DATATYPE,
+                        SyntaxKind::BOUND,
+                        SyntaxKind::IRI,
+                        SyntaxKind::URI,
+                        SyntaxKind::BNODE,
+                        SyntaxKind::RAND,
+                        SyntaxKind::ABS,
+                        SyntaxKind::CEIL,
+                        SyntaxKind::FLOOR,
+                        SyntaxKind::ROUND,
+                        SyntaxKind::CONCAT,
+                        SyntaxKind::STRLEN,
+                        SyntaxKind::UCASE,
+                        SyntaxKind::LCASE,
+                        SyntaxKind::ENCODE_FOR_URI,
+                        SyntaxKind::CONTAINS,
+                        SyntaxKind::STRSTARTS,
+                        SyntaxKind::STRENDS,
+                        SyntaxKind::STRBEFORE,
+                        SyntaxKind::STRAFTER,
+                        SyntaxKind::YEAR,
+                        SyntaxKind::MONTH,
+                        SyntaxKind::DAY,
+                        SyntaxKind::HOURS,
+                        SyntaxKind::MINUTES,
+                        SyntaxKind::SECONDS,
+                        SyntaxKind::TIMEZONE,
+                        SyntaxKind::TZ,
+                        SyntaxKind::NOW,
+                        SyntaxKind::UUID,
+                        SyntaxKind::STRUUID,
+                        SyntaxKind::MD5,
+                        SyntaxKind::SHA1,
+                        SyntaxKind::SHA256,
+                        SyntaxKind::SHA384,
+                        SyntaxKind::SHA512,
+                        SyntaxKind::COALESCE,
+                        SyntaxKind::IF,
+                        SyntaxKind::STRLANG,
+                        SyntaxKind::STRDT,
+                        SyntaxKind::sameTerm,
+                        SyntaxKind::isIRI,
+                        SyntaxKind::isURI,
+                        SyntaxKind::isBLANK,
+                        SyntaxKind::isLITERAL,
+                        SyntaxKind::isNUMERIC,
+                        SyntaxKind::REGEX,
+                        SyntaxKind::SUBSTR,
+                        SyntaxKind::REPLACE,
+                        SyntaxKind::EXISTS,
+                        SyntaxKind::COUNT,
+                        SyntaxKind::SUM,
+                        SyntaxKind::MIN,
+                        SyntaxKind::MAX,
+                        SyntaxKind::AVG,
+                        SyntaxKind::SAMPLE,
+                        SyntaxKind::GROUP_CONCAT,
+                        SyntaxKind::DECIMAL,
+                        SyntaxKind::DOUBLE,
+                        SyntaxKind::INTEGER_POSITIVE,
+                        SyntaxKind::DECIMAL_POSITIVE,
+                        SyntaxKind::DOUBLE_POSITIVE,
+                        SyntaxKind::INTEGER_NEGATIVE,
+                        SyntaxKind::DECIMAL_NEGATIVE,
+                        SyntaxKind::DOUBLE_NEGATIVE,
+                        SyntaxKind::True,
+                        SyntaxKind::False,
+                        SyntaxKind::STRING_LITERAL1,
+                        SyntaxKind::STRING_LITERAL2,
+                        SyntaxKind::STRING_LITERAL_LONG1,
+                        SyntaxKind::STRING_LITERAL_LONG2,
+                        SyntaxKind::PNAME_LN,
+                    ]);
                 }
             };
             p.expect(SyntaxKind::RParen);
@@ -4126,7 +5619,7 @@ pub(super) fn parse_Aggregate(p: &mut Parser) {
         SyntaxKind::SUM => {
             p.expect(SyntaxKind::SUM);
             p.expect(SyntaxKind::LParen);
-            if p.at_any(&[SyntaxKind::DISTINCT]) {
+            if p.at_any(FIRST_Aggregate_0) {
                 p.expect(SyntaxKind::DISTINCT);
             }
             parse_Expression(p);
@@ -4135,7 +5628,7 @@ pub(super) fn parse_Aggregate(p: &mut Parser) {
         SyntaxKind::MIN => {
             p.expect(SyntaxKind::MIN);
             p.expect(SyntaxKind::LParen);
-            if p.at_any(&[SyntaxKind::DISTINCT]) {
+            if p.at_any(FIRST_Aggregate_0) {
                 p.expect(SyntaxKind::DISTINCT);
             }
             parse_Expression(p);
@@ -4144,7 +5637,7 @@ pub(super) fn parse_Aggregate(p: &mut Parser) {
         SyntaxKind::MAX => {
             p.expect(SyntaxKind::MAX);
             p.expect(SyntaxKind::LParen);
-            if p.at_any(&[SyntaxKind::DISTINCT]) {
+            if p.at_any(FIRST_Aggregate_0) {
                 p.expect(SyntaxKind::DISTINCT);
             }
             parse_Expression(p);
@@ -4153,7 +5646,7 @@ pub(super) fn parse_Aggregate(p: &mut Parser) {
         SyntaxKind::AVG => {
             p.expect(SyntaxKind::AVG);
             p.expect(SyntaxKind::LParen);
-            if p.at_any(&[SyntaxKind::DISTINCT]) {
+            if p.at_any(FIRST_Aggregate_0) {
                 p.expect(SyntaxKind::DISTINCT);
             }
             parse_Expression(p);
@@ -4162,7 +5655,7 @@ pub(super) fn parse_Aggregate(p: &mut Parser) {
         SyntaxKind::SAMPLE => {
             p.expect(SyntaxKind::SAMPLE);
             p.expect(SyntaxKind::LParen);
-            if p.at_any(&[SyntaxKind::DISTINCT]) {
+            if p.at_any(FIRST_Aggregate_0) {
                 p.expect(SyntaxKind::DISTINCT);
             }
             parse_Expression(p);
@@ -4171,11 +5664,11 @@ pub(super) fn parse_Aggregate(p: &mut Parser) {
         SyntaxKind::GROUP_CONCAT => {
             p.expect(SyntaxKind::GROUP_CONCAT);
             p.expect(SyntaxKind::LParen);
-            if p.at_any(&[SyntaxKind::DISTINCT]) {
+            if p.at_any(FIRST_Aggregate_0) {
                 p.expect(SyntaxKind::DISTINCT);
             }
             parse_Expression(p);
-            if p.at_any(&[SyntaxKind::Semicolon]) {
+            if p.at_any(FIRST_Aggregate_1) {
                 p.expect(SyntaxKind::Semicolon);
                 p.expect(SyntaxKind::SEPARATOR);
                 p.expect(SyntaxKind::Equals);
@@ -4183,82 +5676,174 @@ pub(super) fn parse_Aggregate(p: &mut Parser) {
             }
             p.expect(SyntaxKind::RParen);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::COUNT,
+                SyntaxKind::SUM,
+                SyntaxKind::MIN,
+                SyntaxKind::MAX,
+                SyntaxKind::AVG,
+                SyntaxKind::SAMPLE,
+                SyntaxKind::GROUP_CONCAT,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::Aggregate);
 }
+const FIRST_SubstringExpression_0: TokenSet = TokenSet::new(&[SyntaxKind::Comma]);
+const RECOVERY_SubstringExpression: TokenSet = TokenSet::new(&[
+    SyntaxKind::HAVING,
+    SyntaxKind::ORDER,
+    SyntaxKind::LIMIT,
+    SyntaxKind::OFFSET,
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [130] SubstringExpression -> 'SUBSTR' '(' Expression ',' Expression (',' Expression)? ')'
 pub(super) fn parse_SubstringExpression(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_SubstringExpression);
     p.expect(SyntaxKind::SUBSTR);
     p.expect(SyntaxKind::LParen);
     parse_Expression(p);
-    p.expect(SyntaxKind::Colon);
+    p.expect(SyntaxKind::Comma);
     parse_Expression(p);
-    if p.at_any(&[SyntaxKind::Colon]) {
-        p.expect(SyntaxKind::Colon);
+    if p.at_any(FIRST_SubstringExpression_0) {
+        p.expect(SyntaxKind::Comma);
         parse_Expression(p);
     }
     p.expect(SyntaxKind::RParen);
     p.close(marker, SyntaxKind::SubstringExpression);
 }
+const FIRST_StrReplaceExpression_0: TokenSet = TokenSet::new(&[SyntaxKind::Comma]);
+const RECOVERY_StrReplaceExpression: TokenSet = TokenSet::new(&[
+    SyntaxKind::HAVING,
+    SyntaxKind::ORDER,
+    SyntaxKind::LIMIT,
+    SyntaxKind::OFFSET,
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [131] StrReplaceExpression -> 'REPLACE' '(' Expression ',' Expression ',' Expression (',' Expression)? ')'
 pub(super) fn parse_StrReplaceExpression(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_StrReplaceExpression);
     p.expect(SyntaxKind::REPLACE);
     p.expect(SyntaxKind::LParen);
     parse_Expression(p);
-    p.expect(SyntaxKind::Colon);
+    p.expect(SyntaxKind::Comma);
     parse_Expression(p);
-    p.expect(SyntaxKind::Colon);
+    p.expect(SyntaxKind::Comma);
     parse_Expression(p);
-    if p.at_any(&[SyntaxKind::Colon]) {
-        p.expect(SyntaxKind::Colon);
+    if p.at_any(FIRST_StrReplaceExpression_0) {
+        p.expect(SyntaxKind::Comma);
         parse_Expression(p);
     }
     p.expect(SyntaxKind::RParen);
     p.close(marker, SyntaxKind::StrReplaceExpression);
 }
+const FIRST_RegexExpression_0: TokenSet = TokenSet::new(&[SyntaxKind::Comma]);
+const RECOVERY_RegexExpression: TokenSet = TokenSet::new(&[
+    SyntaxKind::HAVING,
+    SyntaxKind::ORDER,
+    SyntaxKind::LIMIT,
+    SyntaxKind::OFFSET,
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [132] RegexExpression -> 'REGEX' '(' Expression ',' Expression (',' Expression)? ')'
 pub(super) fn parse_RegexExpression(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_RegexExpression);
     p.expect(SyntaxKind::REGEX);
     p.expect(SyntaxKind::LParen);
     parse_Expression(p);
-    p.expect(SyntaxKind::Colon);
+    p.expect(SyntaxKind::Comma);
     parse_Expression(p);
-    if p.at_any(&[SyntaxKind::Colon]) {
-        p.expect(SyntaxKind::Colon);
+    if p.at_any(FIRST_RegexExpression_0) {
+        p.expect(SyntaxKind::Comma);
         parse_Expression(p);
     }
     p.expect(SyntaxKind::RParen);
     p.close(marker, SyntaxKind::RegexExpression);
 }
+const RECOVERY_ExistsFunc: TokenSet = TokenSet::new(&[
+    SyntaxKind::HAVING,
+    SyntaxKind::ORDER,
+    SyntaxKind::LIMIT,
+    SyntaxKind::OFFSET,
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [133] ExistsFunc -> 'EXISTS' GroupGraphPattern
 pub(super) fn parse_ExistsFunc(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_ExistsFunc);
     p.expect(SyntaxKind::EXISTS);
     parse_GroupGraphPattern(p);
     p.close(marker, SyntaxKind::ExistsFunc);
 }
+const RECOVERY_NotExistsFunc: TokenSet = TokenSet::new(&[
+    SyntaxKind::HAVING,
+    SyntaxKind::ORDER,
+    SyntaxKind::LIMIT,
+    SyntaxKind::OFFSET,
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [134] NotExistsFunc -> 'NOT' 'EXISTS' GroupGraphPattern
 pub(super) fn parse_NotExistsFunc(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_NotExistsFunc);
     p.expect(SyntaxKind::NOT);
     p.expect(SyntaxKind::EXISTS);
     parse_GroupGraphPattern(p);
     p.close(marker, SyntaxKind::NotExistsFunc);
 }
+const RECOVERY_String: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [135] String -> 'STRING_LITERAL1' | 'STRING_LITERAL2' | 'STRING_LITERAL_LONG1' | 'STRING_LITERAL_LONG2'
 pub(super) fn parse_String(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_String);
     match p.nth(0) {
         SyntaxKind::STRING_LITERAL1 => {
             p.expect(SyntaxKind::STRING_LITERAL1);
@@ -4272,20 +5857,31 @@ pub(super) fn parse_String(p: &mut Parser) {
         SyntaxKind::STRING_LITERAL_LONG2 => {
             p.expect(SyntaxKind::STRING_LITERAL_LONG2);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[
+                SyntaxKind::STRING_LITERAL1,
+                SyntaxKind::STRING_LITERAL2,
+                SyntaxKind::STRING_LITERAL_LONG1,
+                SyntaxKind::STRING_LITERAL_LONG2,
+            ]);
         }
     };
     p.close(marker, SyntaxKind::String);
 }
+const RECOVERY_NumericLiteralUnsigned: TokenSet = TokenSet::new(&[
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [136] NumericLiteralUnsigned -> 'INTEGER' | 'DECIMAL' | 'DOUBLE'
 pub(super) fn parse_NumericLiteralUnsigned(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_NumericLiteralUnsigned);
     match p.nth(0) {
         SyntaxKind::INTEGER => {
             p.expect(SyntaxKind::INTEGER);
@@ -4296,20 +5892,36 @@ pub(super) fn parse_NumericLiteralUnsigned(p: &mut Parser) {
         SyntaxKind::DOUBLE => {
             p.expect(SyntaxKind::DOUBLE);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[SyntaxKind::INTEGER, SyntaxKind::DECIMAL, SyntaxKind::DOUBLE]);
         }
     };
     p.close(marker, SyntaxKind::NumericLiteralUnsigned);
 }
+const RECOVERY_PrefixedName: TokenSet = TokenSet::new(&[
+    SyntaxKind::WHERE,
+    SyntaxKind::FROM,
+    SyntaxKind::GROUP,
+    SyntaxKind::HAVING,
+    SyntaxKind::ORDER,
+    SyntaxKind::LIMIT,
+    SyntaxKind::OFFSET,
+    SyntaxKind::VALUES,
+    SyntaxKind::Semicolon,
+    SyntaxKind::DELETE,
+    SyntaxKind::INSERT,
+    SyntaxKind::USING,
+    SyntaxKind::Dot,
+    SyntaxKind::OPTIONAL,
+    SyntaxKind::SERVICE,
+    SyntaxKind::BIND,
+    SyntaxKind::MINUS,
+    SyntaxKind::FILTER,
+    SyntaxKind::Comma,
+]);
 /// [137] PrefixedName -> 'PNAME_LN' | 'PNAME_NS'
 pub(super) fn parse_PrefixedName(p: &mut Parser) {
-    let marker = p.open();
+    let marker = p.open_rule(RECOVERY_PrefixedName);
     match p.nth(0) {
         SyntaxKind::PNAME_LN => {
             p.expect(SyntaxKind::PNAME_LN);
@@ -4317,13 +5929,8 @@ pub(super) fn parse_PrefixedName(p: &mut Parser) {
         SyntaxKind::PNAME_NS => {
             p.expect(SyntaxKind::PNAME_NS);
         }
-        SyntaxKind::Eof => {
-            eprintln!("Unexpected Eof");
-            p.close(marker, SyntaxKind::Error);
-            return;
-        }
         _ => {
-            p.advance_with_error("Expected ....");
+            p.err_recover(&[SyntaxKind::PNAME_NS, SyntaxKind::PNAME_LN]);
         }
     };
     p.close(marker, SyntaxKind::PrefixedName);
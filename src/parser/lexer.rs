@@ -0,0 +1,390 @@
+use std::{fmt, iter, vec};
+
+use logos::{Lexer, Logos};
+use rowan::{TextRange, TextSize};
+
+use crate::SyntaxKind;
+
+use super::escape::{self, OffsetMap};
+
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Token {
+    pub(super) kind: SyntaxKind,
+    pub(super) range: TextRange,
+}
+
+impl Token {
+    pub(super) fn is_trivia(&self) -> bool {
+        self.kind.is_trivia()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LexErrorKind {
+    /// A string literal that is not closed before the end of the line,
+    /// or, for long strings, before the end of the input.
+    UnterminatedString,
+    /// A backslash in a string literal that does not start an ECHAR.
+    InvalidEscape,
+    /// An IRI containing a character that IRIREF excludes.
+    InvalidIri,
+    /// Control characters outside of string literals.
+    ControlCharacter,
+    /// Anything else that does not start a token.
+    UnexpectedCharacters,
+}
+
+impl fmt::Display for LexErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            LexErrorKind::UnterminatedString => "unterminated string literal",
+            LexErrorKind::InvalidEscape => "invalid escape sequence",
+            LexErrorKind::InvalidIri => "invalid character in IRI",
+            LexErrorKind::ControlCharacter => "unexpected control character",
+            LexErrorKind::UnexpectedCharacters => "unexpected characters",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LexError {
+    pub kind: LexErrorKind,
+    pub range: TextRange,
+}
+
+/// A streaming tokenizer over a SPARQL document.
+///
+/// Yields the kind and range of every token, including trivia, without copying the text.
+/// Codepoint escapes are processed first, ranges always point into the original input.
+/// Lexer errors are collected while iterating and can be read with [`Tokenizer::errors`].
+pub struct Tokenizer<'a> {
+    raw: RawTokens<'a>,
+    map: Option<OffsetMap>,
+    peeked: Option<RawToken>,
+    errors: Vec<LexError>,
+}
+
+/// Where a [`Tokenizer`] takes its tokens from.
+enum RawTokens<'a> {
+    /// A lexer that advances over the input as tokens are requested.
+    Lexer(Lexer<'a, SyntaxKind>),
+    /// The tokens of the unescaped copy of an input with codepoint escapes.
+    /// The tokenizer can not borrow the copy, so it is lexed at once.
+    Unescaped(vec::IntoIter<RawToken>),
+}
+
+/// A token before garbage merging, with ranges relative to the unescaped source.
+struct RawToken {
+    kind: SyntaxKind,
+    range: TextRange,
+    errors: Vec<LexError>,
+}
+
+impl RawToken {
+    /// Returns the error kind if this token is garbage that can be merged with its neighbours.
+    fn garbage(&self) -> Option<LexErrorKind> {
+        match (self.kind, &self.errors[..]) {
+            (
+                SyntaxKind::Error,
+                [LexError {
+                    kind:
+                        kind @ (LexErrorKind::UnexpectedCharacters | LexErrorKind::ControlCharacter),
+                    ..
+                }],
+            ) => Some(*kind),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> Tokenizer<'a> {
+    pub fn new(text: &'a str) -> Self {
+        let (raw, map) = match escape::unescape(text) {
+            Some((unescaped, map)) => {
+                let mut lexer = SyntaxKind::lexer(&unescaped);
+                let tokens: Vec<_> = iter::from_fn(|| next_raw(&mut lexer)).collect();
+                (RawTokens::Unescaped(tokens.into_iter()), Some(map))
+            }
+            None => (RawTokens::Lexer(SyntaxKind::lexer(text)), None),
+        };
+        Self {
+            raw,
+            map,
+            peeked: None,
+            errors: Vec::new(),
+        }
+    }
+
+    /// The errors of all tokens yielded so far.
+    pub fn errors(&self) -> &[LexError] {
+        &self.errors
+    }
+
+    pub fn into_errors(self) -> Vec<LexError> {
+        self.errors
+    }
+
+    fn next_raw(&mut self) -> Option<RawToken> {
+        match &mut self.raw {
+            RawTokens::Lexer(lexer) => next_raw(lexer),
+            RawTokens::Unescaped(tokens) => tokens.next(),
+        }
+    }
+
+    fn original_range(&self, range: TextRange) -> TextRange {
+        match &self.map {
+            Some(map) => map.original_range(range),
+            None => range,
+        }
+    }
+}
+
+impl Iterator for Tokenizer<'_> {
+    type Item = (SyntaxKind, TextRange);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut token = self.peeked.take().or_else(|| self.next_raw())?;
+        // Merge consecutive garbage into a single error token.
+        if let Some(garbage) = token.garbage() {
+            while let Some(next) = self.next_raw() {
+                if next.garbage() != Some(garbage) {
+                    self.peeked = Some(next);
+                    break;
+                }
+                token.range = token.range.cover(next.range);
+                token.errors[0].range = token.range;
+            }
+        }
+        for mut error in token.errors {
+            error.range = self.original_range(error.range);
+            self.errors.push(error);
+        }
+        Some((token.kind, self.original_range(token.range)))
+    }
+}
+
+/// Lexes the next token, classifying input that logos could not match.
+fn next_raw(lexer: &mut Lexer<SyntaxKind>) -> Option<RawToken> {
+    let result = lexer.next()?;
+    let span = lexer.span();
+    let (kind, len, mut errors) = match result {
+        Ok(kind) => (kind, span.len(), Vec::new()),
+        Err(()) => classify(&lexer.source()[span.start..], span.len()),
+    };
+    match len.checked_sub(span.len()) {
+        Some(rest) => lexer.bump(rest),
+        // The error token ends before logos gave up, lexing resumes at its end.
+        None => {
+            *lexer = SyntaxKind::lexer(lexer.source());
+            lexer.bump(span.start + len);
+        }
+    }
+    let start = offset(span.start);
+    errors.iter_mut().for_each(|error| error.range += start);
+    Some(RawToken {
+        kind,
+        range: TextRange::at(start, offset(len)),
+        errors,
+    })
+}
+
+/// The tokens of a document with ranges into its text.
+#[derive(Debug, Default)]
+pub(super) struct Lexed {
+    /// The tokens the parser reads: all tokens but trivia, where the two words of
+    /// `INSERT DATA`, `DELETE DATA` and `DELETE WHERE` are joined into one token.
+    ///
+    /// `INSERT DATA` starts a different update operation than `INSERT {`, so the parser
+    /// needs to see both words at once. The tree keeps the words and the trivia between them.
+    pub(super) input: Vec<Token>,
+    pub(super) trivia: Vec<Token>,
+    pub(super) errors: Vec<LexError>,
+}
+
+/// Lexes `text` in a single pass.
+pub(super) fn lex(text: &str) -> Lexed {
+    let mut tokenizer = Tokenizer::new(text);
+    let mut lexed = Lexed::default();
+    for (kind, range) in tokenizer.by_ref() {
+        let token = Token { kind, range };
+        if token.is_trivia() {
+            lexed.trivia.push(token);
+            continue;
+        }
+        if let Some(last) = lexed.input.last_mut() {
+            if let Some(kind) = compound_keyword(last.kind, kind) {
+                if last.range.end() < range.start() {
+                    *last = Token {
+                        kind,
+                        range: last.range.cover(range),
+                    };
+                    continue;
+                }
+            }
+        }
+        lexed.input.push(token);
+    }
+    lexed.errors = tokenizer.into_errors();
+    lexed
+}
+
+fn compound_keyword(first: SyntaxKind, second: SyntaxKind) -> Option<SyntaxKind> {
+    match (first, second) {
+        (SyntaxKind::INSERT, SyntaxKind::DATA) => Some(SyntaxKind::INSERT_DATA),
+        (SyntaxKind::DELETE, SyntaxKind::DATA) => Some(SyntaxKind::DELETE_DATA),
+        (SyntaxKind::DELETE, SyntaxKind::WHERE) => Some(SyntaxKind::DELETE_WHERE),
+        _ => None,
+    }
+}
+
+/// The two words of a token that [`lex`] joined.
+pub(super) fn compound_keyword_words(kind: SyntaxKind) -> Option<(SyntaxKind, SyntaxKind)> {
+    match kind {
+        SyntaxKind::INSERT_DATA => Some((SyntaxKind::INSERT, SyntaxKind::DATA)),
+        SyntaxKind::DELETE_DATA => Some((SyntaxKind::DELETE, SyntaxKind::DATA)),
+        SyntaxKind::DELETE_WHERE => Some((SyntaxKind::DELETE, SyntaxKind::WHERE)),
+        _ => None,
+    }
+}
+
+fn offset(offset: usize) -> TextSize {
+    TextSize::new(offset as u32)
+}
+
+fn range(start: usize, end: usize) -> TextRange {
+    TextRange::new(offset(start), offset(end))
+}
+
+/// Classifies input that logos could not match.
+/// Returns the kind and length of the token to emit together with its errors.
+fn classify(rest: &str, error_len: usize) -> (SyntaxKind, usize, Vec<LexError>) {
+    if let Some((len, forbidden)) = invalid_iri(rest) {
+        let errors = forbidden
+            .into_iter()
+            .map(|range| LexError {
+                kind: LexErrorKind::InvalidIri,
+                range,
+            })
+            .collect();
+        return (SyntaxKind::IRIREF, len, errors);
+    }
+    for (quotes, kind) in [
+        ("'''", SyntaxKind::STRING_LITERAL_LONG1),
+        ("\"\"\"", SyntaxKind::STRING_LITERAL_LONG2),
+        ("'", SyntaxKind::STRING_LITERAL1),
+        ("\"", SyntaxKind::STRING_LITERAL2),
+    ] {
+        if rest.starts_with(quotes) {
+            return string(rest, quotes, kind);
+        }
+    }
+    let control = rest
+        .chars()
+        .take_while(|char| is_control(*char))
+        .map(char::len_utf8)
+        .sum();
+    if control > 0 {
+        return (
+            SyntaxKind::Error,
+            control,
+            vec![LexError {
+                kind: LexErrorKind::ControlCharacter,
+                range: range(0, control),
+            }],
+        );
+    }
+    (
+        SyntaxKind::Error,
+        error_len,
+        vec![LexError {
+            kind: LexErrorKind::UnexpectedCharacters,
+            range: range(0, error_len),
+        }],
+    )
+}
+
+/// Scans a string literal that failed to lex.
+/// It either contains invalid escape sequences or is not terminated.
+fn string(rest: &str, quotes: &str, kind: SyntaxKind) -> (SyntaxKind, usize, Vec<LexError>) {
+    let long = quotes.len() == 3;
+    let mut errors = Vec::new();
+    let mut chars = rest.char_indices().skip(quotes.len());
+    while let Some((index, char)) = chars.next() {
+        match char {
+            '\\' => match chars.next() {
+                Some((_, 't' | 'b' | 'n' | 'r' | 'f' | '\\' | '"' | '\'')) => {}
+                Some((next, escaped)) if long || !matches!(escaped, '\n' | '\r') => {
+                    errors.push(LexError {
+                        kind: LexErrorKind::InvalidEscape,
+                        range: range(index, next + escaped.len_utf8()),
+                    });
+                }
+                _ => {
+                    errors.push(LexError {
+                        kind: LexErrorKind::InvalidEscape,
+                        range: range(index, index + 1),
+                    });
+                    break;
+                }
+            },
+            '\n' | '\r' if !long => return unterminated(index),
+            _ if rest[index..].starts_with(quotes) => {
+                return (kind, index + quotes.len(), errors);
+            }
+            _ => {}
+        }
+    }
+    unterminated(
+        rest.find(|char| !long && matches!(char, '\n' | '\r'))
+            .unwrap_or(rest.len()),
+    )
+}
+
+fn unterminated(len: usize) -> (SyntaxKind, usize, Vec<LexError>) {
+    (
+        SyntaxKind::Error,
+        len,
+        vec![LexError {
+            kind: LexErrorKind::UnterminatedString,
+            range: range(0, len),
+        }],
+    )
+}
+
+/// Detects IRIs with forbidden characters, which logos can not match as IRIREF.
+/// Only text that starts like an absolute IRI or a path is treated as an IRI,
+/// so that comparisons such as `?a < 3 && ?b > 2` still lex as operators.
+/// Returns the length of the IRI and the ranges of the forbidden characters.
+fn invalid_iri(rest: &str) -> Option<(usize, Vec<TextRange>)> {
+    if !rest.starts_with('<') {
+        return None;
+    }
+    let end = rest[1..].find(['<', '>', '\n', '\r'])? + 1;
+    if !rest[end..].starts_with('>') {
+        return None;
+    }
+    let iri = &rest[1..end];
+    let scheme = iri.find(':').is_some_and(|colon| {
+        let mut chars = iri[..colon].chars();
+        chars.next().is_some_and(|char| char.is_ascii_alphabetic())
+            && chars.all(|char| char.is_ascii_alphanumeric() || matches!(char, '+' | '-' | '.'))
+    });
+    if !(scheme || iri.starts_with(['/', '#'])) {
+        return None;
+    }
+    let forbidden = iri
+        .char_indices()
+        .filter(|(_, char)| {
+            matches!(
+                char,
+                '"' | '{' | '}' | '|' | '^' | '`' | '\\' | '\u{00}'..='\u{20}'
+            )
+        })
+        .map(|(index, char)| range(1 + index, 1 + index + char.len_utf8()))
+        .collect();
+    Some((end + 1, forbidden))
+}
+
+fn is_control(char: char) -> bool {
+    char.is_control() && !matches!(char, ' ' | '\t' | '\r' | '\n' | '\u{0C}')
+}
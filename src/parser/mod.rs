@@ -1,204 +1,820 @@
+mod brackets;
+mod collapse;
+mod diagnostic;
+mod escape;
+mod expressions;
 mod grammar;
+mod lexer;
+mod reparsing;
+mod token_set;
 
-use std::cell::Cell;
+use std::mem;
 
-use crate::SyntaxKind;
-use grammar::{parse_QueryUnit, parse_UpdateUnit};
-use logos::Logos;
-use rowan::{GreenNode, GreenNodeBuilder};
+use crate::{SyntaxKind, SyntaxNode};
+use brackets::{is_closing, is_opening, Brackets};
+pub use collapse::WRAPPER_KINDS;
+pub use diagnostic::{Diagnostic, Severity};
+pub(crate) use escape::unescape;
+use lexer::{compound_keyword_words, lex, Lexed, Token};
+pub use lexer::{LexError, LexErrorKind, Tokenizer};
+pub use reparsing::TextEdit;
+use rowan::{GreenNode, GreenNodeBuilder, NodeCache, TextRange, TextSize};
+use token_set::TokenSet;
 
-pub struct Parser {
-    tokens: Vec<Token>,
+/// Lookaheads the parser may take without consuming a token.
+const FUEL: u32 = 256;
+
+/// Settings that limit what the parser does with its input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// The number of brackets that may be open at once.
+    ///
+    /// Every bracket group is parsed by a recursive call, so without a limit deeply
+    /// nested input overflows the stack. The contents of a group past the limit are
+    /// not parsed, they are wrapped into an error node and reported.
+    pub max_nesting: usize,
+    /// Whether expressions are parsed into compact `BinaryExpr`, `UnaryExpr` and `InExpr`
+    /// nodes instead of the precedence cascade of the grammar, which wraps even a single
+    /// variable into ten nodes from `ConditionalOrExpression` down to `PrimaryExpression`.
+    pub compact_expressions: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            max_nesting: 128,
+            compact_expressions: false,
+        }
+    }
+}
+
+pub struct Parser<'t> {
+    tokens: &'t [Token],
     pos: usize,
-    fuel: Cell<u32>,
+    fuel: u32,
     events: Vec<Event>,
+    diagnostics: Vec<Diagnostic>,
+    end: TextSize,
+    /// Recovery sets of the rules currently being parsed, innermost last.
+    recovery: Vec<TokenSet>,
+    /// Position of the last reported error, errors at the same token are not reported twice.
+    last_error: Option<usize>,
+    brackets: Brackets,
+    options: ParseOptions,
+    /// Number of brackets that are open before the input, when a part of a document is reparsed.
+    outer_nesting: usize,
 }
 
+/// The result of parsing a document: the lossless syntax tree and everything
+/// that went wrong while building it.
 #[derive(Debug, Clone)]
-struct Token {
-    kind: SyntaxKind,
-    text: std::string::String,
+pub struct Parse {
+    green: GreenNode,
+    diagnostics: Vec<Diagnostic>,
+    /// Whether the entry point was detected from the input, see [`parse_document`].
+    detected: bool,
+    options: ParseOptions,
 }
 
-impl Token {
-    fn is_trivia(&self) -> bool {
-        match self.kind {
-            SyntaxKind::WHITESPACE => true,
-            _ => false,
+/// Whether a document is a query or an update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DocumentKind {
+    Query,
+    Update,
+    /// The document has no body after its prologue, or the body starts with
+    /// something that is neither a query nor an update.
+    Unknown,
+    /// The tree was parsed from an entry point of a part of a document, like `GroupGraphPattern`.
+    Fragment,
+}
+
+impl Parse {
+    pub fn green_node(&self) -> &GreenNode {
+        &self.green
+    }
+
+    pub fn syntax(&self) -> SyntaxNode {
+        SyntaxNode::new_root(self.green.clone())
+    }
+
+    /// The tree with the nodes of the kinds in `wrappers` elided where they have a single
+    /// child node, e.g. [`WRAPPER_KINDS`]. The full tree stays available from [`Parse::syntax`].
+    pub fn collapsed(&self, wrappers: &[SyntaxKind]) -> SyntaxNode {
+        SyntaxNode::new_root(collapse::collapse(&self.green, wrappers))
+    }
+
+    /// Lexer and parser diagnostics, ordered by their position.
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    /// Whether the tree is a `QueryUnit`, an `UpdateUnit`, neither or a fragment.
+    ///
+    /// A document whose kind could not be detected is parsed as a `Prologue`.
+    pub fn document_kind(&self) -> DocumentKind {
+        match self.syntax().kind() {
+            SyntaxKind::QueryUnit => DocumentKind::Query,
+            SyntaxKind::UpdateUnit => DocumentKind::Update,
+            SyntaxKind::Prologue if self.detected => DocumentKind::Unknown,
+            _ => DocumentKind::Fragment,
         }
     }
+
+    /// Parses the document after `edit`, reusing the unchanged parts of this tree.
+    /// Returns `None` if the edit does not fit the document, see [`TextEdit::apply`].
+    pub fn reparse(&self, edit: &TextEdit) -> Option<Parse> {
+        reparsing::reparse(self, edit)
+    }
+}
+
+pub fn parse_text(input: &str, entry: TopEntryPoint) -> Parse {
+    parse_with_options(input, Some(entry), ParseOptions::default())
+}
+
+/// Parses a document that is either a query or an update.
+///
+/// The entry point is picked by the first token after the prologue.
+/// If there is none, or it can start neither, the document is parsed as a `Prologue`.
+pub fn parse_document(input: &str) -> Parse {
+    parse_with_options(input, None, ParseOptions::default())
+}
+
+/// Parses `input` as `entry`, or like [`parse_document`] if there is no entry point.
+pub fn parse_with_options(
+    input: &str,
+    entry: Option<TopEntryPoint>,
+    options: ParseOptions,
+) -> Parse {
+    parse_with_cache(input, entry, options, &mut NodeCache::default())
+}
+
+/// Like [`parse_with_options`], but the tree is built with `cache`.
+///
+/// Identical subtrees of all documents that are parsed with the same cache are stored once.
+pub fn parse_with_cache(
+    input: &str,
+    entry: Option<TopEntryPoint>,
+    options: ParseOptions,
+    cache: &mut NodeCache,
+) -> Parse {
+    let lexed = lex(input);
+    let detected = entry.is_none();
+    let entry = entry.unwrap_or_else(|| detect(&lexed.input));
+    Parse {
+        detected,
+        ..parse_tokens(input, lexed, entry, options, cache)
+    }
+}
+
+fn detect(tokens: &[Token]) -> TopEntryPoint {
+    let mut kinds = tokens.iter().map(|token| token.kind).peekable();
+    // Prologue ::= ( 'BASE' IRIREF | 'PREFIX' PNAME_NS IRIREF )*
+    while let Some(SyntaxKind::BASE | SyntaxKind::PREFIX) = kinds.peek() {
+        kinds.next();
+        kinds.next_if_eq(&SyntaxKind::PNAME_NS);
+        kinds.next_if_eq(&SyntaxKind::IRIREF);
+    }
+    match kinds.next() {
+        Some(
+            SyntaxKind::SELECT | SyntaxKind::CONSTRUCT | SyntaxKind::DESCRIBE | SyntaxKind::ASK,
+        ) => TopEntryPoint::QueryUnit,
+        Some(
+            SyntaxKind::INSERT
+            | SyntaxKind::DELETE
+            | SyntaxKind::INSERT_DATA
+            | SyntaxKind::DELETE_DATA
+            | SyntaxKind::DELETE_WHERE
+            | SyntaxKind::WITH
+            | SyntaxKind::LOAD
+            | SyntaxKind::CLEAR
+            | SyntaxKind::DROP
+            | SyntaxKind::CREATE
+            | SyntaxKind::ADD
+            | SyntaxKind::MOVE
+            | SyntaxKind::COPY,
+        ) => TopEntryPoint::UpdateUnit,
+        _ => TopEntryPoint::Prologue,
+    }
 }
 
-pub fn parse_text(input: &str, entry: TopEntryPoint) -> GreenNode {
-    let tokens = lex(input);
-    let parse_input = tokens
-        .iter()
-        .filter(|token| !token.is_trivia())
-        .cloned()
-        .collect();
-    let output = entry.parse(parse_input);
-    build_tree(tokens, output)
+fn parse_tokens(
+    input: &str,
+    lexed: Lexed,
+    entry: TopEntryPoint,
+    options: ParseOptions,
+    cache: &mut NodeCache,
+) -> Parse {
+    let (events, parse_diagnostics) = entry.parse(&lexed.input, TextSize::of(input), options);
+    let mut diagnostics: Vec<Diagnostic> = lexed.errors.into_iter().map(Diagnostic::from).collect();
+    diagnostics.extend(parse_diagnostics);
+    diagnostics.sort_by_key(|diagnostic| diagnostic.range.start());
+    Parse {
+        green: build_tree(input, &lexed.input, &lexed.trivia, events, cache),
+        diagnostics,
+        detected: false,
+        options,
+    }
 }
 
-fn build_tree(tokens: Vec<Token>, events: Vec<Event>) -> GreenNode {
-    let mut tokens = tokens.into_iter().peekable();
-    let mut builder = GreenNodeBuilder::new();
+/// Builds the tree from the parser `events` over `input`, adding the `trivia` in between.
+///
+/// A token of the input may enclose trivia, see [`Lexed::input`].
+fn build_tree(
+    text: &str,
+    input: &[Token],
+    trivia: &[Token],
+    mut events: Vec<Event>,
+    cache: &mut NodeCache,
+) -> GreenNode {
+    let mut input = input.iter();
+    let mut trivia = trivia.iter().peekable();
+    // The end of the sign that was split off the next token.
+    let mut sign_end = None;
+    let mut builder = GreenNodeBuilder::with_cache(cache);
 
     // Special case: pop the last `Close` event to ensure
     // that the stack is non-empty inside the loop.
     // assert!(matches!(events.pop(), Some(Event::Close)));
-    for event in &events[..events.len() - 1] {
-        match event {
-            Event::Open { kind } => {
-                while *kind != SyntaxKind::QueryUnit
-                    && tokens.peek().map_or(false, |next| next.is_trivia())
-                {
-                    let token = tokens.next().unwrap();
-                    builder.token(token.kind.into(), &token.text);
+    for index in 0..events.len() - 1 {
+        match mem::replace(&mut events[index], Event::Tombstone) {
+            Event::Open {
+                kind,
+                forward_parent,
+            } => {
+                // Leading trivia belongs into the root node.
+                let next = input.as_slice().first();
+                while let Some(token) = trivia.next_if(|token| {
+                    index > 0 && next.is_none_or(|next| token.range.start() < next.range.start())
+                }) {
+                    builder.token(token.kind.into(), &text[token.range]);
+                }
+                if forward_parent.is_none() {
+                    builder.start_node(kind.into());
+                    continue;
+                }
+                // Nodes that were opened later around this one start before it, outermost first.
+                let mut kinds = vec![kind];
+                let (mut parent, mut forward_parent) = (index, forward_parent);
+                while let Some(distance) = forward_parent {
+                    parent += distance;
+                    let Event::Open {
+                        kind,
+                        forward_parent: next,
+                    } = mem::replace(&mut events[parent], Event::Tombstone)
+                    else {
+                        unreachable!("a forward parent is an open event");
+                    };
+                    kinds.push(kind);
+                    forward_parent = next;
+                }
+                for kind in kinds.into_iter().rev() {
+                    builder.start_node(kind.into());
                 }
-                builder.start_node((*kind).into());
             }
             Event::Close => {
                 builder.finish_node();
             }
 
+            Event::Tombstone => {}
+            Event::Sign => {
+                let token = input.as_slice().first().unwrap();
+                while let Some(trivia) =
+                    trivia.next_if(|trivia| trivia.range.start() < token.range.start())
+                {
+                    builder.token(trivia.kind.into(), &text[trivia.range]);
+                }
+                let (sign, _) = expressions::split_sign(token.kind).unwrap();
+                // The sign may be written as a codepoint escape.
+                let start = token.range.start();
+                let len =
+                    escape::codepoint_escape(&text[usize::from(start)..]).map_or(1, |(_, len)| len);
+                let end = start + TextSize::new(len as u32);
+                builder.token(sign.into(), &text[TextRange::new(start, end)]);
+                sign_end = Some(end);
+            }
             Event::Advance => {
-                while tokens.peek().map_or(false, |next| next.is_trivia()) {
-                    let token = tokens.next().unwrap();
-                    builder.token(token.kind.into(), &token.text);
+                let token = input.next().unwrap();
+                while let Some(trivia) =
+                    trivia.next_if(|trivia| trivia.range.start() < token.range.start())
+                {
+                    builder.token(trivia.kind.into(), &text[trivia.range]);
+                }
+                if let Some(start) = sign_end.take() {
+                    let (_, kind) = expressions::split_sign(token.kind).unwrap();
+                    builder.token(kind.into(), &text[TextRange::new(start, token.range.end())]);
+                    continue;
                 }
-                let token = tokens.next().unwrap();
-                builder.token(token.kind.into(), &token.text);
+                let Some((first, second)) = compound_keyword_words(token.kind) else {
+                    builder.token(token.kind.into(), &text[token.range]);
+                    continue;
+                };
+                // The first word ends where the trivia between the words starts.
+                let mut end = trivia
+                    .peek()
+                    .expect("the words of a joined keyword are separated by trivia")
+                    .range
+                    .start();
+                builder.token(
+                    first.into(),
+                    &text[TextRange::new(token.range.start(), end)],
+                );
+                while let Some(trivia) =
+                    trivia.next_if(|trivia| trivia.range.end() <= token.range.end())
+                {
+                    builder.token(trivia.kind.into(), &text[trivia.range]);
+                    end = trivia.range.end();
+                }
+                builder.token(second.into(), &text[TextRange::new(end, token.range.end())]);
             }
         }
     }
     // Eat trailing trivia tokens
     assert!(matches!(events.last(), Some(Event::Close)));
-    while tokens.peek().map_or(false, |next| next.is_trivia()) {
-        let token = tokens.next().unwrap();
-        builder.token(token.kind.into(), &token.text);
+    for token in trivia {
+        builder.token(token.kind.into(), &text[token.range]);
     }
     builder.finish_node();
     builder.finish()
 }
 
-impl Parser {
-    fn new(input: Vec<Token>) -> Self {
+impl<'t> Parser<'t> {
+    fn new(input: &'t [Token], end: TextSize, options: ParseOptions) -> Self {
+        let brackets = Brackets::new(input);
         Self {
             tokens: input,
             pos: 0,
-            fuel: 256.into(),
+            fuel: FUEL,
             events: Vec::new(),
+            diagnostics: Vec::new(),
+            end,
+            recovery: Vec::new(),
+            last_error: None,
+            brackets,
+            options,
+            outer_nesting: 0,
         }
     }
 }
 
 enum Event {
-    Open { kind: SyntaxKind },
+    Open {
+        kind: SyntaxKind,
+        /// Distance to the `Open` event of a node that encloses this one,
+        /// but was opened after it, see [`Parser::open_before`].
+        forward_parent: Option<usize>,
+    },
     Close,
     Advance,
+    /// The sign of the current token, a signed numeric literal, as a token of its own.
+    /// The next `Advance` emits the literal without its sign.
+    Sign,
+    /// An `Open` event that was already handled as a forward parent.
+    Tombstone,
 }
 
 struct MarkOpened {
     index: usize,
+    /// Number of diagnostics before the node was opened.
+    /// Diagnostics reported inside the node get its kind as their rule.
+    diagnostics: usize,
+    /// Depth of the recovery stack before the node was opened.
+    recovery: usize,
 }
 
-impl Parser {
+/// A node that is completely parsed.
+struct MarkClosed {
+    index: usize,
+}
+
+impl Parser<'_> {
     fn open(&mut self) -> MarkOpened {
         let mark = MarkOpened {
             index: self.events.len(),
+            diagnostics: self.diagnostics.len(),
+            recovery: self.recovery.len(),
         };
         self.events.push(Event::Open {
             kind: SyntaxKind::Error,
+            forward_parent: None,
         });
         mark
     }
 
-    fn close(&mut self, m: MarkOpened, kind: SyntaxKind) {
-        self.events[m.index] = Event::Open { kind };
+    /// Opens a node that encloses the node `m`, which is already closed.
+    fn open_before(&mut self, m: MarkClosed) -> MarkOpened {
+        let mark = self.open();
+        let Event::Open { forward_parent, .. } = &mut self.events[m.index] else {
+            unreachable!("a closed node starts with an open event");
+        };
+        *forward_parent = Some(mark.index - m.index);
+        mark
+    }
+
+    /// Opens a node of a rule whose parsing may stop at the tokens in `recovery`.
+    ///
+    /// Error recovery inside the node never skips a token of an enclosing recovery set.
+    fn open_rule(&mut self, recovery: TokenSet) -> MarkOpened {
+        let m = self.open();
+        self.recovery.push(recovery);
+        m
+    }
+
+    fn close(&mut self, m: MarkOpened, kind: SyntaxKind) -> MarkClosed {
+        self.recovery.truncate(m.recovery);
+        self.assign_rule(&m, kind);
+        let Event::Open { kind: open, .. } = &mut self.events[m.index] else {
+            unreachable!("a node starts with an open event");
+        };
+        *open = kind;
         self.events.push(Event::Close);
+        MarkClosed { index: m.index }
+    }
+
+    fn assign_rule(&mut self, m: &MarkOpened, rule: SyntaxKind) {
+        if rule == SyntaxKind::Error {
+            return;
+        }
+        for diagnostic in &mut self.diagnostics[m.diagnostics..] {
+            diagnostic.rule.get_or_insert(rule);
+        }
     }
 
     fn advance(&mut self) {
         assert!(!self.eof());
-        self.fuel.set(256);
+        self.fuel = FUEL;
+        self.brackets.advance(self.pos, self.tokens[self.pos].kind);
         self.events.push(Event::Advance);
         self.pos += 1;
     }
 
+    /// Consumes the current token, or the whole bracket group it opens.
+    fn skip(&mut self) {
+        let end = self.next_group(self.pos);
+        while self.pos < end {
+            self.advance();
+        }
+    }
+
+    /// The position after the token at `pos`, or after the bracket group it opens.
+    fn next_group(&self, pos: usize) -> usize {
+        match self.brackets.matching(pos).filter(|close| *close > pos) {
+            Some(close) => close + 1,
+            None => pos + 1,
+        }
+    }
+
     fn eof(&self) -> bool {
         self.pos == self.tokens.len()
     }
 
-    fn nth(&self, lookahead: usize) -> SyntaxKind {
-        if self.fuel.get() == 0 {
-            panic!("parser is stuck")
+    /// Looks at the token `lookahead` positions ahead.
+    ///
+    /// Every lookahead spends fuel, which is refilled whenever a token is consumed.
+    /// If the fuel runs out, the parser did not make progress for too long.
+    /// The current token is then wrapped in an error node, which guarantees
+    /// termination without aborting the whole parse.
+    fn nth(&mut self, lookahead: usize) -> SyntaxKind {
+        if self.fuel == 0 {
+            self.fuel = FUEL;
+            if !self.eof() {
+                self.advance_with_error(&[]);
+            }
         }
-        self.fuel.set(self.fuel.get() - 1);
+        self.fuel -= 1;
         self.tokens
             .get(self.pos + lookahead)
             .map_or(SyntaxKind::Eof, |it| it.kind)
     }
 
-    fn at(&self, kind: SyntaxKind) -> bool {
+    fn at(&mut self, kind: SyntaxKind) -> bool {
         self.nth(0) == kind
     }
 
-    fn at_any(&self, kinds: &[SyntaxKind]) -> bool {
-        kinds.iter().any(|kind| self.at(*kind))
+    fn at_any(&mut self, kinds: TokenSet) -> bool {
+        kinds.contains(self.nth(0))
     }
 
     fn eat(&mut self, kind: SyntaxKind) -> bool {
         if self.at(kind) {
             self.advance();
+            self.limit_nesting();
             true
         } else {
             false
         }
     }
 
+    /// Consumes a token of `kind`.
+    ///
+    /// If it is missing, an error is reported. When `kind` follows before the next
+    /// recovery token, the tokens in between are skipped into an error node,
+    /// otherwise `kind` is treated as missing and nothing is consumed.
     fn expect(&mut self, kind: SyntaxKind) {
         if self.eat(kind) {
             return;
         }
-        // TODO: Error reporting.
-        eprintln!("expected {kind:?}");
+        self.error(&[kind]);
+        let mut pos = self.pos;
+        while pos < self.tokens.len() && !self.stops_at(pos, &[kind]) {
+            pos = self.next_group(pos);
+        }
+        if self.tokens.get(pos).is_some_and(|token| token.kind == kind) {
+            self.skip_until(&[kind]);
+            self.advance();
+            self.limit_nesting();
+        } else {
+            self.missing();
+        }
+    }
+
+    /// Skips the contents of the bracket group that was just opened if it is nested too deep.
+    ///
+    /// The contents are wrapped into an error node without descending into them,
+    /// the rule of the group then finds its closing bracket right away.
+    fn limit_nesting(&mut self) {
+        let opener = self.tokens[self.pos - 1];
+        if !is_opening(opener.kind)
+            || self.outer_nesting + self.brackets.depth() <= self.options.max_nesting
+        {
+            return;
+        }
+        self.diagnostics
+            .push(Diagnostic::too_deep(opener.range, self.options.max_nesting));
+        if !self.eof() && !self.brackets.closes_open(self.pos) {
+            let m = self.open();
+            while !self.eof() && !self.brackets.closes_open(self.pos) {
+                self.skip();
+            }
+            self.close(m, SyntaxKind::Error);
+        }
+        // The rules inside the group would only report consequences of the skipped contents.
+        self.last_error = Some(self.pos);
+    }
+
+    /// Reports that the current token is not one of `expected` and skips tokens
+    /// into an error node until a recovery token of an enclosing rule is reached.
+    fn err_recover(&mut self, expected: &[SyntaxKind]) {
+        self.error(expected);
+        self.missing();
+        self.skip_until(&[]);
+    }
+
+    /// Marks the current position as the place of an absent token.
+    /// The kinds that were expected are part of the diagnostic reported there.
+    fn missing(&mut self) {
+        let m = self.open();
+        self.close(m, SyntaxKind::Missing);
+    }
+
+    /// Wraps the tokens before the next recovery token or `extra` into an error node.
+    fn skip_until(&mut self, extra: &[SyntaxKind]) {
+        if self.nth(0) == SyntaxKind::Eof || self.stops_at(self.pos, extra) {
+            return;
+        }
+        let m = self.open();
+        while !self.eof() && !self.stops_at(self.pos, extra) {
+            self.skip();
+        }
+        self.close(m, SyntaxKind::Error);
+    }
+
+    /// Recovers from junk inside a group delimited by curly braces.
+    ///
+    /// Returns `true` when the parser is at a token in `first` and the contents
+    /// of the group should be parsed again, `false` once the closing brace or a
+    /// recovery token that ends the group is reached.
+    /// Separators do not end the group, they belong to the contents.
+    fn recover_in_braces(&mut self, first: TokenSet) -> bool {
+        loop {
+            let kind = self.nth(0);
+            if kind == SyntaxKind::Eof || !first.contains(kind) && self.ends_group(self.pos) {
+                return false;
+            }
+            self.error(&[SyntaxKind::RCurly]);
+            if first.contains(kind) {
+                return true;
+            }
+            let m = self.open();
+            while let Some(token) = self.tokens.get(self.pos) {
+                if first.contains(token.kind) || self.ends_group(self.pos) {
+                    break;
+                }
+                self.skip();
+            }
+            self.close(m, SyntaxKind::Error);
+        }
+    }
+
+    fn ends_group(&self, pos: usize) -> bool {
+        !matches!(
+            self.tokens[pos].kind,
+            SyntaxKind::Dot | SyntaxKind::Semicolon | SyntaxKind::Comma
+        ) && self.stops_at(pos, &[])
+    }
+
+    /// Whether error recovery stops at the token at `pos`.
+    ///
+    /// A closing bracket stops recovery if it closes a bracket the parser is inside of,
+    /// any other token if it is in `extra` or in the recovery set of an enclosing rule.
+    fn stops_at(&self, pos: usize, extra: &[SyntaxKind]) -> bool {
+        let kind = self.tokens[pos].kind;
+        if is_closing(kind) {
+            return self.brackets.closes_open(pos);
+        }
+        extra.contains(&kind) || self.recovery.iter().any(|set| set.contains(kind))
+    }
+
+    /// Reports that the current token is not one of `expected`.
+    ///
+    /// Only the first error at a token is reported, the errors of the enclosing
+    /// rules at the same position are consequences of it.
+    fn error(&mut self, expected: &[SyntaxKind]) {
+        if self.last_error == Some(self.pos) {
+            return;
+        }
+        self.last_error = Some(self.pos);
+        // Unbalanced brackets are reported once, by the bracket matching.
+        if self
+            .tokens
+            .get(self.pos)
+            .is_some_and(|token| self.brackets.is_unbalanced(self.pos, token.kind))
+        {
+            return;
+        }
+        if let [closer] = expected {
+            if is_closing(*closer) && self.brackets.innermost_unclosed(*closer) {
+                return;
+            }
+        }
+        let (found, range) = match self.tokens.get(self.pos) {
+            Some(token) => (token.kind, token.range),
+            None => (SyntaxKind::Eof, TextRange::empty(self.end)),
+        };
+        self.diagnostics
+            .push(Diagnostic::expected(range, expected, found));
+    }
+
+    /// Wraps the tokens left after the entry rule into an error node inside the root.
+    fn finish(mut self) -> (Vec<Event>, Vec<Diagnostic>) {
+        if !self.eof() {
+            let root_close = self.events.pop();
+            debug_assert!(matches!(root_close, Some(Event::Close)));
+            self.error(&[]);
+            let m = self.open();
+            while !self.eof() {
+                self.advance();
+            }
+            self.close(m, SyntaxKind::Error);
+            self.events.push(Event::Close);
+        }
+        let unbalanced = self.brackets.diagnostics(self.tokens);
+        self.diagnostics.extend(unbalanced);
+        (self.events, self.diagnostics)
     }
 
-    fn advance_with_error(&mut self, error: &str) {
+    fn advance_with_error(&mut self, expected: &[SyntaxKind]) {
         let m = self.open();
-        // TODO: Error reporting.
-        eprintln!("{error}");
+        self.error(expected);
         self.advance();
         self.close(m, SyntaxKind::Error);
     }
 }
 
+/// The nonterminals of the grammar that input can be parsed as.
+///
+/// Besides whole queries and updates, fragments like a single `Expression`,
+/// a property `Path` or an `Iri` can be parsed on their own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TopEntryPoint {
     QueryUnit,
     UpdateUnit,
+    Prologue,
+    SelectClause,
+    WhereClause,
+    SolutionModifier,
+    ValuesClause,
+    DataBlock,
+    GroupGraphPattern,
+    TriplesBlock,
+    TriplesTemplate,
+    ConstructTemplate,
+    QuadData,
+    Update,
+    Expression,
+    Path,
+    Var,
+    VarOrTerm,
+    Iri,
+    RDFLiteral,
+    NumericLiteral,
+    BooleanLiteral,
+    PrefixedName,
+    BlankNode,
 }
 
 impl TopEntryPoint {
-    fn parse(&self, input: Vec<Token>) -> Vec<Event> {
-        let mut parser = Parser::new(input);
+    pub const ALL: &'static [TopEntryPoint] = &[
+        TopEntryPoint::QueryUnit,
+        TopEntryPoint::UpdateUnit,
+        TopEntryPoint::Prologue,
+        TopEntryPoint::SelectClause,
+        TopEntryPoint::WhereClause,
+        TopEntryPoint::SolutionModifier,
+        TopEntryPoint::ValuesClause,
+        TopEntryPoint::DataBlock,
+        TopEntryPoint::GroupGraphPattern,
+        TopEntryPoint::TriplesBlock,
+        TopEntryPoint::TriplesTemplate,
+        TopEntryPoint::ConstructTemplate,
+        TopEntryPoint::QuadData,
+        TopEntryPoint::Update,
+        TopEntryPoint::Expression,
+        TopEntryPoint::Path,
+        TopEntryPoint::Var,
+        TopEntryPoint::VarOrTerm,
+        TopEntryPoint::Iri,
+        TopEntryPoint::RDFLiteral,
+        TopEntryPoint::NumericLiteral,
+        TopEntryPoint::BooleanLiteral,
+        TopEntryPoint::PrefixedName,
+        TopEntryPoint::BlankNode,
+    ];
+
+    /// The kind of the root node of trees parsed from this entry point.
+    pub fn kind(&self) -> SyntaxKind {
         match self {
-            TopEntryPoint::QueryUnit => parse_QueryUnit(&mut parser),
-            TopEntryPoint::UpdateUnit => parse_UpdateUnit(&mut parser),
+            TopEntryPoint::QueryUnit => SyntaxKind::QueryUnit,
+            TopEntryPoint::UpdateUnit => SyntaxKind::UpdateUnit,
+            TopEntryPoint::Prologue => SyntaxKind::Prologue,
+            TopEntryPoint::SelectClause => SyntaxKind::SelectClause,
+            TopEntryPoint::WhereClause => SyntaxKind::WhereClause,
+            TopEntryPoint::SolutionModifier => SyntaxKind::SolutionModifier,
+            TopEntryPoint::ValuesClause => SyntaxKind::ValuesClause,
+            TopEntryPoint::DataBlock => SyntaxKind::DataBlock,
+            TopEntryPoint::GroupGraphPattern => SyntaxKind::GroupGraphPattern,
+            TopEntryPoint::TriplesBlock => SyntaxKind::TriplesBlock,
+            TopEntryPoint::TriplesTemplate => SyntaxKind::TriplesTemplate,
+            TopEntryPoint::ConstructTemplate => SyntaxKind::ConstructTemplate,
+            TopEntryPoint::QuadData => SyntaxKind::QuadData,
+            TopEntryPoint::Update => SyntaxKind::Update,
+            TopEntryPoint::Expression => SyntaxKind::Expression,
+            TopEntryPoint::Path => SyntaxKind::Path,
+            TopEntryPoint::Var => SyntaxKind::Var,
+            TopEntryPoint::VarOrTerm => SyntaxKind::VarOrTerm,
+            TopEntryPoint::Iri => SyntaxKind::iri,
+            TopEntryPoint::RDFLiteral => SyntaxKind::RDFLiteral,
+            TopEntryPoint::NumericLiteral => SyntaxKind::NumericLiteral,
+            TopEntryPoint::BooleanLiteral => SyntaxKind::BooleanLiteral,
+            TopEntryPoint::PrefixedName => SyntaxKind::PrefixedName,
+            TopEntryPoint::BlankNode => SyntaxKind::BlankNode,
         }
-        parser.events
     }
-}
 
-fn lex(text: &str) -> Vec<Token> {
-    let mut lexer = SyntaxKind::lexer(text);
-    let mut tokens = Vec::new();
+    fn rule(&self) -> fn(&mut Parser) {
+        match self {
+            TopEntryPoint::QueryUnit => grammar::parse_QueryUnit,
+            TopEntryPoint::UpdateUnit => grammar::parse_UpdateUnit,
+            TopEntryPoint::Prologue => grammar::parse_Prologue,
+            TopEntryPoint::SelectClause => grammar::parse_SelectClause,
+            TopEntryPoint::WhereClause => grammar::parse_WhereClause,
+            TopEntryPoint::SolutionModifier => grammar::parse_SolutionModifier,
+            TopEntryPoint::ValuesClause => grammar::parse_ValuesClause,
+            TopEntryPoint::DataBlock => grammar::parse_DataBlock,
+            TopEntryPoint::GroupGraphPattern => grammar::parse_GroupGraphPattern,
+            TopEntryPoint::TriplesBlock => grammar::parse_TriplesBlock,
+            TopEntryPoint::TriplesTemplate => grammar::parse_TriplesTemplate,
+            TopEntryPoint::ConstructTemplate => grammar::parse_ConstructTemplate,
+            TopEntryPoint::QuadData => grammar::parse_QuadData,
+            TopEntryPoint::Update => grammar::parse_Update,
+            TopEntryPoint::Expression => grammar::parse_Expression,
+            TopEntryPoint::Path => grammar::parse_Path,
+            TopEntryPoint::Var => grammar::parse_Var,
+            TopEntryPoint::VarOrTerm => grammar::parse_VarOrTerm,
+            TopEntryPoint::Iri => grammar::parse_iri,
+            TopEntryPoint::RDFLiteral => grammar::parse_RDFLiteral,
+            TopEntryPoint::NumericLiteral => grammar::parse_NumericLiteral,
+            TopEntryPoint::BooleanLiteral => grammar::parse_BooleanLiteral,
+            TopEntryPoint::PrefixedName => grammar::parse_PrefixedName,
+            TopEntryPoint::BlankNode => grammar::parse_BlankNode,
+        }
+    }
 
-    while let Some(result) = lexer.next() {
-        tokens.push(Token {
-            kind: result.unwrap_or(SyntaxKind::Error),
-            text: lexer.slice().to_string(),
-        });
+    fn parse(
+        &self,
+        input: &[Token],
+        end: TextSize,
+        options: ParseOptions,
+    ) -> (Vec<Event>, Vec<Diagnostic>) {
+        let mut parser = Parser::new(input, end, options);
+        self.rule()(&mut parser);
+        // Optional rules do not produce a node if they are absent, the root exists anyway.
+        if parser.events.is_empty() {
+            let m = parser.open();
+            parser.close(m, self.kind());
+        }
+        parser.finish()
     }
-    return tokens;
 }
+
+#[cfg(test)]
+mod test;
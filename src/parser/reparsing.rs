@@ -0,0 +1,286 @@
+use std::{iter, ops::Range};
+
+use rowan::{GreenNode, GreenToken, NodeCache, NodeOrToken, TextRange, TextSize, TokenAtOffset};
+
+use crate::{SyntaxKind, SyntaxNode, SyntaxToken};
+
+use super::{
+    brackets::Brackets,
+    build_tree,
+    grammar::{parse_BrackettedExpression, parse_GroupGraphPattern, parse_TriplesSameSubjectPath},
+    lexer::{lex, Token, Tokenizer},
+    parse_with_options, Diagnostic, Parse, ParseOptions, Parser, TopEntryPoint,
+};
+
+/// A change of a document: the text in `range` is replaced by `insert`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub range: TextRange,
+    pub insert: String,
+}
+
+impl TextEdit {
+    pub fn new(range: TextRange, insert: impl Into<String>) -> Self {
+        Self {
+            range,
+            insert: insert.into(),
+        }
+    }
+
+    /// Applies the edit to `text`.
+    /// Returns `None` if the range of the edit ends past `text` or splits a character.
+    pub fn apply(&self, text: &str) -> Option<String> {
+        let range = Range::<usize>::from(self.range);
+        if !text.is_char_boundary(range.start) || !text.is_char_boundary(range.end) {
+            return None;
+        }
+        let mut text = text.to_string();
+        text.replace_range(range, &self.insert);
+        Some(text)
+    }
+}
+
+/// Tokens that are relexed on their own when an edit is inside of them.
+const RELEXABLE: &[SyntaxKind] = &[
+    SyntaxKind::WHITESPACE,
+    SyntaxKind::COMMENT,
+    SyntaxKind::VAR1,
+    SyntaxKind::VAR2,
+    SyntaxKind::IRIREF,
+    SyntaxKind::PNAME_LN,
+    SyntaxKind::PNAME_NS,
+    SyntaxKind::BLANK_NODE_LABEL,
+    SyntaxKind::STRING_LITERAL1,
+    SyntaxKind::STRING_LITERAL2,
+    SyntaxKind::STRING_LITERAL_LONG1,
+    SyntaxKind::STRING_LITERAL_LONG2,
+    SyntaxKind::LANGTAG,
+    SyntaxKind::INTEGER,
+    SyntaxKind::DECIMAL,
+    SyntaxKind::DOUBLE,
+];
+
+/// The rules that are reparsed on their own when an edit is inside of them.
+fn reparser(kind: SyntaxKind) -> Option<fn(&mut Parser)> {
+    match kind {
+        SyntaxKind::GroupGraphPattern => Some(parse_GroupGraphPattern),
+        SyntaxKind::BrackettedExpression => Some(parse_BrackettedExpression),
+        SyntaxKind::TriplesSameSubjectPath => Some(parse_TriplesSameSubjectPath),
+        _ => None,
+    }
+}
+
+/// Applies `edit` to the document of `parse` and parses the result.
+///
+/// If the edit stays inside a single token that relexes to a token of the same kind,
+/// only that token is replaced. Otherwise the smallest reparsable node that strictly
+/// contains the edit is reparsed on its own. Both require that the affected part of
+/// the tree has no diagnostics before and after the edit, so that error recovery,
+/// which depends on the enclosing rules, is never involved.
+/// All other cases fall back to parsing the whole document.
+pub(super) fn reparse(parse: &Parse, edit: &TextEdit) -> Option<Parse> {
+    let root = parse.syntax();
+    if !is_char_boundary(&root, edit.range.start()) || !is_char_boundary(&root, edit.range.end()) {
+        return None;
+    }
+    Some(
+        match reparse_token(&root, edit, parse.diagnostics())
+            .or_else(|| reparse_block(&root, edit, parse.diagnostics(), parse.options))
+        {
+            Some((green, replaced)) => Parse {
+                green,
+                diagnostics: shift_diagnostics(parse.diagnostics(), replaced, edit),
+                detected: parse.detected,
+                options: parse.options,
+            },
+            None => {
+                let entry = (!parse.detected).then(|| {
+                    *TopEntryPoint::ALL
+                        .iter()
+                        .find(|entry| entry.kind() == root.kind())
+                        .expect("the root is produced by an entry point")
+                });
+                parse_with_options(&edit.apply(&root.to_string())?, entry, parse.options)
+            }
+        },
+    )
+}
+
+/// Whether `offset` is inside of the document of `root` and does not split a character.
+fn is_char_boundary(root: &SyntaxNode, offset: TextSize) -> bool {
+    if offset > root.text_range().end() {
+        return false;
+    }
+    match root.token_at_offset(offset) {
+        TokenAtOffset::Single(token) => {
+            let offset = offset - token.text_range().start();
+            token.text().is_char_boundary(offset.into())
+        }
+        TokenAtOffset::None | TokenAtOffset::Between(..) => true,
+    }
+}
+
+fn reparse_token(
+    root: &SyntaxNode,
+    edit: &TextEdit,
+    diagnostics: &[Diagnostic],
+) -> Option<(GreenNode, TextRange)> {
+    let NodeOrToken::Token(token) = root.covering_element(edit.range) else {
+        return None;
+    };
+    if !RELEXABLE.contains(&token.kind()) || touches_diagnostic(token.text_range(), diagnostics) {
+        return None;
+    }
+    let text = local_edit(token.text(), token.text_range(), edit);
+    // The neighbours are lexed as well, so that tokens which merge or split are noticed.
+    let prev = token.prev_token();
+    let next = token.next_token();
+    let mut window = String::new();
+    let mut expected = Vec::new();
+    if let Some(prev) = &prev {
+        window.push_str(prev.text());
+        expected.push((prev.kind(), prev.text_range().len()));
+    }
+    window.push_str(&text);
+    expected.push((token.kind(), TextSize::of(&text)));
+    if let Some(next) = &next {
+        window.push_str(next.text());
+        expected.push((next.kind(), next.text_range().len()));
+    }
+    let mut tokenizer = Tokenizer::new(&window);
+    let relexed: Vec<_> = tokenizer
+        .by_ref()
+        .map(|(kind, range)| (kind, range.len()))
+        .collect();
+    if !tokenizer.errors().is_empty() || relexed != expected {
+        return None;
+    }
+    let green = token.replace_with(GreenToken::new(token.kind().into(), &text));
+    Some((green, token.text_range()))
+}
+
+fn reparse_block(
+    root: &SyntaxNode,
+    edit: &TextEdit,
+    diagnostics: &[Diagnostic],
+    options: ParseOptions,
+) -> Option<(GreenNode, TextRange)> {
+    let node = match root.covering_element(edit.range) {
+        NodeOrToken::Node(node) => node,
+        NodeOrToken::Token(token) => token.parent()?,
+    };
+    let node = node
+        .ancestors()
+        .find(|node| reparser(node.kind()).is_some() && strictly_contains(node, edit.range))?;
+    if touches_diagnostic(node.text_range(), diagnostics) {
+        return None;
+    }
+    let text = local_edit(&node.to_string(), node.text_range(), edit);
+    let lexed = lex(&text);
+    // The first and last token are not edited, they must not be lexed differently.
+    let shape = |token: &Token| (token.kind, token.range.len());
+    let old_shape = |token: SyntaxToken| (token.kind(), token.text_range().len());
+    if !lexed.errors.is_empty()
+        || lexed.input.first().map(shape) != node.first_token().map(old_shape)
+        || lexed.input.last().map(shape) != node.last_token().map(old_shape)
+    {
+        return None;
+    }
+    let mut input = lexed.input;
+    let brackets = Brackets::new(&input);
+    if !brackets.diagnostics(&input).is_empty() {
+        return None;
+    }
+    // The token after the node decides where optional and repeated parts end.
+    let len = input.len();
+    let end = TextSize::of(&text);
+    let next = iter::successors(node.last_token()?.next_token(), SyntaxToken::next_token)
+        .find(|token| !token.kind().is_trivia());
+    if let Some(next) = next {
+        input.push(Token {
+            kind: next.kind(),
+            range: TextRange::empty(end),
+        });
+    }
+    let mut parser = Parser::new(&input, end, options);
+    parser.outer_nesting = nesting_before(root, node.text_range().start());
+    reparser(node.kind())?(&mut parser);
+    if parser.pos != len || !parser.diagnostics.is_empty() {
+        return None;
+    }
+    let green = build_tree(
+        &text,
+        &input[..len],
+        &lexed.trivia,
+        parser.events,
+        &mut NodeCache::default(),
+    );
+    Some((node.replace_with(green), node.text_range()))
+}
+
+/// The number of brackets the parser had open when it reached `offset`.
+fn nesting_before(root: &SyntaxNode, offset: TextSize) -> usize {
+    let tokens: Vec<Token> = root
+        .descendants_with_tokens()
+        .filter_map(NodeOrToken::into_token)
+        .filter(|token| !token.kind().is_trivia())
+        .map(|token| Token {
+            kind: token.kind(),
+            range: token.text_range(),
+        })
+        .collect();
+    let mut brackets = Brackets::new(&tokens);
+    for (pos, token) in tokens.iter().enumerate() {
+        if token.range.start() >= offset {
+            break;
+        }
+        brackets.advance(pos, token.kind);
+    }
+    brackets.depth()
+}
+
+/// Whether `range` is inside `node` without touching its first or last token.
+fn strictly_contains(node: &SyntaxNode, range: TextRange) -> bool {
+    match (node.first_token(), node.last_token()) {
+        (Some(first), Some(last)) => {
+            first.text_range().end() <= range.start() && range.end() <= last.text_range().start()
+        }
+        _ => false,
+    }
+}
+
+/// Applies `edit` to `text`, which is found at `range` in the document.
+fn local_edit(text: &str, range: TextRange, edit: &TextEdit) -> String {
+    TextEdit::new(edit.range - range.start(), edit.insert.as_str())
+        .apply(text)
+        .expect("the edit was checked against the document")
+}
+
+fn touches_diagnostic(range: TextRange, diagnostics: &[Diagnostic]) -> bool {
+    diagnostics
+        .iter()
+        .any(|diagnostic| range.intersect(diagnostic.range).is_some())
+}
+
+/// Moves the diagnostics after `replaced` by the change in length of the edit.
+fn shift_diagnostics(
+    diagnostics: &[Diagnostic],
+    replaced: TextRange,
+    edit: &TextEdit,
+) -> Vec<Diagnostic> {
+    let removed = edit.range.len();
+    let inserted = TextSize::of(&edit.insert);
+    diagnostics
+        .iter()
+        .cloned()
+        .map(|mut diagnostic| {
+            if diagnostic.range.start() >= replaced.end() {
+                diagnostic.range = TextRange::at(
+                    diagnostic.range.start() - removed + inserted,
+                    diagnostic.range.len(),
+                );
+            }
+            diagnostic
+        })
+        .collect()
+}
@@ -0,0 +1,1843 @@
+use crate::{
+    parse, parse_entry, parse_query, parse_query_with_diagnostics, parse_update,
+    parse_update_with_diagnostics, tokenize, DocumentKind, Severity, SyntaxKind, SyntaxNode,
+    WRAPPER_KINDS,
+};
+use indoc::indoc;
+use rowan::{TextRange, TextSize};
+
+use super::{
+    lex,
+    lexer::{LexErrorKind, Lexed},
+    parse_text, Parse, TextEdit, TopEntryPoint,
+};
+
+fn kinds(input: &str) -> Vec<SyntaxKind> {
+    tokenize(input)
+        .map(|(kind, _)| kind)
+        .filter(|kind| !kind.is_trivia())
+        .collect()
+}
+
+fn has_errors(root: &SyntaxNode) -> bool {
+    root.descendants_with_tokens()
+        .any(|element| element.kind() == SyntaxKind::Error)
+}
+
+#[test]
+fn lex_numeric_literals() {
+    assert_eq!(
+        kinds("10 3.5 .5 3.5e2 1E-3 .5e+7 4e2"),
+        vec![
+            SyntaxKind::INTEGER,
+            SyntaxKind::DECIMAL,
+            SyntaxKind::DECIMAL,
+            SyntaxKind::DOUBLE,
+            SyntaxKind::DOUBLE,
+            SyntaxKind::DOUBLE,
+            SyntaxKind::DOUBLE,
+        ]
+    );
+    assert_eq!(
+        kinds("+1 -1 +1.0 -.5 +1e3 -2.5E-2"),
+        vec![
+            SyntaxKind::INTEGER_POSITIVE,
+            SyntaxKind::INTEGER_NEGATIVE,
+            SyntaxKind::DECIMAL_POSITIVE,
+            SyntaxKind::DECIMAL_NEGATIVE,
+            SyntaxKind::DOUBLE_POSITIVE,
+            SyntaxKind::DOUBLE_NEGATIVE,
+        ]
+    );
+}
+
+#[test]
+fn lex_integer_followed_by_dot() {
+    assert_eq!(
+        kinds("?s ?p 1."),
+        vec![
+            SyntaxKind::VAR1,
+            SyntaxKind::VAR1,
+            SyntaxKind::INTEGER,
+            SyntaxKind::Dot
+        ]
+    );
+}
+
+#[test]
+fn lex_signed_numbers_in_additive_expression() {
+    assert_eq!(
+        kinds("?a -1"),
+        vec![SyntaxKind::VAR1, SyntaxKind::INTEGER_NEGATIVE]
+    );
+    assert_eq!(
+        kinds("?a - 1"),
+        vec![SyntaxKind::VAR1, SyntaxKind::Minus, SyntaxKind::INTEGER]
+    );
+}
+
+#[test]
+fn parse_numeric_literals() {
+    let inputs = [
+        "SELECT * WHERE { ?s ?p ?o } LIMIT 10",
+        "SELECT * WHERE { ?s ?p ?o } LIMIT 10 OFFSET 20",
+        "SELECT * WHERE { ?s ?p ?x FILTER(?x > 3.5e2) }",
+        "SELECT * WHERE { ?s ?p ?x FILTER(?x -1 = 2) }",
+        "SELECT * WHERE { ?s ?p ?x FILTER(?x - 1 = 2) }",
+        "SELECT * WHERE { ?s ?p ?x FILTER(?x -1 * 2 > -.5) }",
+        "SELECT * WHERE { ?s ?p -42 . ?s ?p +4.2 . ?s ?p 1e10 }",
+    ];
+    for input in inputs {
+        let root = parse_query(input);
+        assert!(!has_errors(&root), "{input}");
+        assert_eq!(root.text(), input);
+    }
+}
+
+#[test]
+fn lex_long_string_literals() {
+    assert_eq!(
+        kinds(
+            r#"'''a 'quoted' ''word'' \' end''' """multi
+line "with" ""quotes"" \t \"""""#
+        ),
+        vec![
+            SyntaxKind::STRING_LITERAL_LONG1,
+            SyntaxKind::STRING_LITERAL_LONG2
+        ]
+    );
+    assert_eq!(kinds("''''''"), vec![SyntaxKind::STRING_LITERAL_LONG1]);
+    assert_eq!(kinds(r#""""" """"#), vec![SyntaxKind::STRING_LITERAL_LONG2]);
+}
+
+#[test]
+fn parse_long_string_literals() {
+    let input = indoc! {r#"
+        SELECT * WHERE {
+          ?s ?p """A description
+        spanning "several" lines""" .
+          ?s ?q '''it''s'''
+        }
+    "#};
+    let root = parse_query(input);
+    assert!(!has_errors(&root));
+    assert_eq!(root.text(), input);
+    let strings: Vec<_> = root
+        .descendants()
+        .filter(|node| node.kind() == SyntaxKind::String)
+        .map(|node| node.text().to_string())
+        .collect();
+    assert_eq!(
+        strings,
+        vec![
+            "\"\"\"A description\nspanning \"several\" lines\"\"\"",
+            "'''it''s'''"
+        ]
+    );
+}
+
+#[test]
+fn lex_rdf_term_terminals() {
+    assert_eq!(
+        kinds(r#""chat"@fr "colour"@en-GB "1"^^<http://www.w3.org/2001/XMLSchema#int>"#),
+        vec![
+            SyntaxKind::STRING_LITERAL2,
+            SyntaxKind::LANGTAG,
+            SyntaxKind::STRING_LITERAL2,
+            SyntaxKind::LANGTAG,
+            SyntaxKind::STRING_LITERAL2,
+            SyntaxKind::DoubleZirkumflex,
+            SyntaxKind::IRIREF,
+        ]
+    );
+    assert_eq!(
+        kinds("_:b0 _:1a _:a.b _:b0."),
+        vec![
+            SyntaxKind::BLANK_NODE_LABEL,
+            SyntaxKind::BLANK_NODE_LABEL,
+            SyntaxKind::BLANK_NODE_LABEL,
+            SyntaxKind::BLANK_NODE_LABEL,
+            SyntaxKind::Dot,
+        ]
+    );
+}
+
+#[test]
+fn lex_anon_and_nil() {
+    assert_eq!(
+        kinds("[] [ ] [\n\t] ( ) () [ ?p ?o ] (?x)"),
+        vec![
+            SyntaxKind::ANON,
+            SyntaxKind::ANON,
+            SyntaxKind::ANON,
+            SyntaxKind::NIL,
+            SyntaxKind::NIL,
+            SyntaxKind::LBrack,
+            SyntaxKind::VAR1,
+            SyntaxKind::VAR1,
+            SyntaxKind::RBrack,
+            SyntaxKind::LParen,
+            SyntaxKind::VAR1,
+            SyntaxKind::RParen,
+        ]
+    );
+}
+
+#[test]
+fn parse_rdf_term_terminals() {
+    let input = indoc! {r#"
+        SELECT * WHERE {
+          [ ] ?p "chat"@fr .
+          _:b0 ?p "1"^^<http://www.w3.org/2001/XMLSchema#int> .
+          [ ?p ?o ] ?q () .
+          ?s ?p ?x FILTER(BOUND(?x) && (?x))
+        }
+    "#};
+    let root = parse_query(input);
+    assert!(!has_errors(&root));
+    assert_eq!(root.text(), input);
+    let count = |kind| {
+        root.descendants()
+            .filter(|node| node.kind() == kind)
+            .count()
+    };
+    assert_eq!(count(SyntaxKind::BlankNodePropertyListPath), 1);
+    assert_eq!(count(SyntaxKind::BrackettedExpression), 2);
+    assert_eq!(count(SyntaxKind::BlankNode), 2);
+}
+
+#[test]
+fn comments_are_trivia() {
+    let input = indoc! {"
+        # leading comment
+        PREFIX ex: <http://example.org/#frag> # after prefix
+        SELECT * WHERE { # inside group
+          ?s ?p \"# not a comment\" . # after triple
+          ?s ?p ?o # before brace
+        } # trailing comment"};
+    let root = parse_query(input);
+    assert!(!has_errors(&root));
+    assert_eq!(root.text(), input);
+    let comments: Vec<_> = root
+        .descendants_with_tokens()
+        .filter(|element| element.kind() == SyntaxKind::COMMENT)
+        .map(|element| element.into_token().unwrap().text().to_string())
+        .collect();
+    assert_eq!(
+        comments,
+        vec![
+            "# leading comment",
+            "# after prefix",
+            "# inside group",
+            "# after triple",
+            "# before brace",
+            "# trailing comment"
+        ]
+    );
+}
+
+#[test]
+fn leading_trivia_in_update() {
+    let input = "# comment\r\n  LOAD <http://example.org/data>";
+    let root = parse_update(input);
+    assert!(!has_errors(&root));
+    assert_eq!(root.text(), input);
+}
+
+#[test]
+fn lex_variable_names() {
+    let corpus = [
+        "?x",
+        "$x",
+        "?item_label",
+        "?x1",
+        "?1x",
+        "?42",
+        "?_tmp",
+        "$_",
+        "?élément",
+        "?ΑΒΓ",
+        "?変数",
+        "?a·b",
+        "?a\u{0301}",
+        "?a\u{203F}b",
+        "?𐌰𐌱",
+        "$CamelCase_09",
+    ];
+    for var in corpus {
+        let tokens = lex(var).input;
+        assert_eq!(tokens.len(), 1, "{var} was split into {tokens:?}");
+        assert!(
+            matches!(tokens[0].kind, SyntaxKind::VAR1 | SyntaxKind::VAR2),
+            "{var} lexed as {:?}",
+            tokens[0].kind
+        );
+    }
+    assert_eq!(
+        kinds("?x-1 ?a.?b ?·"),
+        vec![
+            SyntaxKind::VAR1,
+            SyntaxKind::INTEGER_NEGATIVE,
+            SyntaxKind::VAR1,
+            SyntaxKind::Dot,
+            SyntaxKind::VAR1,
+            SyntaxKind::QuestionMark,
+            SyntaxKind::Error,
+        ]
+    );
+}
+
+#[test]
+fn parse_variable_names() {
+    let input = "SELECT ?item_label ?élément WHERE { ?item_label ?_p ?x1 . BIND(?x1 AS $y2) }";
+    let root = parse_query(input);
+    assert!(!has_errors(&root));
+    assert_eq!(
+        root.descendants()
+            .filter(|node| node.kind() == SyntaxKind::Var)
+            .count(),
+        7
+    );
+}
+
+#[test]
+fn lex_prefixed_names() {
+    let corpus = [
+        ":",
+        ":p",
+        "ex:",
+        "wd:Q42",
+        "ex:123",
+        "ex:a:b",
+        "ex::",
+        "ex:caf%C3%A9",
+        r"ex:foo\,bar",
+        r"ex:\_\~\.\-\!\$\&\'\(\)\*\+\,\;\=\/\?\#\@\%",
+        "ex:a.b",
+        "a.b:c",
+        "select:x",
+        "a:b",
+        "ex:_x",
+        "ex:a·b",
+    ];
+    for name in corpus {
+        let tokens = lex(name).input;
+        assert_eq!(tokens.len(), 1, "{name} was split into {tokens:?}");
+        assert!(
+            matches!(tokens[0].kind, SyntaxKind::PNAME_NS | SyntaxKind::PNAME_LN),
+            "{name} lexed as {:?}",
+            tokens[0].kind
+        );
+    }
+    assert_eq!(
+        kinds("ex:o. ex:o.."),
+        vec![
+            SyntaxKind::PNAME_LN,
+            SyntaxKind::Dot,
+            SyntaxKind::PNAME_LN,
+            SyntaxKind::Dot,
+            SyntaxKind::Dot
+        ]
+    );
+    assert_eq!(
+        kinds("ex:a%2 ex:a,ex:b"),
+        vec![
+            SyntaxKind::PNAME_LN,
+            SyntaxKind::Error,
+            SyntaxKind::INTEGER,
+            SyntaxKind::PNAME_LN,
+            SyntaxKind::Comma,
+            SyntaxKind::PNAME_LN
+        ]
+    );
+}
+
+fn lex_errors(input: &str) -> Vec<(LexErrorKind, &str)> {
+    lex(input)
+        .errors
+        .into_iter()
+        .map(|error| (error.kind, &input[error.range]))
+        .collect()
+}
+
+#[test]
+fn lex_unterminated_strings() {
+    let input = "SELECT * { ?s ?p \"abc\n}";
+    let Lexed {
+        input: tokens,
+        errors,
+        ..
+    } = lex(input);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].kind, LexErrorKind::UnterminatedString);
+    let error_tokens: Vec<_> = tokens
+        .iter()
+        .filter(|token| token.kind == SyntaxKind::Error)
+        .map(|token| &input[token.range])
+        .collect();
+    assert_eq!(error_tokens, vec!["\"abc"]);
+    assert_eq!(
+        lex_errors("'''abc\n'' "),
+        vec![(LexErrorKind::UnterminatedString, "'''abc\n'' ")]
+    );
+}
+
+#[test]
+fn lex_invalid_escapes() {
+    let Lexed {
+        input: tokens,
+        errors,
+        ..
+    } = lex(r#""a\qb\x""#);
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens[0].kind, SyntaxKind::STRING_LITERAL2);
+    assert_eq!(
+        errors
+            .iter()
+            .map(|error| (error.kind, &r#""a\qb\x""#[error.range]))
+            .collect::<Vec<_>>(),
+        vec![
+            (LexErrorKind::InvalidEscape, r"\q"),
+            (LexErrorKind::InvalidEscape, r"\x"),
+        ]
+    );
+    assert_eq!(kinds(r#""\t\"é""#), vec![SyntaxKind::STRING_LITERAL2]);
+}
+
+#[test]
+fn lex_invalid_iris() {
+    let input = "<http://example.org/a b|c>";
+    assert_eq!(kinds(input), vec![SyntaxKind::IRIREF]);
+    assert_eq!(
+        lex_errors(input),
+        vec![
+            (LexErrorKind::InvalidIri, " "),
+            (LexErrorKind::InvalidIri, "|")
+        ]
+    );
+    assert_eq!(
+        kinds("?a < 3 && ?b > 2"),
+        vec![
+            SyntaxKind::VAR1,
+            SyntaxKind::Less,
+            SyntaxKind::INTEGER,
+            SyntaxKind::DoubleAnd,
+            SyntaxKind::VAR1,
+            SyntaxKind::More,
+            SyntaxKind::INTEGER,
+        ]
+    );
+}
+
+#[test]
+fn lex_merges_garbage() {
+    let input = "?a §§§ ?b \u{1}\u{2}";
+    let Lexed {
+        input: tokens,
+        errors,
+        ..
+    } = lex(input);
+    let error_tokens: Vec<_> = tokens
+        .iter()
+        .filter(|token| token.kind == SyntaxKind::Error)
+        .map(|token| &input[token.range])
+        .collect();
+    assert_eq!(error_tokens, vec!["§§§", "\u{1}\u{2}"]);
+    assert_eq!(
+        errors.iter().map(|error| error.kind).collect::<Vec<_>>(),
+        vec![
+            LexErrorKind::UnexpectedCharacters,
+            LexErrorKind::ControlCharacter
+        ]
+    );
+}
+
+#[test]
+fn lex_errors_are_reported_by_the_parser() {
+    let input = "SELECT * WHERE { ?s ?p \"abc }";
+    let parse = parse_text(input, TopEntryPoint::QueryUnit);
+    assert_eq!(parse.syntax().to_string(), input);
+    let lex_errors: Vec<_> = parse
+        .diagnostics()
+        .iter()
+        .filter(|diagnostic| diagnostic.rule.is_none() && diagnostic.expected.is_empty())
+        .collect();
+    assert_eq!(lex_errors.len(), 1);
+    assert_eq!(lex_errors[0].message, "unterminated string literal");
+    assert_eq!(&input[lex_errors[0].range], "\"abc }");
+}
+
+#[test]
+fn lex_codepoint_escapes() {
+    let input = r"SELECT ?n\u0061me WHERE { <http://example.org/\u00E9> ?p 'caf\U000000E9' }";
+    let Lexed {
+        input: tokens,
+        errors,
+        ..
+    } = lex(input);
+    assert!(errors.is_empty());
+    assert_eq!(
+        tokens
+            .iter()
+            .map(|token| (token.kind, &input[token.range]))
+            .collect::<Vec<_>>(),
+        vec![
+            (SyntaxKind::SELECT, "SELECT"),
+            (SyntaxKind::VAR1, r"?n\u0061me"),
+            (SyntaxKind::WHERE, "WHERE"),
+            (SyntaxKind::LCurly, "{"),
+            (SyntaxKind::IRIREF, r"<http://example.org/\u00E9>"),
+            (SyntaxKind::VAR1, "?p"),
+            (SyntaxKind::STRING_LITERAL1, r"'caf\U000000E9'"),
+            (SyntaxKind::RCurly, "}"),
+        ]
+    );
+    let root = parse_query(input);
+    assert_eq!(root.to_string(), input);
+    assert!(!has_errors(&root));
+}
+
+#[test]
+fn lex_invalid_codepoint_escapes() {
+    assert_eq!(
+        lex_errors(r#""\u12" "\uD800""#),
+        vec![
+            (LexErrorKind::InvalidEscape, r"\u"),
+            (LexErrorKind::InvalidEscape, r"\u")
+        ]
+    );
+}
+
+#[test]
+fn lex_error_ranges_point_into_the_original_text() {
+    let input = r#"SELECT * { ?a ?b "\u00E9\q" § }"#;
+    assert_eq!(
+        lex_errors(input),
+        vec![
+            (LexErrorKind::InvalidEscape, r"\q"),
+            (LexErrorKind::UnexpectedCharacters, "§"),
+        ]
+    );
+}
+
+#[test]
+fn lex_compound_update_keywords() {
+    assert_eq!(
+        kinds("INSERT DATA insert\n\tdata DELETE DATA DELETE WHERE"),
+        vec![
+            SyntaxKind::INSERT,
+            SyntaxKind::DATA,
+            SyntaxKind::INSERT,
+            SyntaxKind::DATA,
+            SyntaxKind::DELETE,
+            SyntaxKind::DATA,
+            SyntaxKind::DELETE,
+            SyntaxKind::WHERE,
+        ]
+    );
+    let input_kinds = |input| {
+        lex(input)
+            .input
+            .into_iter()
+            .map(|token| token.kind)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        input_kinds("INSERT DATA insert\n\tdata DELETE DATA delete # comment\n where"),
+        vec![
+            SyntaxKind::INSERT_DATA,
+            SyntaxKind::INSERT_DATA,
+            SyntaxKind::DELETE_DATA,
+            SyntaxKind::DELETE_WHERE,
+        ]
+    );
+    assert_eq!(
+        input_kinds("INSERT { } DELETE {} INSERT DATAX: DELETE"),
+        vec![
+            SyntaxKind::INSERT,
+            SyntaxKind::LCurly,
+            SyntaxKind::RCurly,
+            SyntaxKind::DELETE,
+            SyntaxKind::LCurly,
+            SyntaxKind::RCurly,
+            SyntaxKind::INSERT,
+            SyntaxKind::PNAME_NS,
+            SyntaxKind::DELETE,
+        ]
+    );
+}
+
+#[test]
+fn compound_update_keywords_keep_their_trivia() {
+    let input = "INSERT # c\n DATA {}";
+    let root = parse_update(input);
+    assert!(!has_errors(&root), "{root:#?}");
+    assert_eq!(root.to_string(), input);
+    let insert_data = root
+        .descendants()
+        .find(|node| node.kind() == SyntaxKind::InsertData)
+        .unwrap();
+    let tokens: Vec<_> = insert_data
+        .children_with_tokens()
+        .filter_map(|element| element.into_token())
+        .map(|token| (token.kind(), token.to_string()))
+        .collect();
+    assert_eq!(
+        tokens[..5],
+        [
+            (SyntaxKind::INSERT, "INSERT".to_string()),
+            (SyntaxKind::WHITESPACE, " ".to_string()),
+            (SyntaxKind::COMMENT, "# c".to_string()),
+            (SyntaxKind::WHITESPACE, "\n ".to_string()),
+            (SyntaxKind::DATA, "DATA".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn parse_update_operations() {
+    let cases = [
+        (SyntaxKind::Load, "LOAD SILENT <http://example.org/data> INTO GRAPH <g>"),
+        (SyntaxKind::Load, "LOAD <http://example.org/data>"),
+        (SyntaxKind::Clear, "CLEAR SILENT DEFAULT"),
+        (SyntaxKind::Clear, "CLEAR GRAPH <g>"),
+        (SyntaxKind::Drop, "DROP NAMED"),
+        (SyntaxKind::Drop, "DROP SILENT ALL"),
+        (SyntaxKind::Add, "ADD DEFAULT TO GRAPH <g>"),
+        (SyntaxKind::Move, "MOVE SILENT GRAPH <a> TO DEFAULT"),
+        (SyntaxKind::Copy, "COPY <a> TO <b>"),
+        (SyntaxKind::Create, "CREATE SILENT GRAPH <g>"),
+        (
+            SyntaxKind::InsertData,
+            "INSERT DATA { <a> <b> <c> . GRAPH <g> { <a> <b> 'c' } }",
+        ),
+        (
+            SyntaxKind::DeleteData,
+            "delete data { <a> <b> <c> }",
+        ),
+        (
+            SyntaxKind::DeleteWhere,
+            "DELETE # comment\n WHERE { ?s ?p ?o }",
+        ),
+        (
+            SyntaxKind::Modify,
+            "WITH <g> DELETE { ?s ?p ?o } INSERT { ?s ?p 1 } USING <a> USING NAMED <b> WHERE { ?s ?p ?o }",
+        ),
+        (SyntaxKind::Modify, "INSERT { ?s ?p ?o } WHERE { ?s ?p ?o }"),
+    ];
+    for (kind, input) in cases {
+        let root = parse_update(input);
+        assert!(!has_errors(&root), "{input}\n{root:#?}");
+        assert_eq!(root.to_string(), input);
+        let operation = root
+            .descendants()
+            .find(|node| node.kind() == SyntaxKind::UpdateOne)
+            .and_then(|node| node.first_child())
+            .map(|node| node.kind());
+        assert_eq!(operation, Some(kind), "{input}");
+    }
+}
+
+#[test]
+fn parse_update_sequence() {
+    let input = indoc! {"
+        PREFIX ex: <http://example.org/>
+        INSERT DATA { ex:a ex:b ex:c } ;
+        DELETE WHERE { ?s ex:b ?o } ;
+        CLEAR ALL
+    "};
+    let root = parse_update(input);
+    assert!(!has_errors(&root));
+    assert_eq!(
+        root.descendants()
+            .filter(|node| node.kind() == SyntaxKind::UpdateOne)
+            .count(),
+        3
+    );
+}
+
+#[test]
+fn lex_keywords_case_insensitive() {
+    assert_eq!(
+        kinds("count Count COUNT group_concat Separator isIri ISBLANK sameterm TRUE False"),
+        vec![
+            SyntaxKind::COUNT,
+            SyntaxKind::COUNT,
+            SyntaxKind::COUNT,
+            SyntaxKind::GROUP_CONCAT,
+            SyntaxKind::SEPARATOR,
+            SyntaxKind::isIRI,
+            SyntaxKind::isBLANK,
+            SyntaxKind::sameTerm,
+            SyntaxKind::True,
+            SyntaxKind::False,
+        ]
+    );
+    assert_eq!(kinds("a"), vec![SyntaxKind::a]);
+    assert_ne!(kinds("A"), vec![SyntaxKind::a]);
+}
+
+#[test]
+fn parse_lowercase_builtins() {
+    let input = indoc! {r#"
+        select (count(*) as ?c) (group_concat(?o; separator=", ") as ?all)
+        where {
+          ?s a ?o
+          filter(regex(str(?o), "x", "i") && !isblank(?o) && ?o not in (1, 2))
+        }
+        group by ?s
+    "#};
+    let root = parse_query(input);
+    assert!(!has_errors(&root), "{root:#?}");
+    assert_eq!(root.to_string(), input);
+}
+
+#[test]
+fn tokenize_yields_spans() {
+    let input = "SELECT ?\\u0078 # comment\nWHERE { ?x § }";
+    let mut tokenizer = tokenize(input);
+    let tokens: Vec<_> = tokenizer
+        .by_ref()
+        .map(|(kind, range)| (kind, &input[range]))
+        .collect();
+    assert_eq!(
+        tokens,
+        vec![
+            (SyntaxKind::SELECT, "SELECT"),
+            (SyntaxKind::WHITESPACE, " "),
+            (SyntaxKind::VAR1, "?\\u0078"),
+            (SyntaxKind::WHITESPACE, " "),
+            (SyntaxKind::COMMENT, "# comment"),
+            (SyntaxKind::WHITESPACE, "\n"),
+            (SyntaxKind::WHERE, "WHERE"),
+            (SyntaxKind::WHITESPACE, " "),
+            (SyntaxKind::LCurly, "{"),
+            (SyntaxKind::WHITESPACE, " "),
+            (SyntaxKind::VAR1, "?x"),
+            (SyntaxKind::WHITESPACE, " "),
+            (SyntaxKind::Error, "§"),
+            (SyntaxKind::WHITESPACE, " "),
+            (SyntaxKind::RCurly, "}"),
+        ]
+    );
+    assert_eq!(tokenizer.errors().len(), 1);
+    assert_eq!(
+        tokenizer.errors()[0].kind,
+        LexErrorKind::UnexpectedCharacters
+    );
+}
+
+#[test]
+fn valid_documents_have_no_diagnostics() {
+    let parse = parse_query_with_diagnostics("SELECT * WHERE { ?s ?p ?o }");
+    assert!(parse.diagnostics().is_empty());
+    let parse = parse_update_with_diagnostics("INSERT DATA { <a> <b> <c> }");
+    assert!(parse.diagnostics().is_empty());
+}
+
+#[test]
+fn diagnostics_carry_rule_and_expected_kinds() {
+    let input = "SELECT WHERE { ?s ?p ?o }";
+    let parse = parse_query_with_diagnostics(input);
+    let [diagnostic] = parse.diagnostics() else {
+        panic!("expected one diagnostic, got {:?}", parse.diagnostics());
+    };
+    assert_eq!(&input[diagnostic.range], "WHERE");
+    assert_eq!(diagnostic.severity, Severity::Error);
+    assert_eq!(diagnostic.rule, Some(SyntaxKind::SelectClause));
+    assert_eq!(
+        diagnostic.expected,
+        vec![
+            SyntaxKind::LParen,
+            SyntaxKind::Star,
+            SyntaxKind::VAR1,
+            SyntaxKind::VAR2
+        ]
+    );
+    assert_eq!(
+        diagnostic.message,
+        "expected LParen, Star, VAR1 or VAR2, found WHERE"
+    );
+}
+
+#[test]
+fn diagnostics_at_end_of_input() {
+    let input = "SELECT * WHERE { ?s ?p ?o } LIMIT";
+    let parse = parse_query_with_diagnostics(input);
+    let [diagnostic] = parse.diagnostics() else {
+        panic!("expected one diagnostic, got {:?}", parse.diagnostics());
+    };
+    assert_eq!(diagnostic.range, TextRange::empty(TextSize::of(input)));
+    assert_eq!(diagnostic.rule, Some(SyntaxKind::LimitClause));
+    assert_eq!(diagnostic.expected, vec![SyntaxKind::INTEGER]);
+}
+
+#[test]
+fn diagnostics_are_ordered_by_position() {
+    let parse = parse_query_with_diagnostics("ASK { FILTER( } § ");
+    let starts: Vec<_> = parse
+        .diagnostics()
+        .iter()
+        .map(|diagnostic| diagnostic.range.start())
+        .collect();
+    assert!(starts.len() > 1);
+    assert!(starts.windows(2).all(|pair| pair[0] <= pair[1]));
+}
+
+#[test]
+fn empty_input_has_a_root() {
+    assert_eq!(parse_update("").kind(), SyntaxKind::UpdateUnit);
+    assert_eq!(parse_query("").kind(), SyntaxKind::QueryUnit);
+    assert_eq!(parse_update("  # nothing").to_string(), "  # nothing");
+}
+
+#[test]
+fn trailing_tokens_are_kept_in_an_error_node() {
+    let input = "SELECT * WHERE { } } ?x .";
+    let parse = parse_query_with_diagnostics(input);
+    let root = parse.syntax();
+    assert_eq!(root.to_string(), input);
+    let error = root.last_child().unwrap();
+    assert_eq!(error.kind(), SyntaxKind::Error);
+    assert_eq!(error.to_string(), "} ?x .");
+    assert_eq!(parse.diagnostics().len(), 1);
+}
+
+fn count(root: &SyntaxNode, kind: SyntaxKind) -> usize {
+    root.descendants()
+        .filter(|node| node.kind() == kind)
+        .count()
+}
+
+#[test]
+fn recovery_resumes_inside_group_graph_pattern() {
+    let input = "SELECT * WHERE { ?s ?p ?o . ?s ?p AS ?x . ?a ?b ?c FILTER(?a) } LIMIT 1";
+    let parse = parse_query_with_diagnostics(input);
+    let root = parse.syntax();
+    assert_eq!(root.to_string(), input);
+    let where_clause = root
+        .descendants()
+        .find(|node| node.kind() == SyntaxKind::WhereClause)
+        .unwrap();
+    assert!(where_clause.to_string().ends_with("FILTER(?a) }"));
+    assert_eq!(count(&where_clause, SyntaxKind::TriplesSameSubjectPath), 3);
+    assert_eq!(count(&where_clause, SyntaxKind::Filter), 1);
+    assert_eq!(count(&root, SyntaxKind::LimitClause), 1);
+    let error = root
+        .descendants()
+        .find(|node| node.kind() == SyntaxKind::Error)
+        .unwrap();
+    assert_eq!(error.to_string(), "AS ?x");
+    assert_eq!(parse.diagnostics().len(), 1);
+}
+
+#[test]
+fn recovery_stops_at_clause_keywords() {
+    let input = "SELECT * WHERE { ?s ?p ?o LIMIT 1";
+    let parse = parse_query_with_diagnostics(input);
+    let root = parse.syntax();
+    assert!(!has_errors(&root));
+    assert_eq!(count(&root, SyntaxKind::LimitClause), 1);
+    let [diagnostic] = parse.diagnostics() else {
+        panic!("expected one diagnostic, got {:?}", parse.diagnostics());
+    };
+    assert_eq!(&input[diagnostic.range], "{");
+    assert_eq!(diagnostic.expected, vec![SyntaxKind::RCurly]);
+}
+
+#[test]
+fn unbalanced_brackets_are_reported_once() {
+    let input = "SELECT * { ?s ?p ?o . OPTIONAL { ?s <x> ?y ";
+    let parse = parse_query_with_diagnostics(input);
+    let diagnostics: Vec<_> = parse
+        .diagnostics()
+        .iter()
+        .map(|diagnostic| (&input[diagnostic.range], diagnostic.message.as_str()))
+        .collect();
+    assert_eq!(
+        diagnostics,
+        vec![("{", "unclosed LCurly"), ("{", "unclosed LCurly")]
+    );
+    let diagnostics: Vec<_> = parse
+        .diagnostics()
+        .iter()
+        .map(|diagnostic| diagnostic.range.start())
+        .collect();
+    assert_eq!(diagnostics, vec![TextSize::new(9), TextSize::new(31)]);
+
+    let input = "ASK { ?s ?p ?o ) }";
+    let parse = parse_query_with_diagnostics(input);
+    let [diagnostic] = parse.diagnostics() else {
+        panic!("expected one diagnostic, got {:?}", parse.diagnostics());
+    };
+    assert_eq!(&input[diagnostic.range], ")");
+    assert_eq!(diagnostic.message, "unmatched RParen");
+    assert_eq!(diagnostic.rule, None);
+}
+
+#[test]
+fn many_unmatched_closing_brackets() {
+    let count = 20_000;
+    let input = format!("SELECT * {{ {}{} }}", "(".repeat(count), "]".repeat(count));
+    let parse = parse_query_with_diagnostics(&input);
+    assert_eq!(parse.syntax().to_string(), input);
+    let messages = |message| {
+        parse
+            .diagnostics()
+            .iter()
+            .filter(|diagnostic| diagnostic.message == message)
+            .count()
+    };
+    assert_eq!(messages("unclosed LParen"), count);
+    assert_eq!(messages("unmatched RBrack"), count);
+}
+
+#[test]
+fn unclosed_groups_end_at_the_enclosing_bracket() {
+    let input = "ASK { FILTER(?x } ";
+    let root = parse_query(input);
+    let filter = root
+        .descendants()
+        .find(|node| node.kind() == SyntaxKind::Filter)
+        .unwrap();
+    assert_eq!(filter.to_string(), "FILTER(?x ");
+    let group = filter.parent().unwrap().parent().unwrap().parent().unwrap();
+    assert_eq!(group.kind(), SyntaxKind::GroupGraphPattern);
+    assert!(group.to_string().ends_with('}'));
+
+    let input = "ASK { ?s ?p [ <a> ?b . ?x ?y ?z }";
+    let root = parse_query(input);
+    assert_eq!(count(&root, SyntaxKind::BlankNodePropertyListPath), 1);
+    assert_eq!(count(&root, SyntaxKind::TriplesSameSubjectPath), 2);
+    assert!(!has_errors(&root));
+
+    let input = "SELECT * WHERE { ?s ?p ?o FILTER(<f>(?a, ?b } LIMIT 1";
+    let parse = parse_query_with_diagnostics(input);
+    let root = parse.syntax();
+    let arg_list = root
+        .descendants()
+        .find(|node| node.kind() == SyntaxKind::ArgList)
+        .unwrap();
+    assert_eq!(arg_list.to_string(), "(?a, ?b ");
+    assert_eq!(count(&root, SyntaxKind::LimitClause), 1);
+    assert!(!has_errors(&root));
+    assert_eq!(parse.diagnostics().len(), 2);
+}
+
+#[test]
+fn recovery_skips_balanced_groups() {
+    let input = "ASK { FILTER(?a ?b (?c . ?d) ?e) ?x ?y ?z }";
+    let parse = parse_query_with_diagnostics(input);
+    let root = parse.syntax();
+    let error = root
+        .descendants()
+        .find(|node| node.kind() == SyntaxKind::Error)
+        .unwrap();
+    assert_eq!(error.to_string(), "?b (?c . ?d) ?e");
+    assert_eq!(count(&root, SyntaxKind::BrackettedExpression), 1);
+    assert_eq!(count(&root, SyntaxKind::TriplesSameSubjectPath), 1);
+    assert_eq!(parse.diagnostics().len(), 1);
+}
+
+#[test]
+fn recovery_keeps_nested_groups_closed() {
+    let input = "ASK { OPTIONAL { ?s ?p 1 AS } ?x ?y ?z }";
+    let parse = parse_query_with_diagnostics(input);
+    let root = parse.syntax();
+    let optional = root
+        .descendants()
+        .find(|node| node.kind() == SyntaxKind::OptionalGraphPattern)
+        .unwrap();
+    assert_eq!(optional.to_string(), "OPTIONAL { ?s ?p 1 AS }");
+    assert_eq!(count(&root, SyntaxKind::TriplesSameSubjectPath), 2);
+    assert_eq!(parse.diagnostics().len(), 1);
+}
+
+/// The `Missing` nodes with the kinds expected by the diagnostic at their position.
+fn missing(parse: &Parse) -> Vec<(TextRange, Vec<SyntaxKind>)> {
+    parse
+        .syntax()
+        .descendants()
+        .filter(|node| node.kind() == SyntaxKind::Missing)
+        .map(|node| {
+            assert!(node.first_child_or_token().is_none());
+            let range = node.text_range();
+            let expected = parse
+                .diagnostics()
+                .iter()
+                .find(|diagnostic| diagnostic.range.start() == range.start())
+                .map_or_else(Vec::new, |diagnostic| diagnostic.expected.clone());
+            (range, expected)
+        })
+        .collect()
+}
+
+#[test]
+fn missing_tokens_are_zero_width_nodes() {
+    let input = "SELECT * WHERE { BIND(?x AS ) }";
+    let parse = parse_query_with_diagnostics(input);
+    let root = parse.syntax();
+    assert_eq!(root.to_string(), input);
+    assert_eq!(
+        missing(&parse),
+        vec![(
+            TextRange::empty(TextSize::new(28)),
+            vec![SyntaxKind::VAR1, SyntaxKind::VAR2]
+        )]
+    );
+    let bind = root
+        .descendants()
+        .find(|node| node.kind() == SyntaxKind::Bind)
+        .unwrap();
+    assert!(bind
+        .descendants()
+        .any(|node| node.kind() == SyntaxKind::Missing));
+
+    let input = "SELECT * WHERE { BIND(?x ?y) }";
+    let parse = parse_query_with_diagnostics(input);
+    assert_eq!(
+        missing(&parse),
+        vec![(TextRange::empty(TextSize::new(25)), vec![SyntaxKind::AS])]
+    );
+    assert!(!has_errors(&parse.syntax()));
+}
+
+#[test]
+fn skipped_tokens_are_not_missing() {
+    let parse = parse_query_with_diagnostics("SELECT * WHERE { BIND(?x AS ?y ?z) }");
+    assert!(missing(&parse).is_empty());
+    assert!(has_errors(&parse.syntax()));
+    assert!(missing(&parse_query_with_diagnostics("SELECT * WHERE { ?s ?p ?o }")).is_empty());
+}
+
+#[test]
+fn parse_fragments() {
+    let fragments = [
+        (
+            TopEntryPoint::Expression,
+            "?x + 1 > STRLEN(?name) && BOUND(?y)",
+        ),
+        (TopEntryPoint::Path, "^ex:parent+/ex:name|rdfs:label"),
+        (
+            TopEntryPoint::GroupGraphPattern,
+            "{ ?s ?p ?o OPTIONAL { ?s a ?c } }",
+        ),
+        (TopEntryPoint::Iri, "<http://example.org/>"),
+        (TopEntryPoint::Iri, "ex:local"),
+        (TopEntryPoint::RDFLiteral, "\"chat\"@fr"),
+        (TopEntryPoint::NumericLiteral, "-2.5e3"),
+        (TopEntryPoint::Var, "$x"),
+        (
+            TopEntryPoint::Prologue,
+            "PREFIX ex: <http://ex.org/> BASE <http://b/>",
+        ),
+        (TopEntryPoint::SolutionModifier, "ORDER BY DESC(?x) LIMIT 5"),
+        (TopEntryPoint::TriplesTemplate, "?s ?p ?o . ?s ?q [ ?r ?t ]"),
+        (TopEntryPoint::QuadData, "{ GRAPH <g> { <a> <b> <c> } }"),
+    ];
+    for (entry, input) in fragments {
+        let parse = parse_entry(input, entry);
+        let root = parse.syntax();
+        assert_eq!(root.kind(), entry.kind(), "{input}");
+        assert_eq!(root.to_string(), input);
+        assert!(
+            parse.diagnostics().is_empty(),
+            "{input}: {:?}",
+            parse.diagnostics()
+        );
+        assert!(!has_errors(&root), "{input}");
+    }
+}
+
+#[test]
+fn fragments_report_trailing_input() {
+    let input = "?x + 1 ?y";
+    let parse = parse_entry(input, TopEntryPoint::Expression);
+    let root = parse.syntax();
+    assert_eq!(root.kind(), SyntaxKind::Expression);
+    assert_eq!(root.last_child().unwrap().kind(), SyntaxKind::Error);
+    let [diagnostic] = parse.diagnostics() else {
+        panic!("expected one diagnostic, got {:?}", parse.diagnostics());
+    };
+    assert_eq!(&input[diagnostic.range], "?y");
+    assert_eq!(diagnostic.message, "unexpected VAR1");
+}
+
+#[test]
+fn every_entry_point_has_a_root() {
+    for entry in TopEntryPoint::ALL {
+        for input in ["", "  ", "}", "?x ?y"] {
+            let parse = parse_entry(input, *entry);
+            assert_eq!(parse.syntax().kind(), entry.kind(), "{entry:?} {input:?}");
+            assert_eq!(parse.syntax().to_string(), input);
+        }
+    }
+    let parse = parse_entry("?x", TopEntryPoint::Prologue);
+    assert_eq!(
+        parse.syntax().first_child().unwrap().kind(),
+        SyntaxKind::Error
+    );
+    assert_eq!(parse.diagnostics().len(), 1);
+}
+
+#[test]
+fn parse_detects_the_document_kind() {
+    let documents = [
+        ("SELECT * WHERE { ?s ?p ?o }", DocumentKind::Query),
+        (
+            "PREFIX ex: <http://ex.org/>\nask { ex:a ?p ?o }",
+            DocumentKind::Query,
+        ),
+        (
+            "BASE <http://b/> # comment\nCONSTRUCT WHERE { ?s ?p ?o }",
+            DocumentKind::Query,
+        ),
+        ("PREFIX : <http://ex.org/> DESCRIBE :a", DocumentKind::Query),
+        ("INSERT DATA { <a> <b> <c> }", DocumentKind::Update),
+        (
+            "PREFIX ex: <http://ex.org/> delete where { ?s ex:p ?o }",
+            DocumentKind::Update,
+        ),
+        (
+            "WITH <g> DELETE { ?s ?p ?o } WHERE { ?s ?p ?o }",
+            DocumentKind::Update,
+        ),
+        ("LOAD <http://ex.org/data>", DocumentKind::Update),
+    ];
+    for (input, kind) in documents {
+        let parse = parse(input);
+        assert_eq!(parse.document_kind(), kind, "{input}");
+        assert!(
+            parse.diagnostics().is_empty(),
+            "{input}: {:?}",
+            parse.diagnostics()
+        );
+        assert_eq!(parse.syntax().to_string(), input);
+    }
+}
+
+#[test]
+fn parse_reports_undecidable_documents() {
+    for input in [
+        "",
+        "  # only a comment",
+        "PREFIX ex: <http://ex.org/> BASE <b>",
+    ] {
+        let parse = parse(input);
+        assert_eq!(parse.document_kind(), DocumentKind::Unknown, "{input}");
+        assert_eq!(parse.syntax().kind(), SyntaxKind::Prologue);
+        assert!(parse.diagnostics().is_empty());
+    }
+    let input = "PREFIX ex: <http://ex.org/> { ?s ?p ?o }";
+    let parse = parse(input);
+    assert_eq!(parse.document_kind(), DocumentKind::Unknown);
+    assert_eq!(parse.syntax().to_string(), input);
+    assert_eq!(parse.diagnostics().len(), 1);
+}
+
+#[test]
+fn fragments_are_no_undecidable_documents() {
+    for (input, entry) in [
+        ("{ ?s ?p ?o }", TopEntryPoint::GroupGraphPattern),
+        ("?a + 1", TopEntryPoint::Expression),
+        ("PREFIX ex: <http://ex.org/>", TopEntryPoint::Prologue),
+        ("", TopEntryPoint::Prologue),
+    ] {
+        let parse = parse_entry(input, entry);
+        assert_eq!(parse.document_kind(), DocumentKind::Fragment, "{input}");
+    }
+    let parse = parse_entry("ASK {}", TopEntryPoint::QueryUnit);
+    assert_eq!(parse.document_kind(), DocumentKind::Query);
+}
+
+#[test]
+fn reparse_rejects_edits_that_do_not_fit() {
+    let text = "SELECT * { ?s ?p 'café' }";
+    let parse = parse(text);
+    let end = TextSize::of(text);
+    let outside = [
+        TextEdit::new(TextRange::empty(end + TextSize::new(1)), "x"),
+        TextEdit::new(
+            TextRange::new(end - TextSize::new(1), end + TextSize::new(3)),
+            "",
+        ),
+    ];
+    // `é` takes two bytes.
+    let e = TextSize::new(text.find('é').unwrap() as u32);
+    let splitting = [
+        TextEdit::new(TextRange::empty(e + TextSize::new(1)), "x"),
+        TextEdit::new(
+            TextRange::new(e + TextSize::new(1), e + TextSize::new(2)),
+            "e",
+        ),
+        TextEdit::new(TextRange::new(TextSize::new(0), e + TextSize::new(1)), ""),
+    ];
+    for edit in outside.iter().chain(&splitting) {
+        assert_eq!(edit.apply(text), None, "{edit:?}");
+        assert!(parse.reparse(edit).is_none(), "{edit:?}");
+    }
+    let edit = TextEdit::new(TextRange::at(e, TextSize::of('é')), "e");
+    let reparsed = parse.reparse(&edit).unwrap();
+    assert_eq!(reparsed.syntax().to_string(), "SELECT * { ?s ?p 'cafe' }");
+    let edit = TextEdit::new(TextRange::empty(end), " LIMIT 1");
+    assert!(parse.reparse(&edit).is_some());
+}
+
+#[test]
+fn reparse_detects_the_document_kind_again() {
+    let text = "PREFIX ex: <http://ex.org/> ";
+    let unknown = parse(text);
+    assert_eq!(unknown.document_kind(), DocumentKind::Unknown);
+    let edit = TextEdit::new(TextRange::empty(TextSize::of(text)), "SELECT * {}");
+    assert_eq!(
+        unknown.reparse(&edit).unwrap().document_kind(),
+        DocumentKind::Query
+    );
+    let edit = TextEdit::new(TextRange::empty(TextSize::of(text)), "CLEAR ALL");
+    assert_eq!(
+        unknown.reparse(&edit).unwrap().document_kind(),
+        DocumentKind::Update
+    );
+    // Explicitly chosen entry points are kept.
+    let query = parse_query_with_diagnostics(text);
+    assert_eq!(query.document_kind(), DocumentKind::Query);
+    assert_eq!(
+        query.reparse(&edit).unwrap().document_kind(),
+        DocumentKind::Query
+    );
+}
+
+/// Xorshift PRNG, so that fuzz failures are reproducible without dependencies.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next() % bound as u64) as usize
+    }
+
+    fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+        items[self.below(items.len())]
+    }
+}
+
+const FUZZ_TOKENS: &[&str] = &[
+    "SELECT",
+    "CONSTRUCT",
+    "DESCRIBE",
+    "ASK",
+    "WHERE",
+    "FROM",
+    "NAMED",
+    "PREFIX",
+    "BASE",
+    "DISTINCT",
+    "REDUCED",
+    "AS",
+    "GROUP",
+    "BY",
+    "HAVING",
+    "ORDER",
+    "ASC",
+    "DESC",
+    "LIMIT",
+    "OFFSET",
+    "VALUES",
+    "OPTIONAL",
+    "UNION",
+    "MINUS",
+    "GRAPH",
+    "SERVICE",
+    "SILENT",
+    "BIND",
+    "FILTER",
+    "EXISTS",
+    "NOT",
+    "IN",
+    "UNDEF",
+    "a",
+    "true",
+    "false",
+    "INSERT",
+    "DELETE",
+    "INSERT DATA",
+    "DELETE DATA",
+    "DELETE WHERE",
+    "WITH",
+    "USING",
+    "LOAD",
+    "INTO",
+    "CLEAR",
+    "DROP",
+    "CREATE",
+    "ADD",
+    "MOVE",
+    "COPY",
+    "TO",
+    "DEFAULT",
+    "ALL",
+    "COUNT",
+    "SUM",
+    "GROUP_CONCAT",
+    "SEPARATOR",
+    "STR",
+    "REGEX",
+    "SUBSTR",
+    "REPLACE",
+    "IF",
+    "COALESCE",
+    "{",
+    "}",
+    "(",
+    ")",
+    "[",
+    "]",
+    "()",
+    "[]",
+    ".",
+    ",",
+    ";",
+    "*",
+    "+",
+    "-",
+    "/",
+    "|",
+    "||",
+    "&&",
+    "^",
+    "^^",
+    "?",
+    "!",
+    "!=",
+    "=",
+    "<",
+    ">",
+    "<=",
+    ">=",
+    "?x",
+    "$y",
+    "<http://a.b/c>",
+    "ex:",
+    "ex:local",
+    ":",
+    "_:b0",
+    "'s'",
+    "\"s\"",
+    "'''long'''",
+    "@en",
+    "1",
+    "1.5",
+    "1e3",
+    "+2",
+    "-3",
+    "\"unterminated",
+    "§",
+    "\\u0041",
+    "# comment\n",
+];
+
+fn fuzz_input(rng: &mut Rng) -> String {
+    let mut input = String::new();
+    for _ in 0..rng.below(48) {
+        input.push_str(rng.pick(FUZZ_TOKENS));
+        input.push_str(rng.pick(&[" ", " ", "\n", ""]));
+    }
+    input
+}
+
+/// Parses `input` from every entry point, with and without compact expressions.
+fn assert_lossless(input: &str) {
+    let compact = super::ParseOptions {
+        compact_expressions: true,
+        ..Default::default()
+    };
+    for options in [Default::default(), compact] {
+        for entry in TopEntryPoint::ALL {
+            let parse = crate::parse_with_options(input, Some(*entry), options);
+            assert_eq!(parse.syntax().to_string(), input, "{entry:?} {options:?}");
+        }
+    }
+}
+
+#[test]
+fn fuzz_random_token_sequences() {
+    let mut rng = Rng(0x2545_F491_4F6C_DD1D);
+    for _ in 0..500 {
+        let input = fuzz_input(&mut rng);
+        assert_lossless(&input);
+    }
+}
+
+#[test]
+fn fuzz_random_characters() {
+    let alphabet = [
+        "{", "}", "(", ")", "[", "]", "<", ">", "'", "\"", "\\", "?", ":", ".", ";", ",", "#",
+        "\n", " ", "a", "S", "1", "e", "_", "-", "é", "\u{0}",
+    ];
+    let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+    for _ in 0..500 {
+        let input: String = (0..rng.below(64)).map(|_| rng.pick(&alphabet)).collect();
+        assert_lossless(&input);
+    }
+}
+
+#[test]
+fn exhausted_fuel_skips_the_current_token() {
+    let input = "?x ?y";
+    let tokens = lex(input).input;
+    let mut parser = super::Parser::new(&tokens, TextSize::of(input), Default::default());
+    parser.fuel = 0;
+    assert_eq!(parser.nth(0), SyntaxKind::VAR1);
+    assert_eq!(parser.pos, 1);
+    assert_eq!(parser.diagnostics.len(), 1);
+    assert_eq!(&input[parser.diagnostics[0].range], "?x");
+}
+
+const REPARSE_CORPUS: &[&str] = &[
+    "SELECT * WHERE { ?s ?p ?o . ?s <http://a.b/c> \"x\"@en ; ex:p 1, 2.5 }",
+    "PREFIX ex: <http://ex.org/> SELECT ?x WHERE { ?x ex:p [ ex:q ?y ] FILTER(?y > 3 && (?x != ?y)) }",
+    "SELECT ?x WHERE { { ?x ?p ?o } UNION { ?x a ex:C } OPTIONAL { ?x ex:name ?name } } LIMIT 10",
+    "ASK { ?s ?p ?o BIND((?o + 1) AS ?n) FILTER(REGEX(STR(?s), 'a')) } # done\n",
+    "INSERT DATA { <a> <b> <c> . <a> <b> 'd' }",
+    "DELETE { ?s ?p ?o } INSERT { ?s ?p (1 2) } WHERE { ?s ?p ?o MINUS { ?s ?q ?r } }",
+    "SELECT * { ?s ?p ?o . OPTIONAL { ?s ex:x ?y ",
+];
+
+const REPARSE_EDITS: &[&str] = &[
+    "",
+    "",
+    " ",
+    "\n",
+    "x",
+    "?",
+    "1",
+    ".",
+    ";",
+    ",",
+    "{",
+    "}",
+    "(",
+    ")",
+    "[",
+    "]",
+    "\"",
+    "#",
+    ":",
+    "?z",
+    "ex:",
+    "<",
+    ">",
+    "a",
+    "FILTER",
+    "OPTIONAL {",
+    "DATA",
+    "\\u0041",
+    "§",
+];
+
+#[test]
+fn reparse_matches_full_parse() {
+    let mut rng = Rng(0xD1B5_4A32_D192_ED03);
+    for _ in 0..600 {
+        let mut text = rng.pick(REPARSE_CORPUS).to_string();
+        let update = text.contains("INSERT") || text.contains("DELETE");
+        let entry = || match update {
+            true => TopEntryPoint::UpdateUnit,
+            false => TopEntryPoint::QueryUnit,
+        };
+        let mut parse = parse_text(&text, entry());
+        for _ in 0..5 {
+            let boundaries: Vec<usize> = text
+                .char_indices()
+                .map(|(index, _)| index)
+                .chain([text.len()])
+                .collect();
+            let start = rng.below(boundaries.len());
+            let end = (start + rng.below(4)).min(boundaries.len() - 1);
+            let range = TextRange::new(
+                TextSize::new(boundaries[start] as u32),
+                TextSize::new(boundaries[end] as u32),
+            );
+            let edit = TextEdit::new(range, rng.pick(REPARSE_EDITS));
+            let edited = edit.apply(&text).unwrap();
+            let incremental = parse.reparse(&edit).unwrap();
+            let full = parse_text(&edited, entry());
+            assert_eq!(
+                incremental.green_node(),
+                full.green_node(),
+                "{text:?} with {edit:?}"
+            );
+            assert_eq!(
+                incremental.diagnostics(),
+                full.diagnostics(),
+                "{text:?} with {edit:?}"
+            );
+            text = edited;
+            parse = incremental;
+        }
+    }
+}
+
+#[test]
+fn reparse_reuses_untouched_subtrees() {
+    let text = "SELECT * WHERE { { ?s ?p ?o } OPTIONAL { ?s ?q ?r } FILTER(?o > 1) }";
+    let parse = parse_query_with_diagnostics(text);
+    let optional = |root: &SyntaxNode| {
+        root.descendants()
+            .find(|node| node.kind() == SyntaxKind::OptionalGraphPattern)
+            .unwrap()
+    };
+    let edits = [
+        // Inside a variable, only the token is relexed.
+        TextEdit::new(
+            TextRange::at(TextSize::new(20), TextSize::new(1)),
+            "subject",
+        ),
+        // A new triple, the enclosing group is reparsed.
+        TextEdit::new(TextRange::empty(TextSize::new(27)), " . ?a ?b ?c"),
+        // Inside the filter, the bracketted expression is reparsed.
+        TextEdit::new(TextRange::at(TextSize::new(64), TextSize::new(1)), "2 + ?s"),
+    ];
+    for edit in edits {
+        let reparsed = parse.reparse(&edit).unwrap();
+        let edited = edit.apply(text).unwrap();
+        assert_eq!(reparsed.syntax().to_string(), edited);
+        assert_eq!(
+            reparsed.green_node(),
+            parse_query_with_diagnostics(&edited).green_node()
+        );
+        let (old, new) = (optional(&parse.syntax()), optional(&reparsed.syntax()));
+        assert!(
+            std::ptr::eq(&*old.green(), &*new.green()),
+            "OptionalGraphPattern was rebuilt for {edit:?}"
+        );
+    }
+}
+
+fn too_deep(parse: &super::Parse) -> usize {
+    parse
+        .diagnostics()
+        .iter()
+        .filter(|diagnostic| diagnostic.message.starts_with("brackets nested deeper"))
+        .count()
+}
+
+#[test]
+fn deep_nesting_does_not_overflow_the_stack() {
+    let depth = 10_000;
+    let inputs = [
+        format!(
+            "SELECT * {{ FILTER({}?x{}) }}",
+            "(".repeat(depth),
+            ")".repeat(depth)
+        ),
+        format!("SELECT * {{ FILTER({}?x", "(".repeat(depth)),
+        format!("SELECT * {}{}", "{".repeat(depth), "}".repeat(depth)),
+        format!("SELECT * {}", "{ ?s ?p ?o . ".repeat(depth)),
+        format!(
+            "SELECT * {{ ?s ?p {}{} }}",
+            "[ ?q ".repeat(depth),
+            "]".repeat(depth)
+        ),
+        format!("SELECT * {{ ?s ?p {} }}", "( ".repeat(depth)),
+        format!("SELECT * {{ {}", "{ FILTER([ ".repeat(depth)),
+        format!(
+            "INSERT DATA {{ <s> <p> {}{} }}",
+            "( ".repeat(depth),
+            ")".repeat(depth)
+        ),
+    ];
+    for input in inputs {
+        let parse = parse(&input);
+        assert_eq!(parse.syntax().to_string(), input);
+        assert_eq!(too_deep(&parse), 1, "{}", &input[..40]);
+    }
+
+    // Long chains of triples or updates are no nesting.
+    let length = 30_000;
+    let chains = [
+        format!("SELECT * {{ {}}}", "?s ?p ?o . ".repeat(length)),
+        format!("CONSTRUCT {{ {}}} WHERE {{}}", "?s ?p ?o . ".repeat(length)),
+        format!("INSERT DATA {{ {}}}", "<s> <p> <o> . ".repeat(length)),
+        "CLEAR ALL ; ".repeat(length),
+    ];
+    for input in chains {
+        let parse = parse(&input);
+        assert_eq!(parse.syntax().to_string(), input);
+        assert!(parse.diagnostics().is_empty(), "{}", &input[..40]);
+    }
+}
+
+#[test]
+fn nesting_limit_is_configurable() {
+    let options = super::ParseOptions {
+        max_nesting: 2,
+        ..Default::default()
+    };
+    let parse = crate::parse_with_options("((?x))", Some(TopEntryPoint::Expression), options);
+    assert!(parse.diagnostics().is_empty());
+
+    let input = "((( ?x + ?y ) * 2))";
+    let parse = crate::parse_with_options(input, Some(TopEntryPoint::Expression), options);
+    let root = parse.syntax();
+    assert_eq!(root.to_string(), input);
+    let [diagnostic] = parse.diagnostics() else {
+        panic!("{:?}", parse.diagnostics());
+    };
+    assert_eq!(
+        diagnostic.range,
+        TextRange::at(TextSize::new(2), TextSize::new(1))
+    );
+    assert_eq!(diagnostic.rule, Some(SyntaxKind::BrackettedExpression));
+    let error = root
+        .descendants()
+        .find(|node| node.kind() == SyntaxKind::Error)
+        .unwrap();
+    assert_eq!(error.to_string(), "?x + ?y");
+    // The expression inside of the group is missing, its closing bracket is not.
+    let ranges: Vec<_> = missing(&parse)
+        .into_iter()
+        .map(|(range, _)| range)
+        .collect();
+    assert_eq!(ranges, vec![TextRange::empty(TextSize::new(12))]);
+}
+
+#[test]
+fn reparse_keeps_the_nesting_limit() {
+    let options = super::ParseOptions {
+        max_nesting: 3,
+        ..Default::default()
+    };
+    let text = "SELECT * WHERE { FILTER((?x)) OPTIONAL { ?s ?p ?o } }";
+    let parse = crate::parse_with_options(text, None, options);
+    assert!(parse.diagnostics().is_empty());
+    let edits = [
+        (27, " + 1"),
+        (27, " + (1)"),
+        (49, " . ?a ?b ?c"),
+        (49, " ; ?q [ ?a [ ?b ?c ] ]"),
+    ];
+    for (offset, insert) in edits {
+        let edit = TextEdit::new(TextRange::empty(TextSize::new(offset)), insert);
+        let edited = edit.apply(text).unwrap();
+        let reparsed = parse.reparse(&edit).unwrap();
+        let full = crate::parse_with_options(&edited, None, options);
+        assert_eq!(reparsed.green_node(), full.green_node(), "{edited}");
+        assert_eq!(reparsed.diagnostics(), full.diagnostics(), "{edited}");
+        assert_eq!(
+            too_deep(&full),
+            usize::from(insert.contains(['(', '['])),
+            "{edited}"
+        );
+    }
+}
+
+const COMPACT: super::ParseOptions = super::ParseOptions {
+    max_nesting: 128,
+    compact_expressions: true,
+};
+
+/// Prints compact expressions as s-expressions with the operator first.
+fn expression_shape(node: &SyntaxNode) -> String {
+    match node.kind() {
+        SyntaxKind::BinaryExpr | SyntaxKind::UnaryExpr | SyntaxKind::InExpr => {
+            let operator: Vec<_> = node
+                .children_with_tokens()
+                .filter_map(|element| element.into_token())
+                .filter(|token| !token.kind().is_trivia())
+                .map(|token| token.text().to_string())
+                .collect();
+            let operands: Vec<_> = node
+                .children()
+                .map(|child| expression_shape(&child))
+                .collect();
+            format!("({} {})", operator.join(" "), operands.join(" "))
+        }
+        _ => node.text().to_string().trim().to_string(),
+    }
+}
+
+fn compact_expression(input: &str) -> String {
+    let parse = crate::parse_with_options(input, Some(TopEntryPoint::Expression), COMPACT);
+    assert!(parse.diagnostics().is_empty(), "{:?}", parse.diagnostics());
+    let root = parse.syntax();
+    assert_eq!(root.to_string(), input);
+    let [expression] = &root.children().collect::<Vec<_>>()[..] else {
+        panic!("{root:#?}");
+    };
+    expression_shape(expression)
+}
+
+#[test]
+fn compact_expressions_follow_precedence() {
+    assert_eq!(
+        compact_expression("?a || ?b && !?c = 1 + 2 * ?d"),
+        "(|| ?a (&& ?b (= (! ?c) (+ 1 (* 2 ?d)))))"
+    );
+    assert_eq!(compact_expression("?a - ?b - ?c"), "(- (- ?a ?b) ?c)");
+    assert_eq!(compact_expression("?a / ?b * ?c"), "(* (/ ?a ?b) ?c)");
+    assert_eq!(compact_expression("(?a || ?b) && ?c"), "(&& (?a || ?b) ?c)");
+    assert_eq!(compact_expression("-?a < +?b"), "(< (- ?a) (+ ?b))");
+    assert_eq!(compact_expression("?x IN (1, 2)"), "(IN ?x (1, 2))");
+    assert_eq!(
+        compact_expression("?x + 1 NOT IN ()"),
+        "(NOT IN (+ ?x 1) ())"
+    );
+    // The sign of the literal is the operator, the literal starts a product.
+    assert_eq!(compact_expression("?a -1 * ?b"), "(- ?a (* 1 ?b))");
+    assert_eq!(compact_expression("?a +2 -.3e1"), "(- (+ ?a 2) .3e1)");
+    assert_eq!(compact_expression(r"?a \u002D1"), r"(\u002D ?a 1)");
+}
+
+#[test]
+fn compact_expressions_are_shallow() {
+    let options = COMPACT;
+    let parse = crate::parse_with_options("?x", Some(TopEntryPoint::Expression), options);
+    let kinds: Vec<_> = parse
+        .syntax()
+        .descendants()
+        .map(|node| node.kind())
+        .collect();
+    assert_eq!(
+        kinds,
+        [
+            SyntaxKind::Expression,
+            SyntaxKind::PrimaryExpression,
+            SyntaxKind::Var
+        ]
+    );
+    let cascade = parse_entry("?x", TopEntryPoint::Expression);
+    assert_eq!(cascade.syntax().descendants().count(), 11);
+}
+
+#[test]
+fn compact_expressions_recover_like_the_cascade() {
+    let mut rng = Rng(0x2545_F491_4F6C_DD1D);
+    let inputs = REPARSE_CORPUS
+        .iter()
+        .map(|input| input.to_string())
+        .chain([
+            "SELECT * { FILTER(?a = ?b = ?c) }".to_string(),
+            "SELECT * { FILTER(?a && ?b = ?c = ?d) }".to_string(),
+            "SELECT * { FILTER(?a || ?b IN (1) != ?c && ?d) }".to_string(),
+            "SELECT * { FILTER(?a + ) BIND(! AS ?x) }".to_string(),
+            "SELECT * { FILTER(?a NOT ?b) FILTER(?a IN ?b) }".to_string(),
+            "SELECT (?a * AS ?b) { }".to_string(),
+        ])
+        .chain((0..500).map(|_| fuzz_input(&mut rng)));
+    for input in inputs {
+        let cascade = parse_text(&input, TopEntryPoint::QueryUnit);
+        let compact = crate::parse_with_options(&input, Some(TopEntryPoint::QueryUnit), COMPACT);
+        assert_eq!(compact.syntax().to_string(), input);
+        let errors = |parse: &super::Parse| {
+            parse
+                .diagnostics()
+                .iter()
+                .map(|diagnostic| (diagnostic.range, diagnostic.message.clone()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(errors(&compact), errors(&cascade), "{input}");
+    }
+}
+
+#[test]
+fn reparse_keeps_compact_expressions() {
+    let text = "SELECT * WHERE { FILTER((?x + 1) > 2) OPTIONAL { ?s ?p ?o } }";
+    let parse = crate::parse_with_options(text, None, COMPACT);
+    for (offset, insert) in [(31, " * ?y"), (27, " || ?z")] {
+        let edit = TextEdit::new(TextRange::empty(TextSize::new(offset)), insert);
+        let edited = edit.apply(text).unwrap();
+        let reparsed = parse.reparse(&edit).unwrap();
+        let full = crate::parse_with_options(&edited, None, COMPACT);
+        assert_eq!(reparsed.green_node(), full.green_node(), "{edited}");
+        assert_eq!(
+            count(&reparsed.syntax(), SyntaxKind::ConditionalOrExpression),
+            0
+        );
+    }
+}
+
+#[test]
+fn collapsed_trees_elide_wrappers() {
+    let input = "PREFIX ex: <http://ex.org/> SELECT * WHERE { ?s ex:p/ex:q ?o ; a [ ex:r 1 ] FILTER(?o > 1) }";
+    let parse = parse_query_with_diagnostics(input);
+    let collapsed = parse.collapsed(WRAPPER_KINDS);
+    assert_eq!(collapsed.to_string(), input);
+    assert!(collapsed.descendants().count() < parse.syntax().descendants().count() / 2);
+    for node in collapsed.descendants() {
+        let single_node = node.children().count() == 1 && node.children_with_tokens().count() == 1;
+        assert!(
+            !(WRAPPER_KINDS.contains(&node.kind()) && single_node),
+            "{node:?} was not elided"
+        );
+    }
+    // Wrappers around a single token are kept.
+    assert!(collapsed
+        .descendants()
+        .any(|node| node.kind() == SyntaxKind::PathPrimary));
+    let subject = collapsed
+        .descendants()
+        .find(|node| node.kind() == SyntaxKind::TriplesSameSubjectPath)
+        .and_then(|node| node.first_child())
+        .unwrap();
+    assert_eq!(subject.kind(), SyntaxKind::Var);
+    // The full tree is unchanged and subtrees without wrappers are shared.
+    assert_eq!(count(&parse.syntax(), SyntaxKind::VarOrTerm), 3);
+    let prologue = |root: &SyntaxNode| {
+        root.descendants()
+            .find(|node| node.kind() == SyntaxKind::Prologue)
+            .unwrap()
+    };
+    assert!(std::ptr::eq(
+        &*prologue(&parse.syntax()).green(),
+        &*prologue(&collapsed).green()
+    ));
+}
+
+#[test]
+fn collapsed_trees_keep_the_root() {
+    let parse = parse_entry("?x", TopEntryPoint::VarOrTerm);
+    let collapsed = parse.collapsed(WRAPPER_KINDS);
+    assert_eq!(collapsed.kind(), SyntaxKind::VarOrTerm);
+    assert_eq!(collapsed.first_child().unwrap().kind(), SyntaxKind::Var);
+    let parse = parse_entry("?x", TopEntryPoint::Expression);
+    let kinds: Vec<_> = parse
+        .collapsed(WRAPPER_KINDS)
+        .descendants()
+        .map(|node| node.kind())
+        .collect();
+    assert_eq!(kinds, [SyntaxKind::Expression, SyntaxKind::Var]);
+    assert_eq!(parse.collapsed(&[]).green(), parse.syntax().green());
+}
+
+#[test]
+fn shared_node_cache_deduplicates_across_documents() {
+    let first = "SELECT ?item WHERE { ?item wdt:P31 wd:Q5 }";
+    let second = "SELECT * WHERE { ?item wdt:P31 wd:Q146 }";
+    let mut cache = crate::NodeCache::default();
+    let options = super::ParseOptions::default();
+    let first = crate::parse_with_cache(first, None, options, &mut cache);
+    let second = crate::parse_with_cache(second, None, options, &mut cache);
+    assert_eq!(
+        second.green_node(),
+        parse(&second.syntax().to_string()).green_node()
+    );
+    let property = |parse: &super::Parse| {
+        parse
+            .syntax()
+            .descendants()
+            .find(|node| node.kind() == SyntaxKind::PrefixedName)
+            .unwrap()
+            .green()
+            .into_owned()
+    };
+    assert!(std::ptr::eq(&*property(&first), &*property(&second)));
+    let separate = parse(&first.syntax().to_string());
+    assert!(!std::ptr::eq(&*property(&separate), &*property(&second)));
+}
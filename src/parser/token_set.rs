@@ -0,0 +1,37 @@
+use rowan::Language;
+
+use crate::{Sparql, SyntaxKind};
+
+const WORDS: usize = SyntaxKind::LAST as usize / 64 + 1;
+
+/// A set of syntax kinds, stored as a bitset.
+///
+/// The generated parser checks the FIRST and recovery sets of its rules with
+/// constant token sets, so that a check is a single lookup instead of a scan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct TokenSet([u64; WORDS]);
+
+impl TokenSet {
+    pub(super) const fn new(kinds: &[SyntaxKind]) -> TokenSet {
+        let mut words = [0; WORDS];
+        let mut index = 0;
+        while index < kinds.len() {
+            let kind = kinds[index] as usize;
+            words[kind / 64] |= 1 << (kind % 64);
+            index += 1;
+        }
+        TokenSet(words)
+    }
+
+    pub(super) const fn contains(&self, kind: SyntaxKind) -> bool {
+        let kind = kind as usize;
+        self.0[kind / 64] & (1 << (kind % 64)) != 0
+    }
+
+    /// The kinds in the set, ordered by their discriminant.
+    pub(super) fn iter(self) -> impl Iterator<Item = SyntaxKind> {
+        (0..=SyntaxKind::LAST as u16)
+            .map(|raw| Sparql::kind_from_raw(rowan::SyntaxKind(raw)))
+            .filter(move |kind| self.contains(*kind))
+    }
+}
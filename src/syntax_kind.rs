@@ -1,3 +1,4 @@
+mod callbacks;
 mod generated;
 pub use generated::SyntaxKind::{self};
 
@@ -9,6 +10,6 @@ impl From<SyntaxKind> for rowan::SyntaxKind {
 
 impl SyntaxKind {
     pub fn is_trivia(&self) -> bool {
-        *self == SyntaxKind::WHITESPACE
+        matches!(self, SyntaxKind::WHITESPACE | SyntaxKind::COMMENT)
     }
 }
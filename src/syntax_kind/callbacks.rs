@@ -0,0 +1,238 @@
+//! Lexer callbacks for terminals that can not be expressed as a single
+//! logos pattern.
+use logos::Lexer;
+
+use super::SyntaxKind;
+
+/// Completes a numeric literal.
+///
+/// The patterns only match the sign and the leading digits (or `.` digits),
+/// the remainder is consumed here. Logos does not backtrack, so lexing
+/// `1.` with separate DECIMAL and DOUBLE patterns would fail instead of
+/// producing `INTEGER` followed by `.`.
+///
+/// [146] INTEGER ::= [0-9]+
+/// [147] DECIMAL ::= [0-9]* '.' [0-9]+
+/// [148] DOUBLE ::= [0-9]+ '.' [0-9]* EXPONENT | '.' ([0-9])+ EXPONENT | ([0-9])+ EXPONENT
+/// [155] EXPONENT ::= [eE] [+-]? [0-9]+
+pub(super) fn numeric(lex: &mut Lexer<SyntaxKind>) -> SyntaxKind {
+    let mut fraction = lex.slice().contains('.');
+    let remainder = lex.remainder().as_bytes();
+    let mut len = 0;
+    if !fraction && remainder.first() == Some(&b'.') {
+        let digits = count_digits(&remainder[1..]);
+        if digits > 0 {
+            fraction = true;
+            len = 1 + digits;
+        } else if exponent_len(&remainder[1..]) > 0 {
+            len = 1;
+        }
+    }
+    let exponent = exponent_len(&remainder[len..]);
+    lex.bump(len + exponent);
+
+    let sign = lex.slice().as_bytes()[0];
+    match (sign, exponent > 0, fraction) {
+        (b'+', true, _) => SyntaxKind::DOUBLE_POSITIVE,
+        (b'+', false, true) => SyntaxKind::DECIMAL_POSITIVE,
+        (b'+', false, false) => SyntaxKind::INTEGER_POSITIVE,
+        (b'-', true, _) => SyntaxKind::DOUBLE_NEGATIVE,
+        (b'-', false, true) => SyntaxKind::DECIMAL_NEGATIVE,
+        (b'-', false, false) => SyntaxKind::INTEGER_NEGATIVE,
+        (_, true, _) => SyntaxKind::DOUBLE,
+        (_, false, true) => SyntaxKind::DECIMAL,
+        (_, false, false) => SyntaxKind::INTEGER,
+    }
+}
+
+fn count_digits(input: &[u8]) -> usize {
+    input
+        .iter()
+        .take_while(|char| char.is_ascii_digit())
+        .count()
+}
+
+fn exponent_len(input: &[u8]) -> usize {
+    match input.first() {
+        Some(b'e' | b'E') => {
+            let sign = usize::from(matches!(input.get(1), Some(b'+' | b'-')));
+            match count_digits(&input[1 + sign..]) {
+                0 => 0,
+                digits => 1 + sign + digits,
+            }
+        }
+        _ => 0,
+    }
+}
+
+/// Turns `(` into `NIL` if only whitespace separates it from a `)`.
+///
+/// [161] NIL ::= '(' WS* ')'
+pub(super) fn nil(lex: &mut Lexer<SyntaxKind>) -> SyntaxKind {
+    match closing_after_whitespace(lex, b')') {
+        true => SyntaxKind::NIL,
+        false => SyntaxKind::LParen,
+    }
+}
+
+/// Turns `[` into `ANON` if only whitespace separates it from a `]`.
+///
+/// [163] ANON ::= '[' WS* ']'
+pub(super) fn anon(lex: &mut Lexer<SyntaxKind>) -> SyntaxKind {
+    match closing_after_whitespace(lex, b']') {
+        true => SyntaxKind::ANON,
+        false => SyntaxKind::LBrack,
+    }
+}
+
+fn closing_after_whitespace(lex: &mut Lexer<SyntaxKind>, closing: u8) -> bool {
+    let remainder = lex.remainder().as_bytes();
+    let whitespace = remainder
+        .iter()
+        .take_while(|char| matches!(char, b' ' | b'\t' | b'\r' | b'\n'))
+        .count();
+    if remainder.get(whitespace) == Some(&closing) {
+        lex.bump(whitespace + 1);
+        return true;
+    }
+    false
+}
+
+/// Completes a blank node label after its `_:` prefix.
+/// A label may contain, but not end with, `.`.
+///
+/// [142] BLANK_NODE_LABEL ::= '_:' ( PN_CHARS_U | [0-9] ) ((PN_CHARS|'.')* PN_CHARS)?
+pub(super) fn blank_node_label(lex: &mut Lexer<SyntaxKind>) -> Result<SyntaxKind, ()> {
+    match lex.remainder().chars().next() {
+        Some(char) if is_pn_chars_u(char) || char.is_ascii_digit() => {}
+        _ => return Err(()),
+    }
+    let mut len = 0;
+    for (index, char) in lex.remainder().char_indices() {
+        if is_pn_chars(char) {
+            len = index + char.len_utf8();
+        } else if char != '.' {
+            break;
+        }
+    }
+    lex.bump(len);
+    Ok(SyntaxKind::BLANK_NODE_LABEL)
+}
+
+/// [164] PN_CHARS_BASE
+fn is_pn_chars_base(char: char) -> bool {
+    matches!(char,
+        'A'..='Z'
+        | 'a'..='z'
+        | '\u{00C0}'..='\u{00D6}'
+        | '\u{00D8}'..='\u{00F6}'
+        | '\u{00F8}'..='\u{02FF}'
+        | '\u{0370}'..='\u{037D}'
+        | '\u{037F}'..='\u{1FFF}'
+        | '\u{200C}'..='\u{200D}'
+        | '\u{2070}'..='\u{218F}'
+        | '\u{2C00}'..='\u{2FEF}'
+        | '\u{3001}'..='\u{D7FF}'
+        | '\u{F900}'..='\u{FDCF}'
+        | '\u{FDF0}'..='\u{FFFD}'
+        | '\u{10000}'..='\u{EFFFF}')
+}
+
+/// [165] PN_CHARS_U ::= PN_CHARS_BASE | '_'
+fn is_pn_chars_u(char: char) -> bool {
+    is_pn_chars_base(char) || char == '_'
+}
+
+/// [167] PN_CHARS ::= PN_CHARS_U | '-' | [0-9] | #x00B7 | [#x0300-#x036F] | [#x203F-#x2040]
+fn is_pn_chars(char: char) -> bool {
+    is_pn_chars_u(char)
+        || matches!(char,
+            '-'
+            | '0'..='9'
+            | '\u{00B7}'
+            | '\u{0300}'..='\u{036F}'
+            | '\u{203F}'..='\u{2040}')
+}
+
+/// Validates a `PNAME_NS` and extends it to a `PNAME_LN` if a local name
+/// follows the prefix. Neither the prefix nor the local name may end with `.`.
+///
+/// [140] PNAME_NS ::= PN_PREFIX? ':'
+/// [141] PNAME_LN ::= PNAME_NS PN_LOCAL
+/// [168] PN_PREFIX ::= PN_CHARS_BASE ((PN_CHARS|'.')* PN_CHARS)?
+/// [169] PN_LOCAL ::= (PN_CHARS_U | ':' | [0-9] | PLX ) ((PN_CHARS | '.' | ':' | PLX)* (PN_CHARS | ':' | PLX) )?
+pub(super) fn prefixed_name(lex: &mut Lexer<SyntaxKind>) -> Result<SyntaxKind, ()> {
+    // The pattern allows a trailing `.` in the prefix, since logos
+    // miscompiles the optional group of PN_PREFIX.
+    if lex.slice().ends_with(".:") {
+        return Err(());
+    }
+    let remainder = lex.remainder();
+    // Length of the local name up to its last character that is not a `.`.
+    let mut len = 0;
+    let mut offset = 0;
+    while let Some(char) = remainder[offset..].chars().next() {
+        let step = match plx_len(&remainder[offset..]) {
+            0 => {
+                let valid = match offset {
+                    0 => is_pn_chars_u(char) || char.is_ascii_digit() || char == ':',
+                    _ => is_pn_chars(char) || matches!(char, ':' | '.'),
+                };
+                if !valid {
+                    break;
+                }
+                char.len_utf8()
+            }
+            plx => plx,
+        };
+        offset += step;
+        if char != '.' {
+            len = offset;
+        }
+    }
+    match len {
+        0 => Ok(SyntaxKind::PNAME_NS),
+        _ => {
+            lex.bump(len);
+            Ok(SyntaxKind::PNAME_LN)
+        }
+    }
+}
+
+/// Length of the PLX at the start of `input`, or 0 if there is none.
+///
+/// [170] PLX ::= PERCENT | PN_LOCAL_ESC
+/// [171] PERCENT ::= '%' HEX HEX
+/// [173] PN_LOCAL_ESC ::= '\' ( '_' | '~' | '.' | '-' | '!' | '$' | '&' | "'" | '(' | ')' | '*' | '+' | ',' | ';' | '=' | '/' | '?' | '#' | '@' | '%' )
+fn plx_len(input: &str) -> usize {
+    match input.as_bytes() {
+        [b'%', high, low, ..] if high.is_ascii_hexdigit() && low.is_ascii_hexdigit() => 3,
+        [b'\\', escaped, ..] if is_pn_local_esc(*escaped) => 2,
+        _ => 0,
+    }
+}
+
+fn is_pn_local_esc(char: u8) -> bool {
+    matches!(
+        char,
+        b'_' | b'~'
+            | b'.'
+            | b'-'
+            | b'!'
+            | b'$'
+            | b'&'
+            | b'\''
+            | b'('
+            | b')'
+            | b'*'
+            | b'+'
+            | b','
+            | b';'
+            | b'='
+            | b'/'
+            | b'?'
+            | b'#'
+            | b'@'
+            | b'%'
+    )
+}
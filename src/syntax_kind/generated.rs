@@ -1,20 +1,41 @@
+use super::callbacks;
 use logos::Logos;
-
 #[allow(non_camel_case_types)]
 #[derive(Logos, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
 #[repr(u16)]
 pub enum SyntaxKind {
     Eof = 0,
     Error,
-    #[regex(r#"[ \t\n\f]+"#)]
+    /// Zero-width node without children at the position of an absent token.
+    /// The kinds that were expected are in the diagnostic reported at its position.
+    Missing,
+    /// `lhs op rhs` in a compact expression tree, see `ParseOptions::compact_expressions`.
+    /// After an operand, the sign of a numeric literal is split off as the operator,
+    /// like in `?a -1`.
+    BinaryExpr,
+    /// `op operand` in a compact expression tree.
+    UnaryExpr,
+    /// `lhs IN (...)` or `lhs NOT IN (...)` in a compact expression tree.
+    InExpr,
+    #[regex(r#"[ \t\r\n\f]+"#)]
     WHITESPACE,
+    #[regex(r#"#[^\r\n]*"#)]
+    COMMENT,
+    /// The second word of `INSERT DATA` and `DELETE DATA`, which the parser
+    /// reads together with the first as `INSERT_DATA` or `DELETE_DATA`.
+    #[token("DATA", ignore(case))]
+    DATA,
     #[token("BASE", ignore(case))]
     BASE,
     #[regex(r#"<[^<>\"{}|^`\\\u{00}-\u{20}]*>"#)]
     IRIREF,
     #[token("PREFIX", ignore(case))]
     PREFIX,
-    #[regex("[A-Za-z\u{00C0}-\u{00D6}\u{00D8}-\u{00F6}\u{00F8}-\u{02FF}\u{0370}-\u{037D}\u{037F}-\u{1FFF}\u{200C}-\u{200D}\u{2070}-\u{218F}\u{2C00}-\u{2FEF}\u{3001}-\u{D7FF}\u{F900}-\u{FDCF}\u{FDF0}-\u{FFFD}\u{10000}-\u{EFFFF}]([A-Za-z\u{00C0}-\u{00D6}\u{00D8}-\u{00F6}\u{00F8}-\u{02FF}\u{0370}-\u{037D}\u{037F}-\u{1FFF}\u{200C}-\u{200D}\u{2070}-\u{218F}\u{2C00}-\u{2FEF}\u{3001}-\u{D7FF}\u{F900}-\u{FDCF}\u{FDF0}-\u{FFFD}\u{10000}-\u{EFFFF}0-9\u{00B7}\u{0300}-\u{036F}\u{203F}-\u{2040}_.-]*[A-Za-z\u{00C0}-\u{00D6}\u{00D8}-\u{00F6}\u{00F8}-\u{02FF}\u{0370}-\u{037D}\u{037F}-\u{1FFF}\u{200C}-\u{200D}\u{2070}-\u{218F}\u{2C00}-\u{2FEF}\u{3001}-\u{D7FF}\u{F900}-\u{FDCF}\u{FDF0}-\u{FFFD}\u{10000}-\u{EFFFF}0-9\u{00B7}\u{0300}-\u{036F}\u{203F}-\u{2040}_-])?:")]
+    #[regex(
+        "[A-Za-z\u{00C0}-\u{00D6}\u{00D8}-\u{00F6}\u{00F8}-\u{02FF}\u{0370}-\u{037D}\u{037F}-\u{1FFF}\u{200C}-\u{200D}\u{2070}-\u{218F}\u{2C00}-\u{2FEF}\u{3001}-\u{D7FF}\u{F900}-\u{FDCF}\u{FDF0}-\u{FFFD}\u{10000}-\u{EFFFF}][A-Za-z\u{00C0}-\u{00D6}\u{00D8}-\u{00F6}\u{00F8}-\u{02FF}\u{0370}-\u{037D}\u{037F}-\u{1FFF}\u{200C}-\u{200D}\u{2070}-\u{218F}\u{2C00}-\u{2FEF}\u{3001}-\u{D7FF}\u{F900}-\u{FDCF}\u{FDF0}-\u{FFFD}\u{10000}-\u{EFFFF}0-9\u{00B7}\u{0300}-\u{036F}\u{203F}-\u{2040}_.-]*:",
+        callbacks::prefixed_name
+    )]
+    #[token(":", callbacks::prefixed_name)]
     PNAME_NS,
     #[token("SELECT", ignore(case))]
     SELECT,
@@ -22,7 +43,7 @@ pub enum SyntaxKind {
     DISTINCT,
     #[token("REDUCED", ignore(case))]
     REDUCED,
-    #[token("(")]
+    #[token("(", callbacks::nil)]
     LParen,
     #[token("AS", ignore(case))]
     AS,
@@ -60,7 +81,7 @@ pub enum SyntaxKind {
     DESC,
     #[token("LIMIT", ignore(case))]
     LIMIT,
-    #[token("INTEGER", ignore(case))]
+    #[regex(r"[0-9]+", callbacks::numeric)]
     INTEGER,
     #[token("OFFSET", ignore(case))]
     OFFSET,
@@ -88,19 +109,15 @@ pub enum SyntaxKind {
     MOVE,
     #[token("COPY", ignore(case))]
     COPY,
-    #[token("INSERT", ignore(case))]
-    INSERT,
-    #[token("DATA", ignore(case))]
-    DATA,
     INSERT_DATA,
-    #[regex(r"(?i)DELETE\s+DATA")]
     DELETE_DATA,
-    #[token(r"(?i)DELETE\s+WHERE")]
     DELETE_WHERE,
-    #[token("DELETE", ignore(case))]
-    DELETE,
     #[token("WITH", ignore(case))]
     WITH,
+    #[token("DELETE", ignore(case))]
+    DELETE,
+    #[token("INSERT", ignore(case))]
+    INSERT,
     #[token("USING", ignore(case))]
     USING,
     #[token("DEFAULT", ignore(case))]
@@ -117,7 +134,6 @@ pub enum SyntaxKind {
     SERVICE,
     #[token("BIND", ignore(case))]
     BIND,
-    #[token("NIL", ignore(case))]
     NIL,
     #[token("UNDEF", ignore(case))]
     UNDEF,
@@ -127,8 +143,8 @@ pub enum SyntaxKind {
     UNION,
     #[token("FILTER", ignore(case))]
     FILTER,
-    #[token(":")]
-    Colon,
+    #[token(",")]
+    Comma,
     #[token("a")]
     a,
     #[token("|")]
@@ -143,13 +159,17 @@ pub enum SyntaxKind {
     Plus,
     #[token("!")]
     ExclamationMark,
-    #[token("[")]
+    #[token("[", callbacks::anon)]
     LBrack,
     #[token("]")]
     RBrack,
-    #[regex("\\?[A-Za-z]+")]
+    #[regex(
+        "\\?[A-Za-z\u{00C0}-\u{00D6}\u{00D8}-\u{00F6}\u{00F8}-\u{02FF}\u{0370}-\u{037D}\u{037F}-\u{1FFF}\u{200C}-\u{200D}\u{2070}-\u{218F}\u{2C00}-\u{2FEF}\u{3001}-\u{D7FF}\u{F900}-\u{FDCF}\u{FDF0}-\u{FFFD}\u{10000}-\u{EFFFF}_0-9][A-Za-z\u{00C0}-\u{00D6}\u{00D8}-\u{00F6}\u{00F8}-\u{02FF}\u{0370}-\u{037D}\u{037F}-\u{1FFF}\u{200C}-\u{200D}\u{2070}-\u{218F}\u{2C00}-\u{2FEF}\u{3001}-\u{D7FF}\u{F900}-\u{FDCF}\u{FDF0}-\u{FFFD}\u{10000}-\u{EFFFF}_0-9\u{00B7}\u{0300}-\u{036F}\u{203F}-\u{2040}]*"
+    )]
     VAR1,
-    #[regex("\\$[A-Za-z]+")]
+    #[regex(
+        "\\$[A-Za-z\u{00C0}-\u{00D6}\u{00D8}-\u{00F6}\u{00F8}-\u{02FF}\u{0370}-\u{037D}\u{037F}-\u{1FFF}\u{200C}-\u{200D}\u{2070}-\u{218F}\u{2C00}-\u{2FEF}\u{3001}-\u{D7FF}\u{F900}-\u{FDCF}\u{FDF0}-\u{FFFD}\u{10000}-\u{EFFFF}_0-9][A-Za-z\u{00C0}-\u{00D6}\u{00D8}-\u{00F6}\u{00F8}-\u{02FF}\u{0370}-\u{037D}\u{037F}-\u{1FFF}\u{200C}-\u{200D}\u{2070}-\u{218F}\u{2C00}-\u{2FEF}\u{3001}-\u{D7FF}\u{F900}-\u{FDCF}\u{FDF0}-\u{FFFD}\u{10000}-\u{EFFFF}_0-9\u{00B7}\u{0300}-\u{036F}\u{203F}-\u{2040}]*"
+    )]
     VAR2,
     #[token("||")]
     DoublePipe,
@@ -167,176 +187,168 @@ pub enum SyntaxKind {
     LessEquals,
     #[token(">=")]
     MoreEquals,
-    #[token("IN")]
+    #[token("IN", ignore(case))]
     IN,
-    #[token("NOT")]
+    #[token("NOT", ignore(case))]
     NOT,
     #[token("-")]
     Minus,
-    #[token("STR")]
+    #[token("STR", ignore(case))]
     STR,
-    #[token("LANG")]
+    #[token("LANG", ignore(case))]
     LANG,
-    #[token("LANGMATCHES")]
+    #[token("LANGMATCHES", ignore(case))]
     LANGMATCHES,
-    #[token("DATATYPE")]
+    #[token("DATATYPE", ignore(case))]
     DATATYPE,
-    #[token("BOUND")]
+    #[token("BOUND", ignore(case))]
     BOUND,
-    #[token("IRI")]
+    #[token("IRI", ignore(case))]
     IRI,
-    #[token("URI")]
+    #[token("URI", ignore(case))]
     URI,
-    #[token("BNODE")]
+    #[token("BNODE", ignore(case))]
     BNODE,
-    #[token("RAND")]
+    #[token("RAND", ignore(case))]
     RAND,
-    #[token("ABS")]
+    #[token("ABS", ignore(case))]
     ABS,
-    #[token("CEIL")]
+    #[token("CEIL", ignore(case))]
     CEIL,
-    #[token("FLOOR")]
+    #[token("FLOOR", ignore(case))]
     FLOOR,
-    #[token("ROUND")]
+    #[token("ROUND", ignore(case))]
     ROUND,
-    #[token("CONCAT")]
+    #[token("CONCAT", ignore(case))]
     CONCAT,
-    #[token("STRLEN")]
+    #[token("STRLEN", ignore(case))]
     STRLEN,
-    #[token("UCASE")]
+    #[token("UCASE", ignore(case))]
     UCASE,
-    #[token("LCASE")]
+    #[token("LCASE", ignore(case))]
     LCASE,
-    #[token("ENCODE_FOR_URI")]
+    #[token("ENCODE_FOR_URI", ignore(case))]
     ENCODE_FOR_URI,
-    #[token("CONTAINS")]
+    #[token("CONTAINS", ignore(case))]
     CONTAINS,
-    #[token("STRSTARTS")]
+    #[token("STRSTARTS", ignore(case))]
     STRSTARTS,
-    #[token("STRENDS")]
+    #[token("STRENDS", ignore(case))]
     STRENDS,
-    #[token("STRBEFORE")]
+    #[token("STRBEFORE", ignore(case))]
     STRBEFORE,
-    #[token("STRAFTER")]
+    #[token("STRAFTER", ignore(case))]
     STRAFTER,
-    #[token("YEAR")]
+    #[token("YEAR", ignore(case))]
     YEAR,
-    #[token("MONTH")]
+    #[token("MONTH", ignore(case))]
     MONTH,
-    #[token("DAY")]
+    #[token("DAY", ignore(case))]
     DAY,
-    #[token("HOURS")]
+    #[token("HOURS", ignore(case))]
     HOURS,
-    #[token("MINUTES")]
+    #[token("MINUTES", ignore(case))]
     MINUTES,
-    #[token("SECONDS")]
+    #[token("SECONDS", ignore(case))]
     SECONDS,
-    #[token("TIMEZONE")]
+    #[token("TIMEZONE", ignore(case))]
     TIMEZONE,
-    #[token("TZ")]
+    #[token("TZ", ignore(case))]
     TZ,
-    #[token("NOW")]
+    #[token("NOW", ignore(case))]
     NOW,
-    #[token("UUID")]
+    #[token("UUID", ignore(case))]
     UUID,
-    #[token("STRUUID")]
+    #[token("STRUUID", ignore(case))]
     STRUUID,
-    #[token("MD5")]
+    #[token("MD5", ignore(case))]
     MD5,
-    #[token("SHA1")]
+    #[token("SHA1", ignore(case))]
     SHA1,
-    #[token("SHA256")]
+    #[token("SHA256", ignore(case))]
     SHA256,
-    #[token("SHA384")]
+    #[token("SHA384", ignore(case))]
     SHA384,
-    #[token("SHA512")]
+    #[token("SHA512", ignore(case))]
     SHA512,
-    #[token("COALESCE")]
+    #[token("COALESCE", ignore(case))]
     COALESCE,
-    #[token("IF")]
+    #[token("IF", ignore(case))]
     IF,
-    #[token("STRLANG")]
+    #[token("STRLANG", ignore(case))]
     STRLANG,
-    #[token("STRDT")]
+    #[token("STRDT", ignore(case))]
     STRDT,
-    #[token("sameTerm")]
+    #[token("sameTerm", ignore(case))]
     sameTerm,
-    #[token("isIRI")]
+    #[token("isIRI", ignore(case))]
     isIRI,
-    #[token("isURI")]
+    #[token("isURI", ignore(case))]
     isURI,
-    #[token("isBLANK")]
+    #[token("isBLANK", ignore(case))]
     isBLANK,
-    #[token("isLITERAL")]
+    #[token("isLITERAL", ignore(case))]
     isLITERAL,
-    #[token("isNUMERIC")]
+    #[token("isNUMERIC", ignore(case))]
     isNUMERIC,
-    #[token("REGEX")]
+    #[token("REGEX", ignore(case))]
     REGEX,
-    #[token("SUBSTR")]
+    #[token("SUBSTR", ignore(case))]
     SUBSTR,
-    #[token("REPLACE")]
+    #[token("REPLACE", ignore(case))]
     REPLACE,
-    #[token("EXISTS")]
+    #[token("EXISTS", ignore(case))]
     EXISTS,
-    #[token("COUNT")]
+    #[token("COUNT", ignore(case))]
     COUNT,
-    #[token("SUM")]
+    #[token("SUM", ignore(case))]
     SUM,
-    #[token("MIN")]
+    #[token("MIN", ignore(case))]
     MIN,
-    #[token("MAX")]
+    #[token("MAX", ignore(case))]
     MAX,
-    #[token("AVG")]
+    #[token("AVG", ignore(case))]
     AVG,
-    #[token("SAMPLE")]
+    #[token("SAMPLE", ignore(case))]
     SAMPLE,
-    #[token("GROUP_CONCAT")]
+    #[token("GROUP_CONCAT", ignore(case))]
     GROUP_CONCAT,
-    #[token("SEPARATOR")]
+    #[token("SEPARATOR", ignore(case))]
     SEPARATOR,
-    #[token("LANGTAG")]
+    #[regex("@[a-zA-Z]+(-[a-zA-Z0-9]+)*")]
     LANGTAG,
-    #[token("DoubleZirkumflex")]
+    #[token("^^")]
     DoubleZirkumflex,
-    #[token("DECIMAL")]
+    #[regex(r"\.[0-9]+", callbacks::numeric)]
     DECIMAL,
-    #[token("DOUBLE")]
     DOUBLE,
-    #[token("INTEGER_POSITIVE")]
+    #[regex(r"\+[0-9]+", callbacks::numeric)]
     INTEGER_POSITIVE,
-    #[token("DECIMAL_POSITIVE")]
+    #[regex(r"\+\.[0-9]+", callbacks::numeric)]
     DECIMAL_POSITIVE,
-    #[token("DOUBLE_POSITIVE")]
     DOUBLE_POSITIVE,
-    #[token("INTEGER_NEGATIVE")]
+    #[regex(r"-[0-9]+", callbacks::numeric)]
     INTEGER_NEGATIVE,
-    #[token("DECIMAL_NEGATIVE")]
+    #[regex(r"-\.[0-9]+", callbacks::numeric)]
     DECIMAL_NEGATIVE,
-    #[token("DOUBLE_NEGATIVE")]
     DOUBLE_NEGATIVE,
-    #[token("true")]
+    #[token("true", ignore(case))]
     True,
-    #[token("false")]
+    #[token("false", ignore(case))]
     False,
-    #[regex("'[^\u{27}\u{5C}\u{A}\u{D}]*'")]
+    #[regex(r#"'([^'\\\n\r]|\\[tbnrf\\"'])*'"#)]
     STRING_LITERAL1,
-    #[regex(r#""([^"\\\x00-\x1F]|\\(["\\bnfrt/]|u[a-fA-F0-9]{4}))*""#)]
+    #[regex(r#""([^"\\\n\r]|\\[tbnrf\\"'])*""#)]
     STRING_LITERAL2,
-    // TODO: add regex
-    #[token("STRING_LITERAL_LONG1")]
+    #[regex(r#"'''(('|'')?([^'\\]|\\[tbnrf\\"']))*'''"#)]
     STRING_LITERAL_LONG1,
-    // TODO: add regex
-    #[token("STRING_LITERAL_LONG2")]
+    #[regex(r#""""(("|"")?([^"\\]|\\[tbnrf\\"']))*""""#)]
     STRING_LITERAL_LONG2,
-    #[regex("[A-Za-z\u{00C0}-\u{00D6}\u{00D8}-\u{00F6}\u{00F8}-\u{02FF}\u{0370}-\u{037D}\u{037F}-\u{1FFF}\u{200C}-\u{200D}\u{2070}-\u{218F}\u{2C00}-\u{2FEF}\u{3001}-\u{D7FF}\u{F900}-\u{FDCF}\u{FDF0}-\u{FFFD}\u{10000}-\u{EFFFF}]([A-Za-z\u{00C0}-\u{00D6}\u{00D8}-\u{00F6}\u{00F8}-\u{02FF}\u{0370}-\u{037D}\u{037F}-\u{1FFF}\u{200C}-\u{200D}\u{2070}-\u{218F}\u{2C00}-\u{2FEF}\u{3001}-\u{D7FF}\u{F900}-\u{FDCF}\u{FDF0}-\u{FFFD}\u{10000}-\u{EFFFF}0-9\u{00B7}\u{0300}-\u{036F}\u{203F}-\u{2040}_.-]*[A-Za-z\u{00C0}-\u{00D6}\u{00D8}-\u{00F6}\u{00F8}-\u{02FF}\u{0370}-\u{037D}\u{037F}-\u{1FFF}\u{200C}-\u{200D}\u{2070}-\u{218F}\u{2C00}-\u{2FEF}\u{3001}-\u{D7FF}\u{F900}-\u{FDCF}\u{FDF0}-\u{FFFD}\u{10000}-\u{EFFFF}0-9\u{00B7}\u{0300}-\u{036F}\u{203F}-\u{2040}_-])?:([A-Za-z\u{00C0}-\u{00D6}\u{00D8}-\u{00F6}\u{00F8}-\u{02FF}\u{0370}-\u{037D}\u{037F}-\u{1FFF}\u{200C}-\u{200D}\u{2070}-\u{218F}\u{2C00}-\u{2FEF}\u{3001}-\u{D7FF}\u{F900}-\u{FDCF}\u{FDF0}-\u{FFFD}\u{10000}-\u{EFFFF}_0-9:]|%[0-9A-Fa-f])([A-Za-z\u{00C0}-\u{00D6}\u{00D8}-\u{00F6}\u{00F8}-\u{02FF}\u{0370}-\u{037D}\u{037F}-\u{1FFF}\u{200C}-\u{200D}\u{2070}-\u{218F}\u{2C00}-\u{2FEF}\u{3001}-\u{D7FF}\u{F900}-\u{FDCF}\u{FDF0}-\u{FFFD}\u{10000}-\u{EFFFF}0-9\u{00B7}\u{0300}-\u{036F}\u{203F}-\u{2040}_:.-]|%[0-9A-Fa-f])*([A-Za-z\u{00C0}-\u{00D6}\u{00D8}-\u{00F6}\u{00F8}-\u{02FF}\u{0370}-\u{037D}\u{037F}-\u{1FFF}\u{200C}-\u{200D}\u{2070}-\u{218F}\u{2C00}-\u{2FEF}\u{3001}-\u{D7FF}\u{F900}-\u{FDCF}\u{FDF0}-\u{FFFD}\u{10000}-\u{EFFFF}0-9\u{00B7}\u{0300}-\u{036F}\u{203F}-\u{2040}_:-]|%[0-9A-Fa-f])")]
     PNAME_LN,
-    #[token("BLANK_NODE_LABEL")]
+    #[token("_:", callbacks::blank_node_label)]
     BLANK_NODE_LABEL,
-    #[token("ANON")]
     ANON,
-
-    // Composite nodes
+    QueryUnit,
     Query,
     Prologue,
     SelectQuery,
@@ -381,6 +393,7 @@ pub enum SyntaxKind {
     Load,
     Clear,
     Drop,
+    Add,
     Move,
     Copy,
     Create,
@@ -390,7 +403,6 @@ pub enum SyntaxKind {
     Modify,
     GraphRef,
     GraphRefAll,
-    Add,
     GraphOrDefault,
     QuadData,
     QuadPattern,
@@ -474,5 +486,8 @@ pub enum SyntaxKind {
     String,
     NumericLiteralUnsigned,
     PrefixedName,
-    QueryUnit,
+}
+impl SyntaxKind {
+    /// The kind with the highest discriminant.
+    pub(crate) const LAST: SyntaxKind = SyntaxKind::PrefixedName;
 }
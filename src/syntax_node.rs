@@ -1,4 +1,4 @@
-use crate::SyntaxKind::{self, *};
+use crate::SyntaxKind;
 
 #[allow(dead_code)]
 pub type SyntaxNode = rowan::SyntaxNode<Sparql>;
@@ -20,7 +20,7 @@ pub enum Sparql {}
 impl rowan::Language for Sparql {
     type Kind = SyntaxKind;
     fn kind_from_raw(raw: rowan::SyntaxKind) -> Self::Kind {
-        assert!(raw.0 <= QueryUnit as u16);
+        assert!(raw.0 <= SyntaxKind::LAST as u16);
         unsafe { std::mem::transmute::<u16, SyntaxKind>(raw.0) }
     }
     fn kind_to_raw(kind: Self::Kind) -> rowan::SyntaxKind {
@@ -39,5 +39,5 @@ pub fn print_full_tree(syntax_node: &SyntaxNode, indent: usize) -> std::string::
                 s += &format!("{}{:?}\n", "    ".repeat(indent + 1), token);
             }
         });
-    return s;
+    s
 }